@@ -0,0 +1,36 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ndarray::prelude::*;
+use ndarray_histogram::histogram::{
+	strategies::{Auto, FreedmanDiaconis},
+	GridBuilder, SortedColumns,
+};
+use rand::prelude::*;
+
+fn grid_builder(c: &mut Criterion) {
+	let mut rng = StdRng::seed_from_u64(42);
+	let observations = Array2::from_shape_simple_fn((10_000, 64), || rng.gen_range(0..1_000_000));
+	let mut group = c.benchmark_group("grid_builder");
+	group.bench_function("from_array", |b| {
+		b.iter(|| {
+			let auto = GridBuilder::<Auto<i64>>::from_array(&observations).unwrap();
+			let fd = GridBuilder::<FreedmanDiaconis<i64>>::from_array(&observations).unwrap();
+			black_box((auto.build(), fd.build()))
+		})
+	});
+	group.bench_function("from_sorted", |b| {
+		b.iter(|| {
+			let sorted = SortedColumns::new(&observations);
+			let auto = GridBuilder::<Auto<i64>>::from_sorted(&sorted).unwrap();
+			let fd = GridBuilder::<FreedmanDiaconis<i64>>::from_sorted(&sorted).unwrap();
+			black_box((auto.build(), fd.build()))
+		})
+	});
+	group.finish();
+}
+
+criterion_group! {
+	name = benches;
+	config = Criterion::default();
+	targets = grid_builder
+}
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use ndarray::prelude::*;
+use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+use rand::prelude::*;
+
+fn add_observation(c: &mut Criterion) {
+	let mut rng = StdRng::seed_from_u64(42);
+	let points = Array2::from_shape_simple_fn((10_000, 2), || rng.gen_range(0..100));
+	let bins = Bins::new(Edges::from((0..=100).step_by(5).collect::<Vec<_>>()));
+	let grid = Grid::from(vec![bins.clone(), bins]);
+	let mut group = c.benchmark_group("add_observation");
+	group.bench_function("IxDyn", |b| {
+		b.iter_batched(
+			|| Histogram::new(grid.clone()),
+			|mut histogram| {
+				for point in points.rows() {
+					let _ = histogram.add_observation(&point);
+				}
+				black_box(histogram)
+			},
+			BatchSize::SmallInput,
+		)
+	});
+	group.bench_function("Ix2", |b| {
+		b.iter_batched(
+			|| {
+				Histogram::new(grid.clone())
+					.into_dimensionality::<Ix2>()
+					.unwrap()
+			},
+			|mut histogram| {
+				for point in points.rows() {
+					let _ = histogram.add_observation(&point);
+				}
+				black_box(histogram)
+			},
+			BatchSize::SmallInput,
+		)
+	});
+	group.finish();
+}
+
+criterion_group! {
+	name = benches;
+	config = Criterion::default();
+	targets = add_observation
+}
+criterion_main!(benches);
@@ -0,0 +1,113 @@
+use criterion::{
+	black_box, criterion_group, criterion_main, AxisScale, BatchSize, Criterion, PlotConfiguration,
+};
+use ndarray::prelude::*;
+use ndarray_histogram::{interpolate::Linear, o64, Quantile1dExt};
+use rand::prelude::*;
+
+fn quantiles_mut(c: &mut Criterion) {
+	let lens = vec![1_000, 10_000, 100_000, 1_000_000];
+	let qs: Array1<_> = (1..=12).map(|q| o64(f64::from(q) / 13.)).collect();
+	let mut group = c.benchmark_group("quantiles_mut");
+	group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+	for len in &lens {
+		group.bench_with_input(format!("{}", len), len, |b, &len| {
+			let mut rng = StdRng::seed_from_u64(42);
+			let mut data: Vec<_> = (0..len).collect();
+			data.shuffle(&mut rng);
+			b.iter_batched(
+				|| Array1::from(data.clone()),
+				|mut arr| {
+					black_box(arr.quantiles_mut(&qs, &Linear).unwrap());
+				},
+				BatchSize::LargeInput,
+			)
+		});
+	}
+	group.finish();
+}
+
+fn quantile_mut_tiny(c: &mut Criterion) {
+	let lens = vec![2, 4, 8, 16, 32];
+	let mut group = c.benchmark_group("quantile_mut_tiny");
+	for len in &lens {
+		group.bench_with_input(format!("{}", len), len, |b, &len| {
+			let mut rng = StdRng::seed_from_u64(42);
+			let mut data: Vec<_> = (0..len).collect();
+			data.shuffle(&mut rng);
+			b.iter_batched(
+				|| Array1::from(data.clone()),
+				|mut arr| {
+					black_box(arr.quantile_mut(o64(0.5), &Linear).unwrap());
+				},
+				BatchSize::SmallInput,
+			)
+		});
+	}
+	group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn par_quantiles_mut(c: &mut Criterion) {
+	let lens = vec![1_000, 10_000, 100_000, 1_000_000];
+	let qs: Array1<_> = (1..=12).map(|q| o64(f64::from(q) / 13.)).collect();
+	let mut group = c.benchmark_group("par_quantiles_mut");
+	group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+	for len in &lens {
+		group.bench_with_input(format!("{}", len), len, |b, &len| {
+			let mut rng = StdRng::seed_from_u64(42);
+			let mut data: Vec<_> = (0..len).collect();
+			data.shuffle(&mut rng);
+			b.iter_batched(
+				|| Array1::from(data.clone()),
+				|mut arr| {
+					black_box(arr.par_quantiles_mut(&qs, &Linear).unwrap());
+				},
+				BatchSize::LargeInput,
+			)
+		});
+	}
+	group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn par_quantiles_mut_crossover(c: &mut Criterion) {
+	let lens = vec![1_000, 3_000, 10_000, 30_000, 100_000];
+	let qs: Array1<_> = (1..=12).map(|q| o64(f64::from(q) / 13.)).collect();
+	let mut group = c.benchmark_group("par_quantiles_mut_crossover");
+	group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+	for len in &lens {
+		let mut rng = StdRng::seed_from_u64(42);
+		let mut data: Vec<_> = (0..*len).collect();
+		data.shuffle(&mut rng);
+		for (name, par_threshold) in [("serial", usize::MAX), ("parallel", 0)] {
+			group.bench_with_input(format!("{}/{}", name, len), len, |b, _| {
+				b.iter_batched(
+					|| Array1::from(data.clone()),
+					|mut arr| {
+						black_box(
+							arr.par_quantiles_mut_with_threshold(&qs, &Linear, par_threshold)
+								.unwrap(),
+						);
+					},
+					BatchSize::LargeInput,
+				)
+			});
+		}
+	}
+	group.finish();
+}
+
+#[cfg(not(feature = "rayon"))]
+criterion_group! {
+	name = benches;
+	config = Criterion::default();
+	targets = quantiles_mut, quantile_mut_tiny
+}
+#[cfg(feature = "rayon")]
+criterion_group! {
+	name = benches;
+	config = Criterion::default();
+	targets = quantiles_mut, quantile_mut_tiny, par_quantiles_mut, par_quantiles_mut_crossover
+}
+criterion_main!(benches);
@@ -1,8 +1,11 @@
 //! Custom errors returned from our methods and functions.
 
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
 use num_traits::Float;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
 
 /// An error that indicates that the input array was empty.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -14,8 +17,35 @@ impl fmt::Display for EmptyInput {
 	}
 }
 
+#[cfg(feature = "std")]
 impl Error for EmptyInput {}
 
+/// An error that indicates that a NaN value has been found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NanFound;
+
+impl fmt::Display for NanFound {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "NaN found.")
+	}
+}
+
+#[cfg(feature = "std")]
+impl Error for NanFound {}
+
+/// An error that indicates that a NaN or infinite value has been found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotFinite;
+
+impl fmt::Display for NotFinite {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "NaN or infinity found.")
+	}
+}
+
+#[cfg(feature = "std")]
+impl Error for NotFinite {}
+
 /// An error computing a minimum/maximum value.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum MinMaxError {
@@ -36,6 +66,7 @@ impl fmt::Display for MinMaxError {
 	}
 }
 
+#[cfg(feature = "std")]
 impl Error for MinMaxError {}
 
 impl From<EmptyInput> for MinMaxError {
@@ -47,7 +78,7 @@ impl From<EmptyInput> for MinMaxError {
 /// An error used by methods and functions that take two arrays as argument and
 /// expect them to have exactly the same shape
 /// (e.g. `ShapeMismatch` is raised when `a.shape() == b.shape()` evaluates to `False`).
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ShapeMismatch {
 	/// Shape of first argument.
 	pub first_shape: Vec<usize>,
@@ -65,6 +96,7 @@ impl fmt::Display for ShapeMismatch {
 	}
 }
 
+#[cfg(feature = "std")]
 impl Error for ShapeMismatch {}
 
 /// An error for methods that take multiple non-empty array inputs.
@@ -74,6 +106,8 @@ pub enum MultiInputError {
 	EmptyInput,
 	/// The arrays did not have the same shape.
 	ShapeMismatch(ShapeMismatch),
+	/// Some weights were negative or NaN.
+	InvalidWeights,
 }
 
 impl MultiInputError {
@@ -86,6 +120,11 @@ impl MultiInputError {
 	pub fn is_shape_mismatch(&self) -> bool {
 		matches!(self, MultiInputError::ShapeMismatch(_))
 	}
+
+	/// Returns whether `self` is the `InvalidWeights` variant.
+	pub fn is_invalid_weights(&self) -> bool {
+		matches!(self, MultiInputError::InvalidWeights)
+	}
 }
 
 impl fmt::Display for MultiInputError {
@@ -93,10 +132,12 @@ impl fmt::Display for MultiInputError {
 		match self {
 			MultiInputError::EmptyInput => write!(f, "Empty input."),
 			MultiInputError::ShapeMismatch(e) => write!(f, "Shape mismatch: {}", e),
+			MultiInputError::InvalidWeights => write!(f, "The weights are invalid."),
 		}
 	}
 }
 
+#[cfg(feature = "std")]
 impl Error for MultiInputError {}
 
 impl From<EmptyInput> for MultiInputError {
@@ -111,7 +152,41 @@ impl From<ShapeMismatch> for MultiInputError {
 	}
 }
 
+/// An error constructing a kernel density estimate.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KdeError {
+	/// The input was empty.
+	EmptyInput,
+	/// The bandwidth was not positive and finite.
+	InvalidBandwidth(f64),
+}
+
+impl fmt::Display for KdeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			KdeError::EmptyInput => write!(f, "Empty input."),
+			KdeError::InvalidBandwidth(bandwidth) => {
+				write!(
+					f,
+					"Bandwidth of {:?} is not positive and finite.",
+					bandwidth
+				)
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl Error for KdeError {}
+
+impl From<EmptyInput> for KdeError {
+	fn from(_: EmptyInput) -> Self {
+		KdeError::EmptyInput
+	}
+}
+
 /// An error computing a quantile.
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum QuantileError<F: Float + fmt::Debug> {
 	/// The input was empty.
@@ -120,6 +195,7 @@ pub enum QuantileError<F: Float + fmt::Debug> {
 	InvalidQuantile(F),
 }
 
+#[cfg(feature = "std")]
 impl<F: Float + fmt::Debug> fmt::Display for QuantileError<F> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
@@ -131,10 +207,93 @@ impl<F: Float + fmt::Debug> fmt::Display for QuantileError<F> {
 	}
 }
 
+#[cfg(feature = "std")]
 impl<F: Float + fmt::Debug> Error for QuantileError<F> {}
 
+#[cfg(feature = "std")]
 impl<F: Float + fmt::Debug> From<EmptyInput> for QuantileError<F> {
 	fn from(_: EmptyInput) -> QuantileError<F> {
 		QuantileError::EmptyInput
 	}
 }
+
+/// An error computing a rolling quantile.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RollingQuantileError {
+	/// The input was empty.
+	EmptyInput,
+	/// The window was not between `1` and the length of the input (inclusive).
+	InvalidWindow(usize),
+	/// The `q` was not between `0.` and `1.` (inclusive).
+	InvalidQuantile(f64),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for RollingQuantileError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			RollingQuantileError::EmptyInput => write!(f, "Empty input."),
+			RollingQuantileError::InvalidWindow(window) => write!(
+				f,
+				"Window of {} is not between 1 and the input length (inclusive).",
+				window
+			),
+			RollingQuantileError::InvalidQuantile(q) => {
+				write!(f, "{:?} is not between 0. and 1. (inclusive).", q)
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl Error for RollingQuantileError {}
+
+#[cfg(feature = "std")]
+impl From<EmptyInput> for RollingQuantileError {
+	fn from(_: EmptyInput) -> RollingQuantileError {
+		RollingQuantileError::EmptyInput
+	}
+}
+
+/// An error computing a weighted quantile.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum WeightedQuantileError {
+	/// The total weight was zero, e.g. the input was empty.
+	EmptyInput,
+	/// The `q` was not between `0.` and `1.` (inclusive).
+	InvalidQuantile(f64),
+	/// The shapes of the data and of its weights did not match.
+	ShapeMismatch(ShapeMismatch),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for WeightedQuantileError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			WeightedQuantileError::EmptyInput => write!(f, "Empty input."),
+			WeightedQuantileError::InvalidQuantile(q) => {
+				write!(f, "{:?} is not between 0. and 1. (inclusive).", q)
+			}
+			WeightedQuantileError::ShapeMismatch(e) => write!(f, "Shape mismatch: {}", e),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl Error for WeightedQuantileError {}
+
+#[cfg(feature = "std")]
+impl From<EmptyInput> for WeightedQuantileError {
+	fn from(_: EmptyInput) -> WeightedQuantileError {
+		WeightedQuantileError::EmptyInput
+	}
+}
+
+#[cfg(feature = "std")]
+impl From<ShapeMismatch> for WeightedQuantileError {
+	fn from(err: ShapeMismatch) -> WeightedQuantileError {
+		WeightedQuantileError::ShapeMismatch(err)
+	}
+}
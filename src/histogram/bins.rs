@@ -1,7 +1,17 @@
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 
+use super::errors::EdgesError;
+use alloc::vec::Vec;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+use core::cmp::Ordering;
+use core::fmt;
+#[cfg(feature = "approx")]
+use core::ops::Deref;
+use core::ops::{Index, Range, RangeInclusive};
+use itertools::Itertools;
 use ndarray::prelude::*;
-use std::ops::{Index, Range};
+use num_traits::{CheckedAdd, One, ToPrimitive};
 
 #[cfg(feature = "rayon")]
 use rayon::slice::ParallelSliceMut;
@@ -102,6 +112,70 @@ impl<A: Ord + Send + Clone> From<Array1<A>> for Edges<A> {
 	}
 }
 
+impl<A: Ord + Send> From<Range<A>> for Edges<A>
+where
+	RangeInclusive<A>: Iterator<Item = A>,
+{
+	/// Converts a `Range<A>` of integers into the edges of unit-width bins covering the range,
+	/// i.e. every integer from `range.start` to `range.end` inclusively.
+	///
+	/// The edges are empty if the range is empty.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::Edges;
+	///
+	/// let edges = Edges::from(-1..3);
+	/// assert_eq!(edges.as_array_view(), array![-1, 0, 1, 2, 3]);
+	/// assert!(Edges::from(3..3).is_empty());
+	/// ```
+	fn from(range: Range<A>) -> Self {
+		if range.is_empty() {
+			return Edges { edges: Vec::new() };
+		}
+		let edges = (range.start..=range.end).collect();
+		Edges { edges }
+	}
+}
+
+impl<A: Ord + Send + CheckedAdd + One> From<RangeInclusive<A>> for Edges<A>
+where
+	RangeInclusive<A>: Iterator<Item = A>,
+{
+	/// Converts a `RangeInclusive<A>` of integers into the edges of unit-width bins covering the
+	/// range, i.e. every integer from `range.start()` to `range.end() + 1` inclusively.
+	///
+	/// The edges are empty if the range is empty.
+	///
+	/// # Panics
+	///
+	/// Panics if `range.end() + 1` overflows.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::Edges;
+	///
+	/// let edges = Edges::from(-1..=2);
+	/// assert_eq!(edges.as_array_view(), array![-1, 0, 1, 2, 3]);
+	/// assert_eq!(edges, Edges::from(-1..3));
+	/// ```
+	fn from(range: RangeInclusive<A>) -> Self {
+		if range.is_empty() {
+			return Edges { edges: Vec::new() };
+		}
+		let (start, end) = range.into_inner();
+		let end = end
+			.checked_add(&A::one())
+			.expect("Range end overflows with unit-width bins");
+		let edges = (start..=end).collect();
+		Edges { edges }
+	}
+}
+
 impl<A: Ord + Send> Index<usize> for Edges<A> {
 	type Output = A;
 
@@ -124,7 +198,137 @@ impl<A: Ord + Send> Index<usize> for Edges<A> {
 	}
 }
 
+#[cfg(feature = "approx")]
+impl<A> AbsDiffEq for Edges<A>
+where
+	A: Ord + Send + Deref,
+	A::Target: AbsDiffEq,
+	<A::Target as AbsDiffEq>::Epsilon: Clone,
+{
+	type Epsilon = <A::Target as AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		A::Target::default_epsilon()
+	}
+
+	/// Returns whether both have the same number of edges and each pair of edges is equal up to
+	/// the absolute difference `epsilon`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use approx::assert_abs_diff_eq;
+	/// use ndarray_histogram::{histogram::Edges, o64};
+	///
+	/// let edges = Edges::from(vec![o64(0.), o64(0.1 + 0.2)]);
+	/// assert_ne!(edges, Edges::from(vec![o64(0.), o64(0.3)]));
+	/// assert_abs_diff_eq!(edges, Edges::from(vec![o64(0.), o64(0.3)]));
+	/// ```
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.len() == other.len()
+			&& self
+				.iter()
+				.zip(other.iter())
+				.all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+	}
+}
+
+#[cfg(feature = "approx")]
+impl<A> RelativeEq for Edges<A>
+where
+	A: Ord + Send + Deref,
+	A::Target: RelativeEq,
+	<A::Target as AbsDiffEq>::Epsilon: Clone,
+{
+	fn default_max_relative() -> Self::Epsilon {
+		A::Target::default_max_relative()
+	}
+
+	/// Returns whether both have the same number of edges and each pair of edges is equal up to
+	/// the absolute difference `epsilon` or the relative difference `max_relative`.
+	fn relative_eq(
+		&self,
+		other: &Self,
+		epsilon: Self::Epsilon,
+		max_relative: Self::Epsilon,
+	) -> bool {
+		self.len() == other.len()
+			&& self
+				.iter()
+				.zip(other.iter())
+				.all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+	}
+}
+
 impl<A: Ord + Send> Edges<A> {
+	/// Converts a `Vec<A>` into an `Edges<A>` without sorting nor removing duplicates.
+	///
+	/// This is a performance escape hatch for edges known to be strictly increasing, e.g. when
+	/// generated by a linear space. The caller is trusted, only debug builds verify the order. Use
+	/// `Edges::from` or [`Edges::try_from_sorted`] otherwise, which sort and validate the edges,
+	/// respectively.
+	///
+	/// **Panics** in debug builds if the edges are not strictly increasing.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::Edges;
+	///
+	/// let edges = Edges::from_sorted_unchecked((0..=10).step_by(2).collect());
+	/// assert_eq!(edges, Edges::from(vec![10, 8, 6, 4, 2, 0]));
+	/// ```
+	#[must_use]
+	pub fn from_sorted_unchecked(edges: Vec<A>) -> Self {
+		debug_assert!(
+			edges.iter().tuple_windows().all(|(a, b)| a < b),
+			"Edges are not strictly increasing"
+		);
+		Edges { edges }
+	}
+
+	/// Converts a `Vec<A>` into an `Edges<A>`, validating the edges instead of sorting and
+	/// deduplicating them like `Edges::from`.
+	///
+	/// This is the checked counterpart of [`Edges::from_sorted_unchecked`]. It is not provided as
+	/// `TryFrom<Vec<A>>` since the infallible `From<Vec<A>>` already implies that conversion.
+	///
+	/// # Errors
+	///
+	/// Returns `Err(EdgesError::TooFew)` if there are fewer than two edges,
+	/// `Err(EdgesError::NotSorted)` if they are not sorted in increasing order, and
+	/// `Err(EdgesError::Duplicate)` if they contain duplicates.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{errors::EdgesError, Edges};
+	///
+	/// let edges = Edges::try_from_sorted(vec![0, 1, 5, 10])?;
+	/// assert_eq!(edges[2], 5);
+	///
+	/// assert_eq!(
+	/// 	Edges::try_from_sorted(vec![0, 5, 1]),
+	/// 	Err(EdgesError::NotSorted)
+	/// );
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	///
+	/// [`Edges::from_sorted_unchecked`]: #method.from_sorted_unchecked
+	pub fn try_from_sorted(edges: Vec<A>) -> Result<Self, EdgesError> {
+		if edges.len() < 2 {
+			return Err(EdgesError::TooFew);
+		}
+		for (left, right) in edges.iter().tuple_windows() {
+			match left.cmp(right) {
+				Ordering::Less => {}
+				Ordering::Equal => return Err(EdgesError::Duplicate),
+				Ordering::Greater => return Err(EdgesError::NotSorted),
+			}
+		}
+		Ok(Edges { edges })
+	}
+
 	/// Returns the number of edges in `self`.
 	///
 	/// # Examples
@@ -207,10 +411,102 @@ impl<A: Ord + Send> Edges<A> {
 		}
 	}
 
+	/// Returns the index at which `value` would have to be inserted to keep the `edges` sorted,
+	/// like [`numpy.searchsorted`].
+	///
+	/// If `value` equals an edge, [`Side::Left`] returns the index of the edge whereas
+	/// [`Side::Right`] returns the index after it. Otherwise, both return the index of the first
+	/// edge greater than `value`.
+	///
+	/// Complexity: O(log `n`) where `n` is the number of edges.
+	///
+	/// [`numpy.searchsorted`]: https://numpy.org/doc/stable/reference/generated/numpy.searchsorted.html
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Edges, Side};
+	///
+	/// let edges = Edges::from(vec![0, 2, 3]);
+	/// assert_eq!(edges.searchsorted(&2, Side::Left), 1);
+	/// assert_eq!(edges.searchsorted(&2, Side::Right), 2);
+	/// assert_eq!(edges.searchsorted(&1, Side::Left), 1);
+	/// assert_eq!(edges.searchsorted(&5, Side::Right), 3);
+	/// ```
+	pub fn searchsorted(&self, value: &A, side: Side) -> usize {
+		match side {
+			Side::Left => self.edges.partition_point(|edge| edge < value),
+			Side::Right => self.edges.partition_point(|edge| edge <= value),
+		}
+	}
+
 	/// Returns an iterator over the `edges` in `self`.
 	pub fn iter(&self) -> impl Iterator<Item = &A> {
 		self.edges.iter()
 	}
+
+	/// Returns the union of the `edges` in `self` and `other`, sorted in increasing order with
+	/// duplicates removed.
+	///
+	/// **Note** that the resulting intervals are the refinement of both edge sets. It is the
+	/// caller's responsibility to ensure that this is meaningful, e.g. by merging edges of
+	/// adjacent, non-overlapping ranges.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::Edges;
+	///
+	/// let left = Edges::from(vec![0, 1, 2]);
+	/// let right = Edges::from(vec![2, 3, 4]);
+	/// assert_eq!(left.merge(&right).as_array_view(), array![0, 1, 2, 3, 4]);
+	/// ```
+	#[must_use]
+	pub fn merge(&self, other: &Edges<A>) -> Edges<A>
+	where
+		A: Clone,
+	{
+		let mut edges = Vec::with_capacity(self.len() + other.len());
+		edges.extend(self.edges.iter().merge(&other.edges).cloned());
+		edges.dedup();
+		Edges { edges }
+	}
+
+	/// Returns the edges transformed by `f`, e.g. to relabel them in another unit.
+	///
+	/// The mapping must be strictly increasing in order to keep the edges sorted and unique.
+	///
+	/// **Panics** in debug builds if the mapped edges are not strictly increasing.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::{histogram::Edges, o64};
+	///
+	/// let edges = Edges::from(vec![0, 1, 2]);
+	/// let edges = edges.map(|&edge| o64(f64::from(edge) * 0.5));
+	/// assert_eq!(edges, Edges::from(vec![o64(0.), o64(0.5), o64(1.)]));
+	/// ```
+	#[must_use]
+	pub fn map<B, F>(&self, f: F) -> Edges<B>
+	where
+		B: Ord + Send,
+		F: Fn(&A) -> B,
+	{
+		Edges::from_sorted_unchecked(self.edges.iter().map(f).collect())
+	}
+}
+
+/// Side of equal edges to insert a value at, see [`Edges::searchsorted`].
+///
+/// [`Edges::searchsorted`]: struct.Edges.html#method.searchsorted
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Side {
+	/// Inserts before an equal edge, i.e. returns the index of the first edge `>= value`.
+	Left,
+	/// Inserts after an equal edge, i.e. returns the index of the first edge `> value`.
+	Right,
 }
 
 /// A sorted collection of non-overlapping 1-dimensional intervals.
@@ -240,6 +536,103 @@ pub struct Bins<A: Ord + Send> {
 	edges: Edges<A>,
 }
 
+impl<A: Ord + Send> From<Range<A>> for Bins<A>
+where
+	RangeInclusive<A>: Iterator<Item = A>,
+{
+	/// Converts a `Range<A>` of integers into unit-width bins covering the range, see
+	/// [`Edges::from`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::Bins;
+	///
+	/// let bins = Bins::from(0..5);
+	/// assert_eq!(bins.len(), 5);
+	/// assert_eq!(bins.index(4), 4..5);
+	/// ```
+	///
+	/// [`Edges::from`]: struct.Edges.html#impl-From%3CRange%3CA%3E%3E-for-Edges%3CA%3E
+	fn from(range: Range<A>) -> Self {
+		Bins::new(Edges::from(range))
+	}
+}
+
+impl<A: Ord + Send + CheckedAdd + One> From<RangeInclusive<A>> for Bins<A>
+where
+	RangeInclusive<A>: Iterator<Item = A>,
+{
+	/// Converts a `RangeInclusive<A>` of integers into unit-width bins covering the range, see
+	/// [`Edges::from`].
+	///
+	/// # Panics
+	///
+	/// Panics if `range.end() + 1` overflows.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::Bins;
+	///
+	/// let bins = Bins::from(0..=4);
+	/// assert_eq!(bins, Bins::from(0..5));
+	/// assert_eq!(bins.range_of(&4), Some(4..5));
+	/// ```
+	///
+	/// [`Edges::from`]: struct.Edges.html#impl-From%3CRangeInclusive%3CA%3E%3E-for-Edges%3CA%3E
+	fn from(range: RangeInclusive<A>) -> Self {
+		Bins::new(Edges::from(range))
+	}
+}
+
+#[cfg(feature = "approx")]
+impl<A> AbsDiffEq for Bins<A>
+where
+	A: Ord + Send + Deref,
+	A::Target: AbsDiffEq,
+	<A::Target as AbsDiffEq>::Epsilon: Clone,
+{
+	type Epsilon = <A::Target as AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		A::Target::default_epsilon()
+	}
+
+	/// Returns whether the edges are equal up to the absolute difference `epsilon`, see
+	/// [`Edges::abs_diff_eq`].
+	///
+	/// [`Edges::abs_diff_eq`]: struct.Edges.html#method.abs_diff_eq
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.edges.abs_diff_eq(&other.edges, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl<A> RelativeEq for Bins<A>
+where
+	A: Ord + Send + Deref,
+	A::Target: RelativeEq,
+	<A::Target as AbsDiffEq>::Epsilon: Clone,
+{
+	fn default_max_relative() -> Self::Epsilon {
+		A::Target::default_max_relative()
+	}
+
+	/// Returns whether the edges are equal up to the absolute difference `epsilon` or the relative
+	/// difference `max_relative`, see [`Edges::relative_eq`].
+	///
+	/// [`Edges::relative_eq`]: struct.Edges.html#method.relative_eq
+	fn relative_eq(
+		&self,
+		other: &Self,
+		epsilon: Self::Epsilon,
+		max_relative: Self::Epsilon,
+	) -> bool {
+		self.edges.relative_eq(&other.edges, epsilon, max_relative)
+	}
+}
+
 impl<A: Ord + Send> Bins<A> {
 	/// Returns a `Bins` instance where each bin corresponds to two consecutive members of the given
 	/// [`Edges`], consuming the edges.
@@ -250,6 +643,57 @@ impl<A: Ord + Send> Bins<A> {
 		Bins { edges }
 	}
 
+	/// Returns the bins with their edges transformed by `f`, see [`Edges::map`].
+	///
+	/// **Panics** in debug builds if the mapping is not strictly increasing.
+	///
+	/// [`Edges::map`]: struct.Edges.html#method.map
+	#[must_use]
+	pub fn map<B, F>(&self, f: F) -> Bins<B>
+	where
+		B: Ord + Send,
+		F: Fn(&A) -> B,
+	{
+		Bins::new(self.edges.map(f))
+	}
+
+	/// Returns a `Bins` instance from explicit breakpoints, validating them instead of sorting
+	/// and deduplicating them like [`Edges::from`].
+	///
+	/// # Errors
+	///
+	/// Returns `Err(EdgesError::TooFew)` if there are fewer than two breakpoints,
+	/// `Err(EdgesError::NotSorted)` if they are not sorted in increasing order, and
+	/// `Err(EdgesError::Duplicate)` if they contain duplicates.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{errors::EdgesError, Bins};
+	///
+	/// let bins = Bins::try_from_breakpoints(vec![0, 1, 5, 10])?;
+	/// assert_eq!(bins.index(2), 5..10);
+	///
+	/// assert_eq!(
+	/// 	Bins::try_from_breakpoints(vec![0, 5, 1]),
+	/// 	Err(EdgesError::NotSorted)
+	/// );
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	///
+	/// [`Edges::from`]: struct.Edges.html#impl-From%3CVec%3CA%3E%3E-for-Edges%3CA%3E
+	pub fn try_from_breakpoints(breaks: Vec<A>) -> Result<Self, EdgesError> {
+		Edges::try_from_sorted(breaks).map(Bins::new)
+	}
+
+	/// Returns the [`Edges`] the bins are defined by.
+	///
+	/// [`Edges`]: struct.Edges.html
+	#[must_use]
+	pub fn edges(&self) -> &Edges<A> {
+		&self.edges
+	}
+
 	/// Returns the number of bins in `self`.
 	///
 	/// # Examples
@@ -337,6 +781,18 @@ impl<A: Ord + Send> Bins<A> {
 		self.edges.indices_of(value).map(|t| t.0)
 	}
 
+	/// Returns the index of the bin containing `value` clamped to the range of `self`, i.e. the
+	/// first and last bins are open-ended, or `None` if there are no bins.
+	pub(crate) fn clamped_index_of(&self, value: &A) -> Option<usize> {
+		if self.is_empty() {
+			None
+		} else if value < &self.edges[0] {
+			Some(0)
+		} else {
+			self.index_of(value).or(Some(self.len() - 1))
+		}
+	}
+
 	/// Returns a range as the bin which contains the given `value`, or returns `None` otherwise.
 	///
 	/// # Examples
@@ -391,13 +847,158 @@ impl<A: Ord + Send> Bins<A> {
 			end: self.edges[index + 1].clone(),
 		}
 	}
+
+	/// Returns the center of each bin, i.e. the midpoint `(edges[i] + edges[i + 1]) / 2` of its
+	/// edges, as `f64` which avoids truncation for integer edges.
+	///
+	/// # Panics
+	///
+	/// Panics if an edge cannot be converted into `f64`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges};
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 4, 10]));
+	/// assert_eq!(bins.centers(), array![0.5, 2.5, 7.]);
+	/// ```
+	#[must_use]
+	pub fn centers(&self) -> Array1<f64>
+	where
+		A: ToPrimitive,
+	{
+		self.edges
+			.iter()
+			.map(|edge| edge.to_f64().unwrap())
+			.tuple_windows()
+			.map(|(start, end)| start + (end - start) / 2.)
+			.collect()
+	}
+}
+
+impl<A: Ord + Send + fmt::Display> fmt::Display for Bins<A> {
+	/// Formats the bins as space-separated left-closed, right-open intervals.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Bins, Edges};
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+	/// assert_eq!(bins.to_string(), "[0, 1) [1, 2) [2, 3)");
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let edges = self.edges.as_array_view();
+		for (i, (start, end)) in edges.iter().tuple_windows().enumerate() {
+			if i > 0 {
+				write!(f, " ")?;
+			}
+			write!(f, "[{start}, {end})")?;
+		}
+		Ok(())
+	}
 }
 
 #[cfg(test)]
 mod edges_tests {
-	use super::{Array1, Edges};
+	use super::{Array1, Edges, EdgesError, Side};
+	use alloc::{collections::BTreeSet, vec, vec::Vec};
+	use ndarray::array;
 	use quickcheck_macros::quickcheck;
-	use std::collections::BTreeSet;
+
+	#[test]
+	fn from_ranges_of_integers() {
+		assert_eq!(Edges::from(0_u8..5), Edges::from(vec![0, 1, 2, 3, 4, 5]));
+		assert_eq!(Edges::from(0_u8..=4), Edges::from(vec![0, 1, 2, 3, 4, 5]));
+		assert_eq!(Edges::from(-2_i64..=-2), Edges::from(vec![-2, -1]));
+		assert!(Edges::from(5..5).is_empty());
+		#[allow(clippy::reversed_empty_ranges)]
+		let reversed = Edges::from(5..=4);
+		assert!(reversed.is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "Range end overflows with unit-width bins")]
+	fn from_range_inclusive_panics_on_overflow() {
+		let _ = Edges::from(0_u8..=u8::MAX);
+	}
+
+	#[test]
+	fn from_sorted_unchecked_keeps_edges() {
+		let edges = Edges::from_sorted_unchecked(vec![-1, 0, 3]);
+		assert_eq!(edges, Edges::from(vec![3, 0, -1]));
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "Edges are not strictly increasing")]
+	fn from_sorted_unchecked_panics_for_unsorted_edges_in_debug_builds() {
+		let _ = Edges::from_sorted_unchecked(vec![0, 2, 1]);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "Edges are not strictly increasing")]
+	fn map_panics_for_decreasing_mapping_in_debug_builds() {
+		let _ = Edges::from(vec![0, 1, 2]).map(|&edge| -edge);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "Edges are not strictly increasing")]
+	fn from_sorted_unchecked_panics_for_duplicate_edges_in_debug_builds() {
+		let _ = Edges::from_sorted_unchecked(vec![0, 1, 1]);
+	}
+
+	#[test]
+	fn try_from_sorted_keeps_edges() {
+		let edges = Edges::try_from_sorted(vec![-3, 0, 1, 10]).unwrap();
+		assert_eq!(edges, Edges::from(vec![10, 1, 0, -3]));
+	}
+
+	#[test]
+	fn try_from_sorted_rejects_bad_edges() {
+		assert_eq!(
+			Edges::<i32>::try_from_sorted(vec![]),
+			Err(EdgesError::TooFew)
+		);
+		assert_eq!(Edges::try_from_sorted(vec![0]), Err(EdgesError::TooFew));
+		assert_eq!(
+			Edges::try_from_sorted(vec![0, 2, 1, 3]),
+			Err(EdgesError::NotSorted)
+		);
+		assert_eq!(
+			Edges::try_from_sorted(vec![0, 1, 1, 3]),
+			Err(EdgesError::Duplicate)
+		);
+	}
+
+	#[test]
+	fn merge_adjacent_edges() {
+		let edges = Edges::from(vec![0, 1, 2]).merge(&Edges::from(vec![2, 3, 4]));
+		assert_eq!(edges.as_array_view(), array![0, 1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn merge_overlapping_edges() {
+		let edges = Edges::from(vec![0, 2, 4, 6]).merge(&Edges::from(vec![1, 2, 3, 4]));
+		assert_eq!(edges.as_array_view(), array![0, 1, 2, 3, 4, 6]);
+		let edges = Edges::from(vec![0, 5]).merge(&Edges::from(vec![0, 5]));
+		assert_eq!(edges.as_array_view(), array![0, 5]);
+		let edges = Edges::from(vec![0, 5]).merge(&Edges::from(vec![]));
+		assert_eq!(edges.as_array_view(), array![0, 5]);
+	}
+
+	#[quickcheck]
+	#[allow(clippy::needless_pass_by_value)]
+	fn merge_is_union(u: Vec<i32>, v: Vec<i32>) -> bool {
+		let merged = Edges::from(u.clone()).merge(&Edges::from(v.clone()));
+		let mut union = u;
+		union.extend(v);
+		merged == Edges::from(union)
+	}
 
 	#[quickcheck]
 	fn check_sorted_from_vec(v: Vec<i32>) -> bool {
@@ -452,6 +1053,20 @@ mod edges_tests {
 		}
 	}
 
+	#[test]
+	fn searchsorted_matches_numpy() {
+		// np.searchsorted([1, 3, 5, 7], [0, 1, 2, 3, 6, 7, 8], side=...)
+		let edges = Edges::from(vec![1, 3, 5, 7]);
+		let values = [0, 1, 2, 3, 6, 7, 8];
+		let left = values.map(|value| edges.searchsorted(&value, Side::Left));
+		let right = values.map(|value| edges.searchsorted(&value, Side::Right));
+		assert_eq!(left, [0, 0, 1, 1, 3, 3, 4]);
+		assert_eq!(right, [0, 1, 1, 2, 3, 4, 4]);
+		let empty = Edges::<i32>::from(vec![]);
+		assert_eq!(empty.searchsorted(&0, Side::Left), 0);
+		assert_eq!(empty.searchsorted(&0, Side::Right), 0);
+	}
+
 	#[quickcheck]
 	#[allow(clippy::needless_pass_by_value)]
 	fn edges_are_deduped(v: Vec<i32>) -> bool {
@@ -466,6 +1081,76 @@ mod edges_tests {
 #[cfg(test)]
 mod bins_tests {
 	use super::{Bins, Edges};
+	use crate::histogram::errors::EdgesError;
+	use alloc::{string::ToString, vec};
+	use ndarray::{array, Array1};
+
+	#[test]
+	fn from_range_has_unit_bins() {
+		let bins = Bins::from(0..5);
+		assert_eq!(bins.len(), 5);
+		for i in 0..5 {
+			assert_eq!(bins.index(i), i..i + 1);
+		}
+		assert_eq!(bins.edges().as_array_view(), array![0, 1, 2, 3, 4, 5]);
+		assert_eq!(Bins::from(0..=4), bins);
+		assert_eq!(bins.range_of(&5), None);
+	}
+
+	#[test]
+	fn centers_of_uniform_bins() {
+		let bins = Bins::new(Edges::from(vec![-4, -2, 0, 2]));
+		assert_eq!(bins.centers(), array![-3., -1., 1.]);
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		assert_eq!(bins.centers(), array![0.5, 1.5]);
+	}
+
+	#[test]
+	fn centers_of_non_uniform_bins() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 4, 20]));
+		assert_eq!(bins.centers(), array![0.5, 2.5, 12.]);
+		let bins = Bins::new(Edges::from(vec![1]));
+		assert_eq!(bins.centers(), Array1::<f64>::zeros(0));
+	}
+
+	#[test]
+	fn display_intervals() {
+		let bins = Bins::new(Edges::from(vec![-2, 0, 5]));
+		assert_eq!(bins.to_string(), "[-2, 0) [0, 5)");
+		let bins = Bins::new(Edges::from(vec![7]));
+		assert_eq!(bins.to_string(), "");
+	}
+
+	#[test]
+	fn try_from_breakpoints() {
+		let bins = Bins::try_from_breakpoints(vec![-3, 0, 1, 10]).unwrap();
+		assert_eq!(bins, Bins::new(Edges::from(vec![10, 1, 0, -3])));
+	}
+
+	#[test]
+	fn too_few_breakpoints_are_bad() {
+		assert_eq!(
+			Bins::<i32>::try_from_breakpoints(vec![]),
+			Err(EdgesError::TooFew)
+		);
+		assert_eq!(Bins::try_from_breakpoints(vec![0]), Err(EdgesError::TooFew));
+	}
+
+	#[test]
+	fn unsorted_breakpoints_are_bad() {
+		assert_eq!(
+			Bins::try_from_breakpoints(vec![0, 2, 1, 3]),
+			Err(EdgesError::NotSorted)
+		);
+	}
+
+	#[test]
+	fn duplicate_breakpoints_are_bad() {
+		assert_eq!(
+			Bins::try_from_breakpoints(vec![0, 1, 1, 3]),
+			Err(EdgesError::Duplicate)
+		);
+	}
 
 	#[test]
 	#[should_panic]
@@ -476,4 +1161,20 @@ mod bins_tests {
 		// we need at least two edges to make a valid bin!
 		bins.index(0);
 	}
+
+	#[test]
+	#[cfg(all(feature = "approx", feature = "std"))]
+	fn approx_eq_up_to_rounding() {
+		use crate::o64;
+		use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+
+		let rounded = Bins::new(Edges::from(vec![o64(0.), o64(0.1 + 0.2), o64(0.7)]));
+		let exact = Bins::new(Edges::from(vec![o64(0.), o64(0.3), o64(0.7)]));
+		assert_ne!(rounded, exact);
+		assert_abs_diff_eq!(rounded, exact);
+		assert_relative_eq!(rounded, exact);
+		assert_abs_diff_ne!(rounded, exact, epsilon = 0.);
+		let fewer = Bins::new(Edges::from(vec![o64(0.), o64(0.3)]));
+		assert_abs_diff_ne!(fewer, exact, epsilon = 1.);
+	}
 }
@@ -0,0 +1,226 @@
+use super::bins::{Bins, Edges};
+use super::grid::Grid;
+use super::histograms::Histogram;
+use alloc::{vec, vec::Vec};
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::{FromPrimitive, NumOps, Zero};
+
+/// Histogram over a 1-dimensional uniform grid expanding its range to include any observation.
+///
+/// It starts with `n_bins` bins of a given width. Whenever an observation falls outside, the range
+/// is doubled towards the observation by merging each pair of adjacent bins into one bin of twice
+/// the width, until the observation is included. Hence, no observation is ever dropped and the
+/// counts are exactly the ones of a histogram over the final grid. This is useful for long-running
+/// collectors whose range is not known upfront.
+///
+/// Each expansion costs O(`n_bins`) and at least doubles the range, so the number of expansions
+/// grows logarithmically with the ratio of the final to the initial range. Adding an observation
+/// costs O(log `n_bins`) plus the amortized cost of the expansions, which vanishes as the range
+/// settles.
+///
+/// # Example:
+/// ```
+/// use ndarray::array;
+/// use ndarray_histogram::histogram::DynamicHistogram;
+///
+/// let mut histogram = DynamicHistogram::new(0, 1, 4);
+/// histogram.add_observation(&1);
+/// histogram.add_observation(&6);
+/// assert_eq!(histogram.expansions(), 1);
+/// assert_eq!(histogram.bins().index(3), 6..8);
+/// assert_eq!(histogram.counts(), array![1, 0, 0, 1]);
+/// ```
+pub struct DynamicHistogram<A: Ord + Send> {
+	counts: Array1<usize>,
+	bins: Bins<A>,
+	width: A,
+	expansions: usize,
+}
+
+impl<A> DynamicHistogram<A>
+where
+	A: Ord + Send + Clone + FromPrimitive + NumOps + Zero,
+{
+	/// Returns a new instance of DynamicHistogram with `n_bins` bins of `width` starting at `min`.
+	///
+	/// **Panics** if `n_bins` is not positive and even or if `width` is not positive.
+	#[must_use]
+	pub fn new(min: A, width: A, n_bins: usize) -> Self {
+		assert!(
+			n_bins > 0 && n_bins % 2 == 0,
+			"Number of bins must be positive and even"
+		);
+		assert!(width > A::zero(), "Bin width must be positive");
+		let bins = uniform_bins(&min, &width, n_bins);
+		DynamicHistogram {
+			counts: Array1::zeros(n_bins),
+			bins,
+			width,
+			expansions: 0,
+		}
+	}
+
+	/// Adds a single observation to the histogram, expanding its range if necessary.
+	///
+	/// **Panics** if the edges of the expanded range overflow `A`, e.g. for integers.
+	pub fn add_observation(&mut self, observation: &A) {
+		loop {
+			let edges = self.bins.edges();
+			if *observation < edges[0] {
+				self.expand_down();
+			} else if *observation >= edges[edges.len() - 1] {
+				self.expand_up();
+			} else {
+				break;
+			}
+		}
+		let index = self
+			.bins
+			.index_of(observation)
+			.expect("Observation lies within the expanded range");
+		self.counts[index] += 1;
+	}
+
+	/// Adds each observation of the 1-dimensional array to the histogram, expanding its range if
+	/// necessary.
+	///
+	/// **Panics** if the edges of the expanded range overflow `A`, e.g. for integers.
+	pub fn add_observations<S>(&mut self, observations: &ArrayBase<S, Ix1>)
+	where
+		S: Data<Elem = A>,
+	{
+		for observation in observations {
+			self.add_observation(observation);
+		}
+	}
+
+	/// Doubles the range towards larger values.
+	fn expand_up(&mut self) {
+		let n_bins = self.counts.len();
+		let mut counts = Array1::zeros(n_bins);
+		for (index, pair) in self.counts.exact_chunks(2).into_iter().enumerate() {
+			counts[index] = pair.sum();
+		}
+		self.width = self.width.clone() + self.width.clone();
+		self.bins = uniform_bins(&self.bins.edges()[0], &self.width, n_bins);
+		self.counts = counts;
+		self.expansions += 1;
+	}
+
+	/// Doubles the range towards smaller values.
+	fn expand_down(&mut self) {
+		let n_bins = self.counts.len();
+		let mut counts = Array1::zeros(n_bins);
+		for (index, pair) in self.counts.exact_chunks(2).into_iter().enumerate() {
+			counts[n_bins / 2 + index] = pair.sum();
+		}
+		let range = self.width.clone() * A::from_usize(n_bins).unwrap();
+		let min = self.bins.edges()[0].clone() - range;
+		self.width = self.width.clone() + self.width.clone();
+		self.bins = uniform_bins(&min, &self.width, n_bins);
+		self.counts = counts;
+		self.expansions += 1;
+	}
+}
+
+impl<A: Ord + Send> DynamicHistogram<A> {
+	/// Borrows a view on the histogram counts.
+	pub fn counts(&self) -> ArrayView1<'_, usize> {
+		self.counts.view()
+	}
+
+	/// Borrows an immutable reference to the current bins.
+	pub fn bins(&self) -> &Bins<A> {
+		&self.bins
+	}
+
+	/// Returns the number of times the range has been doubled.
+	pub fn expansions(&self) -> usize {
+		self.expansions
+	}
+
+	/// Returns the histogram over a grid of the current bins.
+	pub fn into_histogram(self) -> Histogram<A> {
+		let grid = Grid::from(vec![self.bins]);
+		Histogram::from_counts(grid, self.counts.into_dyn())
+			.expect("Counts match the number of bins")
+	}
+}
+
+/// Returns `n_bins` bins of `width` starting at `min`.
+fn uniform_bins<A>(min: &A, width: &A, n_bins: usize) -> Bins<A>
+where
+	A: Ord + Send + Clone + FromPrimitive + NumOps,
+{
+	let edges = (0..=n_bins)
+		.map(|index| min.clone() + width.clone() * A::from_usize(index).unwrap())
+		.collect::<Vec<_>>();
+	Bins::new(Edges::from_sorted_unchecked(edges))
+}
+
+#[cfg(test)]
+mod dynamic_histogram_tests {
+	use super::DynamicHistogram;
+	use crate::histogram::{Bins, Edges, Grid};
+	#[cfg(feature = "std")]
+	use crate::o64;
+	use crate::HistogramExt;
+	use alloc::vec;
+	use ndarray::{array, Axis};
+
+	#[test]
+	fn expansions_match_one_shot_histogram() {
+		let observations = array![3, -1, 7, 15, 2, -20, 40, 0, 9, -3, 63, 5];
+		let mut histogram = DynamicHistogram::new(0, 2, 6);
+		histogram.add_observations(&observations);
+		assert_eq!(histogram.expansions(), 4);
+		assert_eq!(histogram.counts().sum(), observations.len());
+		let grid = Grid::from(vec![histogram.bins().clone()]);
+		assert_eq!(
+			grid,
+			Grid::from(vec![Bins::new(Edges::from(vec![
+				-60, -28, 4, 36, 68, 100, 132
+			]))])
+		);
+		let one_shot = observations.insert_axis(Axis(1)).histogram(grid);
+		let histogram = histogram.into_histogram();
+		assert!(histogram.same_grid(&one_shot));
+		assert_eq!(histogram.counts(), one_shot.counts());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn expansions_of_float_ranges() {
+		let mut histogram = DynamicHistogram::new(o64(0.), o64(0.25), 4);
+		let observations = array![0.1, 0.9, -0.5, 3.5, 1.75, -7.].mapv(o64);
+		for observation in &observations {
+			histogram.add_observation(observation);
+		}
+		assert_eq!(histogram.expansions(), 4);
+		assert_eq!(histogram.bins().index(0), o64(-9.)..o64(-5.));
+		let grid = Grid::from(vec![histogram.bins().clone()]);
+		let one_shot = observations.insert_axis(Axis(1)).histogram(grid);
+		assert_eq!(histogram.into_histogram().counts(), one_shot.counts());
+	}
+
+	#[test]
+	fn observations_within_range_do_not_expand() {
+		let mut histogram = DynamicHistogram::new(10, 5, 2);
+		histogram.add_observations(&array![10, 14, 15, 19]);
+		assert_eq!(histogram.expansions(), 0);
+		assert_eq!(histogram.counts(), array![2, 2]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Number of bins must be positive and even")]
+	fn odd_number_of_bins_panics() {
+		let _ = DynamicHistogram::new(0, 1, 3);
+	}
+
+	#[test]
+	#[should_panic(expected = "Bin width must be positive")]
+	fn zero_width_panics() {
+		let _ = DynamicHistogram::new(0, 0, 2);
+	}
+}
@@ -1,8 +1,9 @@
 //! Histogram related errors.
 
-use crate::errors::{EmptyInput, MinMaxError};
+use crate::errors::{EmptyInput, MinMaxError, ShapeMismatch};
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
 
 /// Error to denote that no bin has been found for a certain observation.
 #[derive(Debug, Clone)]
@@ -14,12 +15,144 @@ impl fmt::Display for BinNotFound {
 	}
 }
 
+#[cfg(feature = "std")]
 impl error::Error for BinNotFound {
 	fn description(&self) -> &str {
 		"No bin has been found."
 	}
 }
 
+/// Error to denote that the number of dimensions does not match the expected one, e.g. the grid's.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DimensionMismatch {
+	/// Number of dimensions expected, e.g. the grid's.
+	pub expected: usize,
+	/// Number of dimensions found.
+	pub found: usize,
+}
+
+impl fmt::Display for DimensionMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"Dimension mismatch: expected {} dimensions, found {}.",
+			self.expected, self.found
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for DimensionMismatch {}
+
+/// Error to denote that the grids of two histograms differ, hence their counts cannot be compared
+/// bin by bin.
+///
+/// It wraps the [`ShapeMismatch`] of both grids together with the operation during which it arose.
+/// Both grids have the same shape if only their edges differ.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GridMismatch {
+	context: &'static str,
+	shape_mismatch: ShapeMismatch,
+}
+
+impl GridMismatch {
+	pub(crate) fn new(context: &'static str, shape_mismatch: ShapeMismatch) -> Self {
+		GridMismatch {
+			context,
+			shape_mismatch,
+		}
+	}
+
+	/// Returns the operation during which the grids did not match, e.g. `"histogram
+	/// intersection"`.
+	pub fn context(&self) -> &'static str {
+		self.context
+	}
+
+	/// Returns the shapes of both grids.
+	pub fn shape_mismatch(&self) -> &ShapeMismatch {
+		&self.shape_mismatch
+	}
+
+	/// Returns whether both grids have the same shape but different edges.
+	pub fn is_edges_mismatch(&self) -> bool {
+		self.shape_mismatch.first_shape == self.shape_mismatch.second_shape
+	}
+}
+
+impl fmt::Display for GridMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Grids do not match during {}: ", self.context)?;
+		if self.is_edges_mismatch() {
+			write!(f, "edges differ.")
+		} else {
+			write!(
+				f,
+				"shapes {:?} and {:?}.",
+				self.shape_mismatch.first_shape, self.shape_mismatch.second_shape
+			)
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for GridMismatch {
+	fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+		Some(&self.shape_mismatch)
+	}
+}
+
+impl From<GridMismatch> for ShapeMismatch {
+	fn from(err: GridMismatch) -> Self {
+		err.shape_mismatch
+	}
+}
+
+/// Error adding an observation to a histogram.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ObservationError {
+	/// The observation has a different number of dimensions than the grid.
+	DimensionMismatch(DimensionMismatch),
+	/// No bin has been found for the observation.
+	BinNotFound,
+}
+
+impl ObservationError {
+	/// Returns whether `self` is the `DimensionMismatch` variant.
+	pub fn is_dimension_mismatch(&self) -> bool {
+		matches!(self, ObservationError::DimensionMismatch(_))
+	}
+
+	/// Returns whether `self` is the `BinNotFound` variant.
+	pub fn is_bin_not_found(&self) -> bool {
+		matches!(self, ObservationError::BinNotFound)
+	}
+}
+
+impl fmt::Display for ObservationError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ObservationError::DimensionMismatch(e) => write!(f, "{}", e),
+			ObservationError::BinNotFound => write!(f, "No bin has been found."),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ObservationError {}
+
+impl From<DimensionMismatch> for ObservationError {
+	fn from(err: DimensionMismatch) -> Self {
+		ObservationError::DimensionMismatch(err)
+	}
+}
+
+impl From<BinNotFound> for ObservationError {
+	fn from(_: BinNotFound) -> Self {
+		ObservationError::BinNotFound
+	}
+}
+
 /// Error computing the set of histogram bins.
 #[derive(Debug, Clone)]
 pub enum BinsBuildError {
@@ -27,6 +160,13 @@ pub enum BinsBuildError {
 	EmptyInput,
 	/// The strategy for computing appropriate bins failed.
 	Strategy,
+	/// The input array is constant, i.e. its minimum equals its maximum.
+	ConstantInput,
+	/// The weights do not match the observations in length, are negative, not finite, or sum up
+	/// to zero.
+	InvalidWeights,
+	/// The padding fraction is negative or not finite.
+	InvalidPadding,
 	#[doc(hidden)]
 	__NonExhaustive,
 }
@@ -41,14 +181,35 @@ impl BinsBuildError {
 	pub fn is_strategy(&self) -> bool {
 		matches!(self, BinsBuildError::Strategy)
 	}
+
+	/// Returns whether `self` is the `InvalidWeights` variant.
+	pub fn is_invalid_weights(&self) -> bool {
+		matches!(self, BinsBuildError::InvalidWeights)
+	}
+
+	/// Returns whether `self` is the `ConstantInput` variant.
+	pub fn is_constant_input(&self) -> bool {
+		matches!(self, BinsBuildError::ConstantInput)
+	}
+
+	/// Returns whether `self` is the `InvalidPadding` variant.
+	pub fn is_invalid_padding(&self) -> bool {
+		matches!(self, BinsBuildError::InvalidPadding)
+	}
 }
 
 impl fmt::Display for BinsBuildError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "The strategy failed to determine a non-zero bin width.")
+		match self {
+			BinsBuildError::InvalidWeights => write!(f, "The weights are invalid."),
+			BinsBuildError::ConstantInput => write!(f, "The input array is constant."),
+			BinsBuildError::InvalidPadding => write!(f, "The padding fraction is invalid."),
+			_ => write!(f, "The strategy failed to determine a non-zero bin width."),
+		}
 	}
 }
 
+#[cfg(feature = "std")]
 impl error::Error for BinsBuildError {
 	fn description(&self) -> &str {
 		"The strategy failed to determine a non-zero bin width."
@@ -69,3 +230,185 @@ impl From<MinMaxError> for BinsBuildError {
 		}
 	}
 }
+
+/// Error constructing the counts of a histogram from a [`Grid`](super::Grid).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum GridError {
+	/// The total number of bins overflows `usize`, the counts would exceed `isize::MAX` bytes, or
+	/// the allocator fails to provide them.
+	TooManyBins,
+	#[doc(hidden)]
+	__NonExhaustive,
+}
+
+impl GridError {
+	/// Returns whether `self` is the `TooManyBins` variant.
+	pub fn is_too_many_bins(&self) -> bool {
+		matches!(self, GridError::TooManyBins)
+	}
+}
+
+impl fmt::Display for GridError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "The grid has too many bins to allocate its counts.")
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for GridError {}
+
+/// Error rebinning a histogram onto a coarser grid.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RebinError {
+	/// The grids differ in their number of dimensions.
+	DimensionMismatch,
+	/// An edge of the coarse grid is not an edge of the fine grid.
+	MisalignedEdges,
+	#[doc(hidden)]
+	__NonExhaustive,
+}
+
+impl RebinError {
+	/// Returns whether `self` is the `DimensionMismatch` variant.
+	pub fn is_dimension_mismatch(&self) -> bool {
+		matches!(self, RebinError::DimensionMismatch)
+	}
+
+	/// Returns whether `self` is the `MisalignedEdges` variant.
+	pub fn is_misaligned_edges(&self) -> bool {
+		matches!(self, RebinError::MisalignedEdges)
+	}
+}
+
+impl fmt::Display for RebinError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			RebinError::DimensionMismatch => write!(f, "The grids differ in dimensions."),
+			RebinError::MisalignedEdges | RebinError::__NonExhaustive => {
+				write!(f, "The coarse grid is not nested in the fine grid.")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for RebinError {}
+
+/// Error slicing a histogram at a bin along one of its axes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SliceError {
+	/// The axis is out of bounds for the dimensions of the histogram.
+	AxisOutOfBounds,
+	/// The bin index is out of bounds for the number of bins along the axis.
+	IndexOutOfBounds,
+	#[doc(hidden)]
+	__NonExhaustive,
+}
+
+impl SliceError {
+	/// Returns whether `self` is the `AxisOutOfBounds` variant.
+	pub fn is_axis_out_of_bounds(&self) -> bool {
+		matches!(self, SliceError::AxisOutOfBounds)
+	}
+
+	/// Returns whether `self` is the `IndexOutOfBounds` variant.
+	pub fn is_index_out_of_bounds(&self) -> bool {
+		matches!(self, SliceError::IndexOutOfBounds)
+	}
+}
+
+impl fmt::Display for SliceError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			SliceError::AxisOutOfBounds => write!(f, "The axis is out of bounds."),
+			SliceError::IndexOutOfBounds | SliceError::__NonExhaustive => {
+				write!(f, "The bin index is out of bounds.")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for SliceError {}
+
+/// Error permuting the axes of a histogram.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PermuteError {
+	/// The number of axes differs from the dimensions of the histogram.
+	DimensionMismatch,
+	/// An axis is out of bounds or occurs more than once.
+	NotAPermutation,
+	#[doc(hidden)]
+	__NonExhaustive,
+}
+
+impl PermuteError {
+	/// Returns whether `self` is the `DimensionMismatch` variant.
+	pub fn is_dimension_mismatch(&self) -> bool {
+		matches!(self, PermuteError::DimensionMismatch)
+	}
+
+	/// Returns whether `self` is the `NotAPermutation` variant.
+	pub fn is_not_a_permutation(&self) -> bool {
+		matches!(self, PermuteError::NotAPermutation)
+	}
+}
+
+impl fmt::Display for PermuteError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			PermuteError::DimensionMismatch => write!(f, "The number of axes does not match."),
+			PermuteError::NotAPermutation | PermuteError::__NonExhaustive => {
+				write!(f, "The axes are not a permutation.")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for PermuteError {}
+
+/// Error validating breakpoints as edges of bins.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum EdgesError {
+	/// The breakpoints are not sorted in increasing order.
+	NotSorted,
+	/// There are fewer than two breakpoints, i.e. not a single bin.
+	TooFew,
+	/// The breakpoints contain duplicates, i.e. empty bins.
+	Duplicate,
+	#[doc(hidden)]
+	__NonExhaustive,
+}
+
+impl EdgesError {
+	/// Returns whether `self` is the `NotSorted` variant.
+	pub fn is_not_sorted(&self) -> bool {
+		matches!(self, EdgesError::NotSorted)
+	}
+
+	/// Returns whether `self` is the `TooFew` variant.
+	pub fn is_too_few(&self) -> bool {
+		matches!(self, EdgesError::TooFew)
+	}
+
+	/// Returns whether `self` is the `Duplicate` variant.
+	pub fn is_duplicate(&self) -> bool {
+		matches!(self, EdgesError::Duplicate)
+	}
+}
+
+impl fmt::Display for EdgesError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			EdgesError::NotSorted => write!(f, "The breakpoints are not sorted."),
+			EdgesError::TooFew => write!(f, "At least two breakpoints are required."),
+			EdgesError::Duplicate | EdgesError::__NonExhaustive => {
+				write!(f, "The breakpoints contain duplicates.")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for EdgesError {}
@@ -1,9 +1,26 @@
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 
-use super::{bins::Bins, errors::BinsBuildError, strategies::BinsBuildingStrategy};
+use super::{
+	bins::{Bins, Edges},
+	errors::{DimensionMismatch, EdgesError},
+};
+#[cfg(feature = "std")]
+use super::{errors::BinsBuildError, strategies::BinsBuildingStrategy};
+use alloc::vec::Vec;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+#[cfg(feature = "approx")]
+use core::ops::Deref;
+use core::{fmt, ops::Range};
 use itertools::izip;
-use ndarray::{ArrayBase, Axis, Data, Ix1, Ix2};
-use std::ops::Range;
+use ndarray::{Array1, ArrayBase, Data, Ix1};
+#[cfg(feature = "std")]
+use ndarray::{Array2, ArrayView2, Axis, Ix2};
+#[cfg(feature = "std")]
+use ndarray_slice::Slice1Ext;
+use num_traits::ToPrimitive;
+#[cfg(feature = "std")]
+use num_traits::{FromPrimitive, NumOps};
 
 /// An orthogonal partition of a rectangular region in an *n*-dimensional space, e.g.
 /// [*a*<sub>0</sub>, *b*<sub>0</sub>) × ⋯ × [*a*<sub>*n*−1</sub>, *b*<sub>*n*−1</sub>),
@@ -90,6 +107,7 @@ use std::ops::Range;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Grid<A: Ord + Send> {
 	projections: Vec<Bins<A>>,
+	open_ends: bool,
 }
 
 impl<A: Ord + Send> From<Vec<Bins<A>>> for Grid<A> {
@@ -102,11 +120,221 @@ impl<A: Ord + Send> From<Vec<Bins<A>>> for Grid<A> {
 	///
 	/// [`GridBuilder`]: struct.GridBuilder.html
 	fn from(projections: Vec<Bins<A>>) -> Self {
-		Grid { projections }
+		Grid {
+			projections,
+			open_ends: false,
+		}
+	}
+}
+
+impl<A: Ord + Send + fmt::Display> fmt::Display for Grid<A> {
+	/// Formats the bins along each coordinate axis, one axis per line.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let grid = Grid::from(vec![
+	/// 	Bins::new(Edges::from(vec![0, 1, 2])),
+	/// 	Bins::new(Edges::from(vec![-1, 1])),
+	/// ]);
+	/// assert_eq!(grid.to_string(), "axis 0: [0, 1) [1, 2)\naxis 1: [-1, 1)");
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (axis, bins) in self.projections.iter().enumerate() {
+			if axis > 0 {
+				writeln!(f)?;
+			}
+			write!(f, "axis {axis}: {bins}")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "approx")]
+impl<A> AbsDiffEq for Grid<A>
+where
+	A: Ord + Send + Deref,
+	A::Target: AbsDiffEq,
+	<A::Target as AbsDiffEq>::Epsilon: Clone,
+{
+	type Epsilon = <A::Target as AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		A::Target::default_epsilon()
+	}
+
+	/// Returns whether both grids have the same number of dimensions and open ends and their bins
+	/// along each axis are equal up to the absolute difference `epsilon`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use approx::assert_abs_diff_eq;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	o64,
+	/// };
+	///
+	/// let bins = |edges: [f64; 2]| Bins::new(Edges::from(edges.map(o64).to_vec()));
+	/// let grid = Grid::from(vec![bins([0., 0.1 + 0.2]), bins([-1., 1.])]);
+	/// assert_abs_diff_eq!(grid, Grid::from(vec![bins([0., 0.3]), bins([-1., 1.])]));
+	/// ```
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.open_ends == other.open_ends
+			&& self.ndim() == other.ndim()
+			&& izip!(&self.projections, &other.projections)
+				.all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+	}
+}
+
+#[cfg(feature = "approx")]
+impl<A> RelativeEq for Grid<A>
+where
+	A: Ord + Send + Deref,
+	A::Target: RelativeEq,
+	<A::Target as AbsDiffEq>::Epsilon: Clone,
+{
+	fn default_max_relative() -> Self::Epsilon {
+		A::Target::default_max_relative()
+	}
+
+	/// Returns whether both grids have the same number of dimensions and open ends and their bins
+	/// along each axis are equal up to the absolute difference `epsilon` or the relative
+	/// difference `max_relative`.
+	fn relative_eq(
+		&self,
+		other: &Self,
+		epsilon: Self::Epsilon,
+		max_relative: Self::Epsilon,
+	) -> bool {
+		self.open_ends == other.open_ends
+			&& self.ndim() == other.ndim()
+			&& izip!(&self.projections, &other.projections)
+				.all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
 	}
 }
 
 impl<A: Ord + Send> Grid<A> {
+	/// Returns a `Grid` given the edges along each coordinate axis, e.g. as exported by another
+	/// tool.
+	///
+	/// The `i`-th vector holds the edges of the projection of the grid onto the `i`-th axis. See
+	/// [`Bins::try_from_breakpoints`] for how they are validated.
+	///
+	/// # Errors
+	///
+	/// Returns [`EdgesError`] if the edges along any axis are fewer than two, not sorted in
+	/// increasing order, or contain duplicates.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let grid = Grid::from_edges(vec![vec![0, 1, 2], vec![-1, 0, 1, 2]])?;
+	/// let expected_grid = Grid::from(vec![
+	/// 	Bins::new(Edges::from(vec![0, 1, 2])),
+	/// 	Bins::new(Edges::from(vec![-1, 0, 1, 2])),
+	/// ]);
+	/// assert_eq!(grid, expected_grid);
+	/// assert!(Grid::from_edges(vec![vec![0, 1], vec![1, 0]]).is_err());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	///
+	/// [`Bins::try_from_breakpoints`]: struct.Bins.html#method.try_from_breakpoints
+	/// [`EdgesError`]: errors/enum.EdgesError.html
+	pub fn from_edges(edges: Vec<Vec<A>>) -> Result<Self, EdgesError> {
+		let projections = edges
+			.into_iter()
+			.map(Bins::try_from_breakpoints)
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(Self::from(projections))
+	}
+
+	/// Returns the grid with open-ended first and last bins along each axis, i.e. extending to
+	/// −∞ and +∞, respectively, so no point is ever outside the grid.
+	///
+	/// This only affects where points are looked up, e.g. via [`index_of`] or when adding
+	/// observations to a [`Histogram`], whereas the bins themselves, e.g. as returned by
+	/// [`index`], keep their edges. Interior bins are unaffected.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+	/// let grid = Grid::from(vec![bins]);
+	/// assert_eq!(grid.index_of(&array![-5]), None);
+	/// let grid = grid.with_open_ends();
+	/// assert!(grid.has_open_ends());
+	/// assert_eq!(grid.index_of(&array![-5]), Some(vec![0]));
+	/// assert_eq!(grid.index_of(&array![1]), Some(vec![1]));
+	/// assert_eq!(grid.index_of(&array![3]), Some(vec![2]));
+	/// ```
+	///
+	/// [`index_of`]: #method.index_of
+	/// [`index`]: #method.index
+	/// [`Histogram`]: struct.Histogram.html
+	#[must_use]
+	pub fn with_open_ends(mut self) -> Self {
+		self.open_ends = true;
+		self
+	}
+
+	/// Returns whether the first and last bins along each axis are open-ended, see
+	/// [`with_open_ends`].
+	///
+	/// [`with_open_ends`]: #method.with_open_ends
+	#[must_use]
+	pub fn has_open_ends(&self) -> bool {
+		self.open_ends
+	}
+
+	/// Returns the grid with the edges along each axis transformed by `f`, e.g. to relabel them in
+	/// another unit, see [`Edges::map`]. Open ends are kept.
+	///
+	/// **Panics** in debug builds if the mapping is not strictly increasing.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	o64,
+	/// };
+	///
+	/// // Raw ADC counts to volts.
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 512, 1024]))]);
+	/// let grid = grid.map(|&count| o64(f64::from(count) * 5. / 1024.));
+	/// assert_eq!(grid.index(&[1]), vec![o64(2.5)..o64(5.)]);
+	/// ```
+	///
+	/// [`Edges::map`]: struct.Edges.html#method.map
+	#[must_use]
+	pub fn map<B, F>(&self, f: F) -> Grid<B>
+	where
+		B: Ord + Send,
+		F: Fn(&A) -> B,
+	{
+		Grid {
+			projections: self.projections.iter().map(|bins| bins.map(&f)).collect(),
+			open_ends: self.open_ends,
+		}
+	}
+
+	/// Returns the index of the bin in `bins`, a projection of the grid, containing `value`.
+	pub(crate) fn bin_index_of(&self, bins: &Bins<A>, value: &A) -> Option<usize> {
+		if self.open_ends {
+			bins.clamped_index_of(value)
+		} else {
+			bins.index_of(value)
+		}
+	}
+
 	/// Returns the number of dimensions of the region partitioned by the grid.
 	///
 	/// # Examples
@@ -125,6 +353,40 @@ impl<A: Ord + Send> Grid<A> {
 		self.projections.len()
 	}
 
+	/// Checks whether `ndim` equals the number of dimensions of the region partitioned by the grid.
+	///
+	/// # Errors
+	///
+	/// Returns [`DimensionMismatch`] if `ndim` differs from [`Self::ndim`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{errors::DimensionMismatch, Bins, Edges, Grid};
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1]));
+	/// let square_grid = Grid::from(vec![bins.clone(), bins.clone()]);
+	///
+	/// assert_eq!(square_grid.check_ndim(2), Ok(()));
+	/// assert_eq!(
+	/// 	square_grid.check_ndim(3),
+	/// 	Err(DimensionMismatch {
+	/// 		expected: 2,
+	/// 		found: 3
+	/// 	})
+	/// );
+	/// ```
+	pub fn check_ndim(&self, ndim: usize) -> Result<(), DimensionMismatch> {
+		if ndim == self.ndim() {
+			Ok(())
+		} else {
+			Err(DimensionMismatch {
+				expected: self.ndim(),
+				found: ndim,
+			})
+		}
+	}
+
 	/// Returns the numbers of bins along each coordinate axis.
 	///
 	/// # Examples
@@ -145,21 +407,148 @@ impl<A: Ord + Send> Grid<A> {
 		self.projections.iter().map(Bins::len).collect()
 	}
 
+	/// Returns the number of bins along the coordinate axis `axis`, or `None` if `axis` is out of
+	/// bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let bins_x = Bins::new(Edges::from(vec![0, 1]));
+	/// let bins_y = Bins::new(Edges::from(vec![-1, 0, 1]));
+	/// let square_grid = Grid::from(vec![bins_x, bins_y]);
+	///
+	/// assert_eq!(square_grid.n_bins(1), Some(2));
+	/// assert_eq!(square_grid.n_bins(2), None);
+	/// ```
+	#[must_use]
+	pub fn n_bins(&self, axis: usize) -> Option<usize> {
+		self.projections.get(axis).map(Bins::len)
+	}
+
+	/// Returns the grid projection on the coordinate axis `axis`, or `None` if `axis` is out of
+	/// bounds.
+	#[must_use]
+	pub fn axis_bins(&self, axis: usize) -> Option<&Bins<A>> {
+		self.projections.get(axis)
+	}
+
+	/// Returns the centers of the bins along `axis`, see [`Bins::centers`], or `None` if `axis` is
+	/// out of bounds.
+	///
+	/// # Panics
+	///
+	/// Panics if an edge cannot be converted into `f64`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let grid = Grid::from(vec![
+	/// 	Bins::new(Edges::from(vec![0, 2, 4])),
+	/// 	Bins::new(Edges::from(vec![0, 1, 5])),
+	/// ]);
+	/// assert_eq!(grid.bin_centers(0), Some(array![1., 3.]));
+	/// assert_eq!(grid.bin_centers(1), Some(array![0.5, 3.]));
+	/// assert_eq!(grid.bin_centers(2), None);
+	/// ```
+	///
+	/// [`Bins::centers`]: struct.Bins.html#method.centers
+	#[must_use]
+	pub fn bin_centers(&self, axis: usize) -> Option<Array1<f64>>
+	where
+		A: ToPrimitive,
+	{
+		self.projections.get(axis).map(Bins::centers)
+	}
+
+	/// Returns the `n`-dimensional volume of the bin at `index`, i.e. the product of its widths
+	/// along each axis, or `None` if `index` has not `n` components or is out of bounds.
+	///
+	/// This is required to normalize counts to densities over non-uniform grids. The extreme bins
+	/// of a grid with open ends have the volume spanned by their edges.
+	///
+	/// # Panics
+	///
+	/// Panics if an edge cannot be converted into `f64`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let grid = Grid::from(vec![
+	/// 	Bins::new(Edges::from(vec![0, 1, 4])),
+	/// 	Bins::new(Edges::from(vec![0, 2, 7])),
+	/// ]);
+	/// assert_eq!(grid.volume_of(&[0, 1]), Some(5.));
+	/// assert_eq!(grid.volume_of(&[1, 1]), Some(15.));
+	/// assert_eq!(grid.volume_of(&[2, 0]), None);
+	/// ```
+	#[must_use]
+	pub fn volume_of(&self, index: &[usize]) -> Option<f64>
+	where
+		A: ToPrimitive,
+	{
+		if index.len() != self.ndim() {
+			return None;
+		}
+		izip!(&self.projections, index)
+			.map(|(bins, &i)| {
+				let edges = bins.edges();
+				(i < bins.len())
+					.then(|| edges[i + 1].to_f64().unwrap() - edges[i].to_f64().unwrap())
+			})
+			.product()
+	}
+
 	/// Returns the grid projections on each coordinate axis as a slice of immutable references.
 	#[must_use]
 	pub fn projections(&self) -> &[Bins<A>] {
 		&self.projections
 	}
 
+	/// Returns the edges of the grid projections on each coordinate axis.
+	///
+	/// Together with [`has_open_ends`], they fully describe the binning scheme of the grid, e.g.
+	/// to export it and to reconstruct it via [`from_edges`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let grid = Grid::from(vec![
+	/// 	Bins::new(Edges::from(vec![0, 1, 2])),
+	/// 	Bins::new(Edges::from(vec![-1, 0, 3])),
+	/// ]);
+	/// let edges = grid.edges();
+	/// assert_eq!(edges.len(), 2);
+	/// assert_eq!(edges[1].as_array_view().to_vec(), vec![-1, 0, 3]);
+	/// ```
+	///
+	/// [`has_open_ends`]: #method.has_open_ends
+	/// [`from_edges`]: #method.from_edges
+	#[must_use]
+	pub fn edges(&self) -> Vec<&Edges<A>> {
+		self.projections.iter().map(Bins::edges).collect()
+	}
+
 	/// Returns an `n-dimensional` index, of bins along each axis that contains the point, if one
 	/// exists.
 	///
-	/// Returns `None` if the point is outside the grid.
+	/// Returns `None` if the point is outside the grid, which never happens for a grid with open
+	/// ends, see [`with_open_ends`].
 	///
 	/// # Panics
 	///
 	/// Panics if dimensionality of the point doesn't equal the grid's.
 	///
+	/// [`with_open_ends`]: #method.with_open_ends
+	///
 	/// # Examples
 	///
 	/// Basic usage:
@@ -213,9 +602,84 @@ impl<A: Ord + Send> Grid<A> {
 		point
 			.iter()
 			.zip(self.projections.iter())
-			.map(|(v, e)| e.index_of(v))
+			.map(|(v, e)| self.bin_index_of(e, v))
 			.collect()
 	}
+
+	/// Returns an `n-dimensional` index as [`index_of`] does but without panicking on dimension
+	/// mismatch.
+	///
+	/// # Errors
+	///
+	/// Returns [`DimensionMismatch`] if dimensionality of the point doesn't equal the grid's.
+	///
+	/// [`index_of`]: #method.index_of
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let bins = Bins::new(Edges::from(vec![-1, 0, 1]));
+	/// let square_grid = Grid::from(vec![bins.clone(), bins]);
+	///
+	/// assert_eq!(square_grid.try_index_of(&array![0, -1]), Ok(Some(vec![1, 0])));
+	/// assert_eq!(square_grid.try_index_of(&array![0, 1]), Ok(None));
+	/// assert!(square_grid.try_index_of(&array![0, -1, 0]).is_err());
+	/// ```
+	pub fn try_index_of<S>(
+		&self,
+		point: &ArrayBase<S, Ix1>,
+	) -> Result<Option<Vec<usize>>, DimensionMismatch>
+	where
+		S: Data<Elem = A>,
+	{
+		self.check_ndim(point.len())?;
+		Ok(self.index_of(point))
+	}
+
+	/// Returns whether the point lies within the grid, i.e. whether each coordinate lies within the
+	/// right-open range spanned by the edges along its axis, or always `true` for a grid with open
+	/// ends.
+	///
+	/// This is equivalent to `self.index_of(point).is_some()` without allocating the index.
+	///
+	/// # Panics
+	///
+	/// Panics if dimensionality of the point doesn't equal the grid's.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let bins = Bins::new(Edges::from(vec![-1, 0, 1]));
+	/// let square_grid = Grid::from(vec![bins.clone(), bins]);
+	///
+	/// assert!(square_grid.contains(&array![0, -1]));
+	/// // Bins are right-open.
+	/// assert!(!square_grid.contains(&array![0, 1]));
+	/// ```
+	#[must_use]
+	pub fn contains<S>(&self, point: &ArrayBase<S, Ix1>) -> bool
+	where
+		S: Data<Elem = A>,
+	{
+		assert_eq!(
+			point.len(),
+			self.ndim(),
+			"Dimension mismatch: the point has {:?} dimensions, the grid \
+			 expected {:?} dimensions.",
+			point.len(),
+			self.ndim()
+		);
+		point
+			.iter()
+			.zip(self.projections.iter())
+			.all(|(v, e)| self.bin_index_of(e, v).is_some())
+	}
 }
 
 impl<A: Ord + Send + Clone> Grid<A> {
@@ -274,6 +738,38 @@ impl<A: Ord + Send + Clone> Grid<A> {
 			.map(|(bins, &i)| bins.index(i))
 			.collect()
 	}
+
+	/// Returns the extent `(first_edge, last_edge)` of the grid along each coordinate axis.
+	///
+	/// These are the bounds to plot the grid within or to check whether two grids cover the same
+	/// domain without comparing all their edges. The extreme bins of a grid with open ends extend
+	/// beyond these bounds.
+	///
+	/// # Panics
+	///
+	/// Panics if the bins along an axis have no edges.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid};
+	///
+	/// let grid = Grid::from(vec![
+	/// 	Bins::new(Edges::from(vec![0, 1, 4])),
+	/// 	Bins::new(Edges::from(vec![-2, 7])),
+	/// ]);
+	/// assert_eq!(grid.extents(), vec![(0, 4), (-2, 7)]);
+	/// ```
+	#[must_use]
+	pub fn extents(&self) -> Vec<(A, A)> {
+		self.projections
+			.iter()
+			.map(|bins| {
+				let edges = bins.edges();
+				(edges[0].clone(), edges[edges.len() - 1].clone())
+			})
+			.collect()
+	}
 }
 
 /// A builder used to create [`Grid`] instances for [`histogram`] computations.
@@ -306,10 +802,12 @@ impl<A: Ord + Send + Clone> Grid<A> {
 /// [`histogram`]: trait.HistogramExt.html
 /// [`strategy`]: strategies/index.html
 #[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "std")]
 pub struct GridBuilder<B: BinsBuildingStrategy> {
 	bin_builders: Vec<B>,
 }
 
+#[cfg(feature = "std")]
 impl<A, B> GridBuilder<B>
 where
 	A: Ord + Send,
@@ -342,6 +840,131 @@ where
 		Ok(Self { bin_builders })
 	}
 
+	/// Returns a `GridBuilder` for building a [`Grid`] with a given [`strategy`] and some weighted
+	/// observations in a 2-dimensional array with shape `(n_observations, n_dimension)` and
+	/// `weights` with shape `(n_observations)`.
+	///
+	/// See [`BinsBuildingStrategy::from_array_weighted_with_max`] for which strategies honor the
+	/// weights.
+	///
+	/// # Errors
+	///
+	/// It returns [`BinsBuildError`] if the weights are invalid or if it is not possible to build a
+	/// [`Grid`] given the observed data according to the chosen [`strategy`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{strategies::Sturges, GridBuilder};
+	///
+	/// let observations = array![[0], [1], [2], [3], [4], [5], [6], [7], [8]];
+	/// let grid = GridBuilder::<Sturges<i32>>::from_array(&observations)
+	/// 	.unwrap()
+	/// 	.build();
+	/// assert_eq!(grid.projections()[0].len(), 5);
+	/// // Effectively a single observation.
+	/// let weights = array![1., 1., 1., 1., 1., 1., 1., 1., 1e6];
+	/// let grid = GridBuilder::<Sturges<i32>>::from_array_weighted(&observations, &weights)
+	/// 	.unwrap()
+	/// 	.build();
+	/// assert_eq!(grid.projections()[0].len(), 2);
+	/// ```
+	///
+	/// [`Grid`]: struct.Grid.html
+	/// [`strategy`]: strategies/index.html
+	/// [`BinsBuildError`]: errors/enum.BinsBuildError.html
+	pub fn from_array_weighted<S, S2>(
+		array: &ArrayBase<S, Ix2>,
+		weights: &ArrayBase<S2, Ix1>,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = A>,
+		S2: Data<Elem = f64>,
+	{
+		let bin_builders = array
+			.axis_iter(Axis(1))
+			.map(|data| B::from_array_weighted(&data, weights))
+			.collect::<Result<Vec<B>, BinsBuildError>>()?;
+		Ok(Self { bin_builders })
+	}
+
+	/// Returns a `GridBuilder` for building a [`Grid`] with a given [`strategy`] and some
+	/// observations with each of their columns sorted once in advance, see [`SortedColumns`].
+	///
+	/// This avoids sorting or selecting the observations again for each strategy depending on
+	/// quantiles, e.g. when comparing grids of several strategies for the same observations. The
+	/// builder is the same as of [`from_array`].
+	///
+	/// # Errors
+	///
+	/// It returns [`BinsBuildError`] if it is not possible to build a [`Grid`] given
+	/// the observed data according to the chosen [`strategy`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{
+	/// 	strategies::{Auto, FreedmanDiaconis},
+	/// 	GridBuilder, SortedColumns,
+	/// };
+	///
+	/// let observations = array![[1, 8], [4, 2], [5, 7], [2, 9], [9, 1], [7, 3], [3, 5], [6, 4]];
+	/// let sorted = SortedColumns::new(&observations);
+	/// for grid in [
+	/// 	GridBuilder::<Auto<i32>>::from_sorted(&sorted).unwrap().build(),
+	/// 	GridBuilder::<FreedmanDiaconis<i32>>::from_sorted(&sorted)
+	/// 		.unwrap()
+	/// 		.build(),
+	/// ] {
+	/// 	assert_eq!(grid.ndim(), 2);
+	/// }
+	/// ```
+	///
+	/// [`Grid`]: struct.Grid.html
+	/// [`strategy`]: strategies/index.html
+	/// [`BinsBuildError`]: errors/enum.BinsBuildError.html
+	/// [`SortedColumns`]: struct.SortedColumns.html
+	/// [`from_array`]: #method.from_array
+	pub fn from_sorted(columns: &SortedColumns<'_, A>) -> Result<Self, BinsBuildError>
+	where
+		A: Clone,
+	{
+		let bin_builders = izip!(columns.observations.columns(), columns.sorted.rows())
+			.map(|(data, sorted)| B::from_sorted(&data, &sorted))
+			.collect::<Result<Vec<B>, BinsBuildError>>()?;
+		Ok(Self { bin_builders })
+	}
+
+	/// Returns the number of bins along each axis inferred in [`from_array`] without building the
+	/// [`Grid`].
+	///
+	/// Their product is the number of counts of a histogram over the built grid, e.g. to check the
+	/// memory usage beforehand.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{strategies::Sqrt, GridBuilder};
+	///
+	/// let observations = array![[0, 0], [1, 4], [2, 8], [3, 12]];
+	/// let builder = GridBuilder::<Sqrt<i32>>::from_array(&observations).unwrap();
+	/// assert_eq!(builder.n_bins_per_axis(), vec![4, 3]);
+	/// assert_eq!(builder.build().shape(), builder.n_bins_per_axis());
+	/// ```
+	///
+	/// [`Grid`]: struct.Grid.html
+	/// [`from_array`]: #method.from_array
+	#[must_use]
+	pub fn n_bins_per_axis(&self) -> Vec<usize> {
+		self.bin_builders
+			.iter()
+			.map(BinsBuildingStrategy::n_bins)
+			.collect()
+	}
+
 	/// Returns a [`Grid`] instance, with building parameters infered in [`from_array`], according
 	/// to the specified [`strategy`] and observations provided.
 	///
@@ -361,4 +984,507 @@ where
 			.collect();
 		Grid::from(projections)
 	}
+
+	/// Returns a [`Grid`] instance like [`build`] but with the range along each axis extended by
+	/// `fraction` of its span on both ends, so the extreme observations are not crowded against
+	/// the outer edges.
+	///
+	/// The inferred bin width is kept, hence the range is extended by whole bins, i.e. by
+	/// `ceil(fraction × n)` bins on both ends where `n` is the inferred number of bins along the
+	/// axis. A `fraction` of zero is equivalent to [`build`].
+	///
+	/// # Errors
+	///
+	/// Returns [`BinsBuildError::InvalidPadding`] if `fraction` is negative or not finite.
+	///
+	/// # Panics
+	///
+	/// Panics if the extended edges overflow `A`, e.g. when extending below zero for unsigned
+	/// integers.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{strategies::Sqrt, Bins, Edges, Grid, GridBuilder};
+	///
+	/// let observations = array![[10], [14], [15], [17], [20], [21], [22], [24], [25]];
+	/// let builder = GridBuilder::<Sqrt<i32>>::from_array(&observations).unwrap();
+	/// assert_eq!(
+	/// 	builder.build(),
+	/// 	Grid::from(vec![Bins::new(Edges::from(vec![10, 15, 20, 25, 30]))])
+	/// );
+	/// assert_eq!(
+	/// 	builder.build_with_padding(0.25).unwrap(),
+	/// 	Grid::from(vec![Bins::new(Edges::from(vec![
+	/// 		5, 10, 15, 20, 25, 30, 35
+	/// 	]))])
+	/// );
+	/// assert!(builder
+	/// 	.build_with_padding(-0.25)
+	/// 	.unwrap_err()
+	/// 	.is_invalid_padding());
+	/// ```
+	///
+	/// [`Grid`]: struct.Grid.html
+	/// [`build`]: #method.build
+	/// [`BinsBuildError::InvalidPadding`]: errors/enum.BinsBuildError.html#variant.InvalidPadding
+	pub fn build_with_padding(&self, fraction: f64) -> Result<Grid<A>, BinsBuildError>
+	where
+		A: Clone + FromPrimitive + NumOps,
+	{
+		if !(fraction >= 0. && fraction.is_finite()) {
+			return Err(BinsBuildError::InvalidPadding);
+		}
+		let projections: Vec<_> = self
+			.bin_builders
+			.iter()
+			.map(|builder| {
+				let bins = builder.build();
+				let n_bins = bins.len();
+				let n_pad = f64::from_usize(n_bins).unwrap() * fraction;
+				let n_pad = usize::from_f64(n_pad.ceil()).unwrap();
+				let Range { start, end } = bins.index(0);
+				let bin_width = end - start.clone();
+				let start = start - A::from_usize(n_pad).unwrap() * bin_width.clone();
+				let edges = (0..=n_bins + 2 * n_pad)
+					.map(|i| start.clone() + A::from_usize(i).unwrap() * bin_width.clone())
+					.collect();
+				Bins::new(Edges::from_sorted_unchecked(edges))
+			})
+			.collect();
+		Ok(Grid::from(projections))
+	}
+}
+
+/// Observations in a 2-dimensional array with shape `(n_observations, n_dimension)` along with a
+/// sorted copy of each of their columns, shared among strategies by [`GridBuilder::from_sorted`].
+///
+/// [`GridBuilder::from_sorted`]: struct.GridBuilder.html#method.from_sorted
+#[cfg(feature = "std")]
+pub struct SortedColumns<'a, A> {
+	observations: ArrayView2<'a, A>,
+	sorted: Array2<A>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, A> SortedColumns<'a, A>
+where
+	A: Ord + Send + Clone,
+{
+	/// Borrows the `observations` and sorts a copy of each of their columns, in parallel if the
+	/// `rayon` feature is enabled.
+	///
+	/// Complexity: O(`n` `m` log `m`) where `n` is the number of dimensions and `m` the number of
+	/// observations.
+	#[must_use]
+	pub fn new<S>(observations: &'a ArrayBase<S, Ix2>) -> Self
+	where
+		S: Data<Elem = A>,
+	{
+		let observations = observations.view();
+		// Transposed in standard layout, hence each column becomes a contiguous row.
+		let mut sorted = observations.t().as_standard_layout().into_owned();
+		for mut column in sorted.rows_mut() {
+			#[cfg(feature = "rayon")]
+			column.par_sort_unstable();
+			#[cfg(not(feature = "rayon"))]
+			column.sort_unstable();
+		}
+		Self {
+			observations,
+			sorted,
+		}
+	}
+
+	/// Returns the number of dimensions of the observations.
+	#[must_use]
+	pub fn ndim(&self) -> usize {
+		self.observations.ncols()
+	}
+}
+
+#[cfg(test)]
+mod grid_tests {
+	use super::{Bins, Grid};
+	#[cfg(feature = "std")]
+	use super::{GridBuilder, SortedColumns};
+	#[cfg(feature = "std")]
+	use crate::histogram::strategies::{
+		Auto, BinsBuildingStrategy, FreedmanDiaconis, Sqrt, Sturges,
+	};
+	use crate::histogram::{
+		errors::{DimensionMismatch, EdgesError},
+		Edges,
+	};
+	#[cfg(feature = "std")]
+	use crate::o64;
+	use alloc::{string::ToString, vec};
+	use ndarray::array;
+	#[cfg(feature = "std")]
+	use ndarray::{s, Array2};
+
+	fn cube_grid() -> Grid<i32> {
+		Grid::from(vec![
+			Bins::new(Edges::from(vec![0, 1])),
+			Bins::new(Edges::from(vec![0, 1, 2])),
+			Bins::new(Edges::from(vec![0, 1, 2, 3])),
+		])
+	}
+
+	#[test]
+	fn from_edges_matches_manual_construction() {
+		let grid = Grid::from_edges(vec![vec![0, 1], vec![0, 1, 2], vec![0, 1, 2, 3]]).unwrap();
+		let expected_grid = cube_grid();
+		assert_eq!(grid, expected_grid);
+		for point in [
+			array![0, 1, 2],
+			array![0, 2, 2],
+			array![1, 0, 0],
+			array![0, 0, -1],
+		] {
+			assert_eq!(grid.index_of(&point), expected_grid.index_of(&point));
+		}
+		assert_eq!(grid.index_of(&array![0, 1, 2]), Some(vec![0, 1, 2]));
+	}
+
+	#[test]
+	fn from_edges_with_invalid_edges_is_bad() {
+		assert_eq!(
+			Grid::from_edges(vec![vec![0, 1], vec![0]]),
+			Err(EdgesError::TooFew)
+		);
+		assert_eq!(
+			Grid::from_edges(vec![vec![0, 2, 1]]),
+			Err(EdgesError::NotSorted)
+		);
+		assert_eq!(
+			Grid::from_edges(vec![vec![0, 1, 1]]),
+			Err(EdgesError::Duplicate)
+		);
+	}
+
+	#[test]
+	fn open_ends_catch_extreme_values() {
+		let grid = cube_grid().with_open_ends();
+		assert!(grid.has_open_ends());
+		assert!(!cube_grid().has_open_ends());
+		assert_ne!(grid, cube_grid());
+		for point in [
+			array![0, 0, 0],
+			array![0, 1, 2],
+			array![0, 0, 1],
+			array![0, 1, 0],
+		] {
+			assert_eq!(grid.index_of(&point), cube_grid().index_of(&point));
+		}
+		assert_eq!(grid.index_of(&array![-9, 1, 1]), Some(vec![0, 1, 1]));
+		assert_eq!(grid.index_of(&array![9, -9, 3]), Some(vec![0, 0, 2]));
+		assert_eq!(grid.index_of(&array![1, 2, i32::MAX]), Some(vec![0, 1, 2]));
+		assert!(grid.contains(&array![i32::MIN, i32::MAX, 100]));
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn map_applies_linear_transform() {
+		let grid = cube_grid().with_open_ends();
+		let mapped = grid.map(|&edge| o64(2. * f64::from(edge) - 1.));
+		let expected = Grid::from(vec![
+			Bins::new(Edges::from(vec![o64(-1.), o64(1.)])),
+			Bins::new(Edges::from(vec![o64(-1.), o64(1.), o64(3.)])),
+			Bins::new(Edges::from(vec![o64(-1.), o64(1.), o64(3.), o64(5.)])),
+		])
+		.with_open_ends();
+		assert_eq!(mapped, expected);
+		assert_eq!(
+			mapped.index_of(&array![o64(0.), o64(2.), o64(0.)]),
+			grid.index_of(&array![0, 1, 0])
+		);
+	}
+
+	#[test]
+	fn display_axis_by_axis() {
+		assert_eq!(
+			cube_grid().to_string(),
+			"axis 0: [0, 1)\naxis 1: [0, 1) [1, 2)\naxis 2: [0, 1) [1, 2) [2, 3)"
+		);
+		assert_eq!(Grid::<i32>::from(vec![]).to_string(), "");
+	}
+
+	#[test]
+	fn contains_points_within_extent() {
+		let grid = cube_grid();
+		assert!(grid.contains(&array![0, 0, 0]));
+		assert!(grid.contains(&array![0, 1, 2]));
+		// Out of range along a single axis.
+		assert!(!grid.contains(&array![0, 1, -1]));
+		assert!(!grid.contains(&array![0, 7, 2]));
+		// The maximum edge is excluded.
+		assert!(!grid.contains(&array![1, 1, 2]));
+		assert!(!grid.contains(&array![0, 1, 3]));
+		for point in [array![0, 1, 2], array![0, 2, 2], array![1, 0, 0]] {
+			assert_eq!(grid.contains(&point), grid.index_of(&point).is_some());
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Dimension mismatch")]
+	fn contains_with_wrong_dimension_panics() {
+		let _ = cube_grid().contains(&array![0, 0]);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn volume_of_bins_with_unequal_widths() {
+		let grid = Grid::from(vec![
+			Bins::new(Edges::from(vec![o64(0.), o64(0.5), o64(2.)])),
+			Bins::new(Edges::from(vec![o64(-1.), o64(3.), o64(4.), o64(10.)])),
+		]);
+		assert_eq!(grid.volume_of(&[0, 0]), Some(2.));
+		assert_eq!(grid.volume_of(&[0, 2]), Some(3.));
+		assert_eq!(grid.volume_of(&[1, 0]), Some(6.));
+		assert_eq!(grid.volume_of(&[1, 1]), Some(1.5));
+		let total: f64 = (0..2)
+			.flat_map(|i| (0..3).map(move |j| [i, j]))
+			.map(|index| grid.volume_of(&index).unwrap())
+			.sum();
+		assert!((total - 2. * 11.).abs() < 1e-12);
+		assert_eq!(grid.volume_of(&[2, 0]), None);
+		assert_eq!(grid.volume_of(&[0, 3]), None);
+		assert_eq!(grid.volume_of(&[0]), None);
+		assert_eq!(grid.volume_of(&[0, 0, 0]), None);
+	}
+
+	#[test]
+	fn try_index_of_with_wrong_dimension_is_bad() {
+		let grid = cube_grid();
+		assert_eq!(
+			grid.try_index_of(&array![0, 0]),
+			Err(DimensionMismatch {
+				expected: 3,
+				found: 2
+			})
+		);
+		assert_eq!(
+			grid.try_index_of(&array![0, 0, 0]),
+			Ok(grid.index_of(&array![0, 0, 0]))
+		);
+	}
+
+	#[test]
+	fn n_bins_matches_shape() {
+		let grid = cube_grid();
+		let n_bins = (0..grid.ndim()).map(|axis| grid.n_bins(axis).unwrap());
+		assert!(n_bins.eq(grid.shape()));
+		assert_eq!(grid.n_bins(3), None);
+	}
+
+	#[test]
+	fn edges_reconstruct_grid() {
+		let grid = Grid::from(vec![
+			Bins::new(Edges::from(vec![0, 2, 3])),
+			Bins::new(Edges::from(vec![-4, -1, 1, 5])),
+			Bins::new(Edges::from(vec![7, 8])),
+		]);
+		let edges = grid
+			.edges()
+			.into_iter()
+			.map(|edges| edges.as_array_view().to_vec())
+			.collect();
+		assert_eq!(Grid::from_edges(edges).unwrap(), grid);
+	}
+
+	#[test]
+	fn extents_are_edge_endpoints() {
+		let grid = Grid::from(vec![
+			Bins::new(Edges::from(vec![0, 2, 3])),
+			Bins::new(Edges::from(vec![-4, -1, 1, 5])),
+			Bins::new(Edges::from(vec![7, 8])),
+		]);
+		let extents = grid.extents();
+		assert_eq!(extents, vec![(0, 3), (-4, 5), (7, 8)]);
+		for (&(first, last), edges) in extents.iter().zip(grid.edges()) {
+			assert_eq!(first, edges[0]);
+			assert_eq!(last, edges[edges.len() - 1]);
+		}
+		let open_grid = grid.clone().with_open_ends();
+		assert_eq!(open_grid.extents(), extents);
+	}
+
+	#[test]
+	#[should_panic(expected = "index out of bounds")]
+	fn extents_of_axis_without_edges_panics() {
+		let grid = Grid::from(vec![Bins::new(Edges::<i32>::from(vec![]))]);
+		let _ = grid.extents();
+	}
+
+	#[test]
+	fn axis_bins_matches_projections() {
+		let grid = cube_grid();
+		for (axis, bins) in grid.projections().iter().enumerate() {
+			assert_eq!(grid.axis_bins(axis), Some(bins));
+		}
+		assert_eq!(grid.axis_bins(2).unwrap().index(2), 2..3);
+		assert_eq!(grid.axis_bins(3), None);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn padded_grid_exceeds_data_range_by_fraction() {
+		let observations = array![
+			[o64(0.), o64(-2.)],
+			[o64(1.5), o64(3.)],
+			[o64(2.), o64(-1.)],
+			[o64(3.5), o64(0.5)],
+			[o64(4.), o64(8.)],
+			[o64(6.), o64(1.)],
+			[o64(7.), o64(2.)],
+			[o64(10.), o64(4.)],
+		];
+		let builder = GridBuilder::<Sturges<_>>::from_array(&observations).unwrap();
+		let grid = builder.build();
+		for fraction in [0.1, 0.25, 1.] {
+			let padded = builder.build_with_padding(fraction).unwrap();
+			for (axis, (bins, padded_bins)) in grid
+				.projections()
+				.iter()
+				.zip(padded.projections())
+				.enumerate()
+			{
+				let column = observations.column(axis);
+				let min = column.iter().min().unwrap().into_inner();
+				let max = column.iter().max().unwrap().into_inner();
+				let pad = fraction * (max - min);
+				let edges = padded_bins.edges();
+				assert!(edges[0].into_inner() <= min - pad);
+				assert!(edges[edges.len() - 1].into_inner() >= max + pad);
+				let width = |bins: &Bins<_>| {
+					let bin = bins.index(0);
+					bin.end - bin.start
+				};
+				assert!((width(padded_bins) - width(bins)).abs() < 1e-12);
+				assert_eq!((padded_bins.len() - bins.len()) % 2, 0);
+			}
+		}
+		assert_eq!(builder.build_with_padding(0.).unwrap(), grid);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn negative_or_non_finite_padding_is_bad() {
+		let observations = array![[0], [1], [2], [3]];
+		let builder = GridBuilder::<Sturges<i32>>::from_array(&observations).unwrap();
+		for fraction in [-0.5, f64::NAN, f64::INFINITY] {
+			assert!(builder
+				.build_with_padding(fraction)
+				.unwrap_err()
+				.is_invalid_padding());
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn n_bins_per_axis_matches_built_bins() {
+		let observations = array![
+			[0, 0, 5],
+			[1, 10, 5],
+			[2, 20, 5],
+			[3, 30, 6],
+			[4, 35, 6],
+			[5, 36, 6],
+			[6, 37, 7],
+			[7, 38, 9],
+			[8, 90, 9],
+		];
+		let builder = GridBuilder::<Sturges<i32>>::from_array(&observations).unwrap();
+		let lens = builder.n_bins_per_axis();
+		let grid = builder.build();
+		assert_eq!(lens.len(), grid.ndim());
+		for (len, bins) in lens.iter().zip(grid.projections()) {
+			assert_eq!(*len, bins.len());
+		}
+		let builder = GridBuilder::<FreedmanDiaconis<i32>>::from_array(&observations).unwrap();
+		assert_eq!(builder.n_bins_per_axis(), builder.build().shape());
+	}
+
+	#[cfg(feature = "std")]
+	fn assert_from_sorted_matches_from_array<B>(sorted: &SortedColumns<'_, i64>)
+	where
+		B: BinsBuildingStrategy<Elem = i64>,
+	{
+		let expected = GridBuilder::<B>::from_array(&sorted.observations).map(|b| b.build());
+		let grid = GridBuilder::<B>::from_sorted(sorted).map(|b| b.build());
+		match (grid, expected) {
+			(Ok(grid), Ok(expected)) => assert_eq!(grid, expected),
+			(Err(_), Err(_)) => (),
+			_ => panic!("Results differ"),
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn from_sorted_matches_from_array() {
+		// Uniform, skewed, zero IQR with an outlier (Scott's rule), and constant columns.
+		let observations = Array2::from_shape_fn((257, 4), |(i, j)| {
+			let i = i64::try_from(i).unwrap();
+			match j {
+				0 => (i * 7919) % 1009,
+				1 => (i * i * 31) % 997 * (i % 5),
+				2 => {
+					if i == 0 {
+						0
+					} else {
+						42
+					}
+				}
+				_ => 3,
+			}
+		});
+		let sorted = SortedColumns::new(&observations);
+		assert_eq!(sorted.ndim(), 4);
+		for column in 0..3 {
+			let columns = observations.slice(s![.., column..=column]);
+			let sorted = SortedColumns::new(&columns);
+			assert_from_sorted_matches_from_array::<Auto<i64>>(&sorted);
+			assert_from_sorted_matches_from_array::<FreedmanDiaconis<i64>>(&sorted);
+			assert_from_sorted_matches_from_array::<Sturges<i64>>(&sorted);
+			assert_from_sorted_matches_from_array::<Sqrt<i64>>(&sorted);
+		}
+		assert_from_sorted_matches_from_array::<Auto<i64>>(&sorted);
+		assert_from_sorted_matches_from_array::<FreedmanDiaconis<i64>>(&sorted);
+		let empty = Array2::<i64>::zeros((0, 2));
+		assert_from_sorted_matches_from_array::<Auto<i64>>(&SortedColumns::new(&empty));
+	}
+
+	#[test]
+	#[cfg(all(feature = "approx", feature = "std"))]
+	fn approx_eq_up_to_rounding() {
+		use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+
+		let grid = |width: f64| {
+			let edges = (0..=10)
+				.map(|i| o64(f64::from(i) * width))
+				.collect::<Vec<_>>();
+			Grid::from(vec![Bins::new(Edges::from(edges)); 2])
+		};
+		let rounded = grid(0.1);
+		let exact = Grid::from(vec![
+			Bins::new(Edges::from(
+				(0..=10)
+					.map(|i| o64(f64::from(i) / 10.))
+					.collect::<Vec<_>>()
+			));
+			2
+		]);
+		assert_ne!(rounded, exact);
+		assert_abs_diff_eq!(rounded, exact, epsilon = 1e-12);
+		assert_relative_eq!(rounded, exact);
+		assert_abs_diff_ne!(rounded, grid(0.1 + 1e-9), epsilon = 1e-12);
+		assert_abs_diff_ne!(rounded.clone().with_open_ends(), exact, epsilon = 1.);
+		assert_abs_diff_ne!(
+			rounded,
+			Grid::from(vec![Bins::new(Edges::from(vec![o64(0.), o64(1.)]))]),
+			epsilon = 1.
+		);
+	}
 }
@@ -1,21 +1,203 @@
-use super::errors::BinNotFound;
+use super::bins::Bins;
+use super::errors::{
+	BinNotFound, DimensionMismatch, GridError, GridMismatch, ObservationError, PermuteError,
+	RebinError, SliceError,
+};
 use super::grid::Grid;
+use crate::errors::ShapeMismatch;
+use alloc::{vec, vec::Vec};
+use core::mem;
+#[cfg(feature = "rand")]
+use core::ops::Range;
+use itertools::izip;
 use ndarray::prelude::*;
-use ndarray::Data;
+use ndarray::{Data, IntoDimension, Zip};
+use num_traits::{float::FloatCore, ToPrimitive, Zero};
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// Histogram data structure.
-pub struct Histogram<A: Ord + Send> {
-	counts: ArrayD<usize>,
+///
+/// The counts are stored as an array of dimension `D`. It defaults to [`IxDyn`] which is what
+/// [`HistogramExt::histogram`] returns. For grids of known dimensionality, a statically-typed
+/// histogram, e.g. `Histogram<A, Ix2>`, avoids the overhead of dynamic indexing when adding
+/// observations, see [`into_dimensionality`].
+///
+/// [`IxDyn`]: https://docs.rs/ndarray/latest/ndarray/type.IxDyn.html
+/// [`HistogramExt::histogram`]: trait.HistogramExt.html#tymethod.histogram
+/// [`into_dimensionality`]: #method.into_dimensionality
+pub struct Histogram<A: Ord + Send, D: Dimension = IxDyn> {
+	counts: Array<usize, D>,
 	grid: Grid<A>,
 }
 
 impl<A: Ord + Send> Histogram<A> {
 	/// Returns a new instance of Histogram given a [`Grid`].
 	///
+	/// **Panics** if the counts cannot be allocated, see [`Self::try_new`].
+	///
 	/// [`Grid`]: struct.Grid.html
+	#[must_use]
 	pub fn new(grid: Grid<A>) -> Self {
-		let counts = ArrayD::zeros(grid.shape());
-		Histogram { counts, grid }
+		Self::try_new(grid).expect("Too many bins")
+	}
+
+	/// Returns a new instance of Histogram given a [`Grid`].
+	///
+	/// Returns `Err(GridError::TooManyBins)` if the product of the numbers of bins along each axis
+	/// overflows `usize`, if the counts would exceed `isize::MAX` bytes, or if the allocator fails
+	/// to provide them.
+	///
+	/// There is no fixed limit on the number of bins below these as a sane one depends on the
+	/// memory of the target. Callers wanting a tighter limit can check the product of
+	/// [`Grid::shape`] beforehand.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray_histogram::histogram::{errors::GridError, Bins, Edges, Grid, Histogram};
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// // 2^70 bins overflow `usize`
+	/// let grid = Grid::from(vec![bins; 70]);
+	/// assert!(matches!(Histogram::try_new(grid), Err(GridError::TooManyBins)));
+	/// ```
+	///
+	/// [`Grid`]: struct.Grid.html
+	/// [`Grid::shape`]: struct.Grid.html#method.shape
+	pub fn try_new(grid: Grid<A>) -> Result<Self, GridError> {
+		let counts = try_zeros(&grid)?;
+		Ok(Histogram { counts, grid })
+	}
+
+	/// Returns the histograms of multiple batches of observations sharing the same `grid`.
+	///
+	/// As each histogram owns its grid, `grid` is cloned once per batch. Each batch is a
+	/// 2-dimensional array of points as in [`HistogramExt::histogram`]. Points outside the grid are
+	/// ignored.
+	///
+	/// This is an associated function of `Histogram` rather than of [`HistogramExt`] as it takes
+	/// no array as receiver, hence the trait would require naming the array type at every call.
+	///
+	/// **Panics** if the number of columns of any batch is different from `grid.ndim()`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let batches = [array![[0], [1]], array![[1], [1]]];
+	/// let batches = batches.iter().map(|batch| batch.view()).collect::<Vec<_>>();
+	/// let histograms = Histogram::histograms_batched(&batches, &grid);
+	/// assert_eq!(histograms[0].counts(), array![1, 1].into_dyn());
+	/// assert_eq!(histograms[1].counts(), array![0, 2].into_dyn());
+	/// ```
+	///
+	/// [`HistogramExt`]: trait.HistogramExt.html
+	/// [`HistogramExt::histogram`]: trait.HistogramExt.html#tymethod.histogram
+	pub fn histograms_batched(batches: &[ArrayView2<'_, A>], grid: &Grid<A>) -> Vec<Self>
+	where
+		A: Clone,
+	{
+		batches
+			.iter()
+			.map(|batch| batch.histogram(grid.clone()))
+			.collect()
+	}
+
+	/// A parallel version of [`histograms_batched`], computing the histograms of the batches in
+	/// parallel.
+	///
+	/// As each histogram owns its grid, `grid` is cloned once per batch.
+	///
+	/// **Panics** if the number of columns of any batch is different from `grid.ndim()`.
+	///
+	/// [`histograms_batched`]: #method.histograms_batched
+	#[cfg(feature = "rayon")]
+	pub fn par_histograms_batched(batches: &[ArrayView2<'_, A>], grid: &Grid<A>) -> Vec<Self>
+	where
+		A: Clone + Sync,
+	{
+		batches
+			.par_iter()
+			.map(|batch| batch.histogram(grid.clone()))
+			.collect()
+	}
+}
+
+impl<A: Ord + Send, D: Dimension> Histogram<A, D> {
+	/// Returns a histogram given a [`Grid`] and its `counts`, e.g. loaded from disk or computed
+	/// elsewhere.
+	///
+	/// This is the inverse of [`counts`].
+	///
+	/// Returns `Err(ShapeMismatch)` if the shape of `counts` differs from `grid.shape()`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let histogram = Histogram::from_counts(grid.clone(), array![3, 4].into_dyn())?;
+	/// assert_eq!(histogram.counts(), array![3, 4].into_dyn());
+	/// assert!(Histogram::from_counts(grid, array![3, 4, 5].into_dyn()).is_err());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	///
+	/// [`Grid`]: struct.Grid.html
+	/// [`counts`]: #method.counts
+	pub fn from_counts(grid: Grid<A>, counts: Array<usize, D>) -> Result<Self, ShapeMismatch> {
+		let shape = grid.shape();
+		if counts.shape() == shape.as_slice() {
+			Ok(Self { counts, grid })
+		} else {
+			Err(ShapeMismatch {
+				first_shape: shape,
+				second_shape: counts.shape().to_vec(),
+			})
+		}
+	}
+
+	/// Converts the histogram into one of dimension `D2`, e.g. into a statically-typed histogram.
+	///
+	/// Returns `Err(DimensionMismatch)` if `self.ndim()` is incompatible with `D2`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::{array, Ix1, Ix2};
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let mut histogram = Histogram::new(grid).into_dimensionality::<Ix2>()?;
+	/// histogram.add_observation(&array![1, 0])?;
+	/// assert_eq!(histogram.counts(), array![[0, 0], [1, 0]]);
+	/// assert!(histogram.into_dyn().into_dimensionality::<Ix1>().is_err());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn into_dimensionality<D2: Dimension>(self) -> Result<Histogram<A, D2>, DimensionMismatch> {
+		let found = self.ndim();
+		match self.counts.into_dimensionality() {
+			Ok(counts) => Ok(Histogram {
+				counts,
+				grid: self.grid,
+			}),
+			Err(_) => Err(DimensionMismatch {
+				expected: D2::NDIM.unwrap_or(found),
+				found,
+			}),
+		}
+	}
+
+	/// Converts the histogram into a dynamic-dimensional one.
+	pub fn into_dyn(self) -> Histogram<A> {
+		Histogram {
+			counts: self.counts.into_dyn(),
+			grid: self.grid,
+		}
 	}
 
 	/// Adds a single observation to the histogram.
@@ -48,13 +230,132 @@ impl<A: Ord + Send> Histogram<A> {
 	where
 		S: Data<Elem = A>,
 	{
-		match self.grid.index_of(observation) {
-			Some(bin_index) => {
-				self.counts[&*bin_index] += 1;
-				Ok(())
-			}
-			None => Err(BinNotFound),
-		}
+		let bin_index = bin_index::<A, S, D>(&self.grid, observation)?;
+		self.counts[bin_index] += 1;
+		Ok(())
+	}
+
+	/// Adds a single observation to the histogram as [`add_observation`] does and returns whether
+	/// it populated a previously empty bin, e.g. to redraw only the changed bins.
+	///
+	/// **Panics** if dimensions do not match: `self.ndim() != observation.len()`.
+	///
+	/// [`add_observation`]: #method.add_observation
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let mut histogram = Histogram::new(Grid::from(vec![bins]));
+	/// assert!(histogram.checked_add_observation(&array![1])?);
+	/// assert!(!histogram.checked_add_observation(&array![1])?);
+	/// assert!(histogram.checked_add_observation(&array![2]).is_err());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn checked_add_observation<S>(
+		&mut self,
+		observation: &ArrayBase<S, Ix1>,
+	) -> Result<bool, BinNotFound>
+	where
+		S: Data<Elem = A>,
+	{
+		let bin_index = bin_index::<A, S, D>(&self.grid, observation)?;
+		let count = &mut self.counts[bin_index];
+		*count += 1;
+		Ok(*count == 1)
+	}
+
+	/// Adds a single observation to the histogram without panicking on dimension mismatch.
+	///
+	/// Returns `Err(ObservationError::DimensionMismatch)` if `self.ndim() != observation.len()`
+	/// and `Err(ObservationError::BinNotFound)` if the observation is outside the grid.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+	///
+	/// let bins = Bins::new(Edges::from(vec![-1, 0, 1]));
+	/// let mut histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+	///
+	/// assert!(histogram.add_observation_checked(&array![0, -1]).is_ok());
+	/// assert!(histogram
+	/// 	.add_observation_checked(&array![0, -1, 0])
+	/// 	.unwrap_err()
+	/// 	.is_dimension_mismatch());
+	/// ```
+	pub fn add_observation_checked<S>(
+		&mut self,
+		observation: &ArrayBase<S, Ix1>,
+	) -> Result<(), ObservationError>
+	where
+		S: Data<Elem = A>,
+	{
+		self.grid.check_ndim(observation.len())?;
+		Ok(self.add_observation(observation)?)
+	}
+
+	/// Adds the observations of a 2-dimensional array of points to the histogram, e.g. to
+	/// accumulate multiple chunks of data into a single histogram.
+	///
+	/// Each row is a point as in [`HistogramExt::histogram`]. Returns the number of points outside
+	/// the grid which are ignored.
+	///
+	/// **Panics** if the number of columns is different from `self.ndim()`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let mut histogram = Histogram::new(grid);
+	/// assert_eq!(histogram.add_observations(&array![[0], [1]]), 0);
+	/// assert_eq!(histogram.add_observations(&array![[1], [2], [-1]]), 2);
+	/// assert_eq!(histogram.counts(), array![1, 2].into_dyn());
+	/// ```
+	///
+	/// [`HistogramExt::histogram`]: trait.HistogramExt.html#tymethod.histogram
+	pub fn add_observations<S>(&mut self, observations: &ArrayBase<S, Ix2>) -> usize
+	where
+		S: Data<Elem = A>,
+	{
+		observations
+			.axis_iter(Axis(0))
+			.filter(|point| self.add_observation(point).is_err())
+			.count()
+	}
+
+	/// Adds the observations of a 2-dimensional array of points to the histogram without
+	/// panicking on dimension mismatch, see [`add_observations`].
+	///
+	/// Returns `Err(DimensionMismatch)` if the number of columns is different from `self.ndim()`,
+	/// in which case no observation is added.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, Histogram};
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let mut histogram = Histogram::new(grid);
+	/// assert_eq!(histogram.try_add_observations(&array![[1], [2]]), Ok(1));
+	/// assert!(histogram.try_add_observations(&array![[0, 1]]).is_err());
+	/// assert_eq!(histogram.counts(), array![0, 1].into_dyn());
+	/// ```
+	///
+	/// [`add_observations`]: #method.add_observations
+	pub fn try_add_observations<S>(
+		&mut self,
+		observations: &ArrayBase<S, Ix2>,
+	) -> Result<usize, DimensionMismatch>
+	where
+		S: Data<Elem = A>,
+	{
+		self.grid.check_ndim(observations.ncols())?;
+		Ok(self.add_observations(observations))
 	}
 
 	/// Returns the number of dimensions of the space the histogram is covering.
@@ -64,86 +365,2587 @@ impl<A: Ord + Send> Histogram<A> {
 	}
 
 	/// Borrows a view on the histogram counts matrix.
-	pub fn counts(&self) -> ArrayViewD<'_, usize> {
+	pub fn counts(&self) -> ArrayView<'_, usize, D> {
 		self.counts.view()
 	}
 
-	/// Borrows an immutable reference to the histogram grid.
-	pub fn grid(&self) -> &Grid<A> {
-		&self.grid
+	/// Borrows a 1-dimensional view on the histogram counts.
+	///
+	/// Returns `Err(DimensionMismatch)` if `self.ndim() != 1`.
+	pub fn counts_1d(&self) -> Result<ArrayView1<'_, usize>, DimensionMismatch> {
+		self.counts
+			.view()
+			.into_dimensionality()
+			.map_err(|_| DimensionMismatch {
+				expected: 1,
+				found: self.ndim(),
+			})
 	}
-}
 
-/// Extension trait for `ArrayBase` providing methods to compute histograms.
-pub trait HistogramExt<A, S>
-where
-	S: Data<Elem = A>,
-{
-	/// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram)
-	/// for a 2-dimensional array of points `M`.
+	/// Borrows a 2-dimensional view on the histogram counts matrix.
 	///
-	/// Let `(n, d)` be the shape of `M`:
-	/// - `n` is the number of points;
-	/// - `d` is the number of dimensions of the space those points belong to.
-	/// It follows that every column in `M` is a `d`-dimensional point.
+	/// Returns `Err(DimensionMismatch)` if `self.ndim() != 2`.
 	///
-	/// For example: a (3, 4) matrix `M` is a collection of 3 points in a
-	/// 4-dimensional space.
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
 	///
-	/// Important: points outside the grid are ignored!
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let histogram = array![[0, 1], [1, 1]].histogram(grid);
+	/// let counts = histogram.counts_2d()?;
+	/// assert_eq!(counts.row(1), array![0, 1]);
+	/// assert!(histogram.counts_1d().is_err());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn counts_2d(&self) -> Result<ArrayView2<'_, usize>, DimensionMismatch> {
+		self.counts
+			.view()
+			.into_dimensionality()
+			.map_err(|_| DimensionMismatch {
+				expected: 2,
+				found: self.ndim(),
+			})
+	}
+
+	/// Borrows a mutable view on the histogram counts matrix.
+	///
+	/// The counts can be edited in place, e.g. to apply corrections, whereas the grid is kept
+	/// intact. As a view, it cannot change the shape of the counts which has to match the grid.
+	pub fn counts_mut(&mut self) -> ArrayViewMut<'_, usize, D> {
+		self.counts.view_mut()
+	}
+
+	/// Draws `n` random observations distributed according to the counts, e.g. for bootstrapping
+	/// or synthetic data.
+	///
+	/// Each observation falls into a bin drawn with a probability proportional to its count, see
+	/// [inverse transform sampling], and is uniformly distributed within the bin. The observations
+	/// are returned as rows of a 2-dimensional array with shape `(n, ndim)`.
+	///
+	/// Requires the `rand` feature.
 	///
-	/// **Panics** if `d` is different from `grid.ndim()`.
+	/// **Panics** if `n > 0` and the histogram is empty.
+	///
+	/// [inverse transform sampling]: https://en.wikipedia.org/wiki/Inverse_transform_sampling
 	///
 	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	/// use rand::{rngs::StdRng, SeedableRng};
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 10, 20]))]);
+	/// let histogram = array![[1], [2], [3]].histogram(grid);
+	/// let samples = histogram.sample(&mut StdRng::seed_from_u64(42), 100);
+	/// assert_eq!(samples.shape(), &[100, 1]);
+	/// assert!(samples.iter().all(|&x| (0. ..10.).contains(&x)));
+	/// ```
+	#[cfg(feature = "rand")]
+	pub fn sample<R: Rng>(&self, rng: &mut R, n: usize) -> Array2<f64>
+	where
+		A: Clone + ToPrimitive,
+	{
+		let mut total = 0;
+		let mut cumulative_counts = Vec::new();
+		let mut bins = Vec::new();
+		for (index, &count) in self.counts.indexed_iter() {
+			if count > 0 {
+				total += count;
+				cumulative_counts.push(total);
+				bins.push(index.into_dimension());
+			}
+		}
+		assert!(n == 0 || total > 0, "Histogram is empty");
+		let mut samples = Array2::zeros((n, self.ndim()));
+		for mut sample in samples.rows_mut() {
+			let count = rng.gen_range(0..total);
+			let bin = &bins[cumulative_counts.partition_point(|&c| c <= count)];
+			for (value, projection, &i) in izip!(&mut sample, self.grid.projections(), bin.slice())
+			{
+				let Range { start, end } = projection.index(i);
+				let (start, end) = (start.to_f64().unwrap(), end.to_f64().unwrap());
+				*value = start + (end - start) * rng.gen::<f64>();
+			}
+		}
+		samples
+	}
+
+	/// Returns the projection of the counts onto `axis`, i.e. the counts summed over all other
+	/// axes, aligned with the bins of the grid along `axis`.
+	///
+	/// **Panics** if `axis` is out of bounds.
 	///
+	/// # Example:
 	/// ```
 	/// use ndarray::array;
 	/// use ndarray_histogram::{
-	/// 	histogram::{strategies::Sqrt, Bins, Edges, Grid, GridBuilder, Histogram},
-	/// 	o64, HistogramExt, O64,
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
 	/// };
 	///
-	/// let observations = array![
-	/// 	[o64(1.), o64(0.5)],
-	/// 	[o64(-0.5), o64(1.)],
-	/// 	[o64(-1.), o64(-0.5)],
-	/// 	[o64(0.5), o64(-1.)]
-	/// ];
-	/// let grid = GridBuilder::<Sqrt<O64>>::from_array(&observations)
-	/// 	.unwrap()
-	/// 	.build();
-	/// let expected_grid = Grid::from(vec![
-	/// 	Bins::new(Edges::from(vec![o64(-1.), o64(0.), o64(1.), o64(2.)])),
-	/// 	Bins::new(Edges::from(vec![o64(-1.), o64(0.), o64(1.), o64(2.)])),
-	/// ]);
-	/// assert_eq!(grid, expected_grid);
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let histogram = array![[0, 0], [0, 1], [1, 1]].histogram(grid);
+	/// assert_eq!(histogram.project(0), array![2, 1]);
+	/// assert_eq!(histogram.project(1), array![1, 2]);
+	/// ```
+	pub fn project(&self, axis: usize) -> Array1<usize> {
+		assert!(
+			axis < self.ndim(),
+			"Axis {} is out of bounds for {} dimensions",
+			axis,
+			self.ndim()
+		);
+		self.counts
+			.view()
+			.into_dyn()
+			.axis_iter(Axis(axis))
+			.map(|counts| counts.sum())
+			.collect()
+	}
+
+	/// Returns the cross-section of the histogram at bin `index` along `axis`, i.e. a histogram over
+	/// the remaining axes with the counts of that bin. Open ends of the grid are kept.
 	///
-	/// let histogram = observations.histogram(grid);
+	/// Returns `Err(SliceError::AxisOutOfBounds)` if `axis` is out of bounds and
+	/// `Err(SliceError::IndexOutOfBounds)` if `index` is out of bounds for the bins along `axis`.
 	///
-	/// let histogram_matrix = histogram.counts();
-	/// // Bins are left inclusive, right exclusive!
-	/// let expected = array![[1, 0, 1], [1, 0, 0], [0, 1, 0],];
-	/// assert_eq!(histogram_matrix, expected.into_dyn());
+	/// # Example:
 	/// ```
-	fn histogram(&self, grid: Grid<A>) -> Histogram<A>
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let histogram = array![[0, 0], [0, 1], [1, 1]].histogram(grid);
+	/// let slice = histogram.slice_at(0, 1)?;
+	/// assert_eq!(slice.ndim(), 1);
+	/// assert_eq!(slice.counts(), array![0, 1].into_dyn());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn slice_at(&self, axis: usize, index: usize) -> Result<Histogram<A>, SliceError>
 	where
-		A: Ord + Send;
-
-	private_decl! {}
-}
+		A: Clone,
+	{
+		let n_bins = self.grid.n_bins(axis).ok_or(SliceError::AxisOutOfBounds)?;
+		if index >= n_bins {
+			return Err(SliceError::IndexOutOfBounds);
+		}
+		let projections = self
+			.grid
+			.projections()
+			.iter()
+			.enumerate()
+			.filter(|&(other, _)| other != axis)
+			.map(|(_, bins)| bins.clone())
+			.collect::<Vec<_>>();
+		let mut grid = Grid::from(projections);
+		if self.grid.has_open_ends() {
+			grid = grid.with_open_ends();
+		}
+		let counts = self
+			.counts
+			.view()
+			.into_dyn()
+			.index_axis_move(Axis(axis), index)
+			.to_owned();
+		Ok(Histogram { counts, grid })
+	}
 
-impl<A, S> HistogramExt<A, S> for ArrayBase<S, Ix2>
-where
-	S: Data<Elem = A>,
-	A: Ord + Send,
-{
-	fn histogram(&self, grid: Grid<A>) -> Histogram<A> {
-		let mut histogram = Histogram::new(grid);
-		for point in self.axis_iter(Axis(0)) {
-			let _ = histogram.add_observation(&point);
+	/// Returns the histogram with its axes reordered such that axis `i` of the returned histogram
+	/// is axis `order[i]` of `self`, e.g. `&[1, 0]` transposes a 2-dimensional histogram.
+	///
+	/// Both the counts and the bins of the grid are reordered. Open ends of the grid are kept.
+	///
+	/// Returns `Err(PermuteError::DimensionMismatch)` if `order` differs in length from the
+	/// dimensions of the histogram and `Err(PermuteError::NotAPermutation)` if an axis in `order`
+	/// is out of bounds or occurs more than once.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let x = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let y = Bins::new(Edges::from(vec![0, 5]));
+	/// let histogram = array![[0, 0], [1, 2]].histogram(Grid::from(vec![x.clone(), y.clone()]));
+	/// let transposed = histogram.permute_axes(&[1, 0])?;
+	/// assert_eq!(transposed.grid(), &Grid::from(vec![y, x]));
+	/// assert_eq!(transposed.counts(), array![[1, 1]].into_dyn());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn permute_axes(&self, order: &[usize]) -> Result<Histogram<A>, PermuteError>
+	where
+		A: Clone,
+	{
+		if order.len() != self.ndim() {
+			return Err(PermuteError::DimensionMismatch);
 		}
-		histogram
+		let mut seen = vec![false; order.len()];
+		for &axis in order {
+			match seen.get_mut(axis) {
+				Some(seen) if !*seen => *seen = true,
+				_ => return Err(PermuteError::NotAPermutation),
+			}
+		}
+		let projections = self.grid.projections();
+		let projections = order
+			.iter()
+			.map(|&axis| projections[axis].clone())
+			.collect::<Vec<_>>();
+		let mut grid = Grid::from(projections);
+		if self.grid.has_open_ends() {
+			grid = grid.with_open_ends();
+		}
+		let counts = self
+			.counts
+			.view()
+			.into_dyn()
+			.permuted_axes(order)
+			.as_standard_layout()
+			.into_owned();
+		Ok(Histogram { counts, grid })
 	}
 
-	private_impl! {}
+	/// Returns the counts of the histogram transformed by `f` without changing the histogram.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let histogram = array![[1], [1], [1]].histogram(grid);
+	/// let total = histogram.counts().sum() as f64;
+	/// let frequencies = histogram.map_counts(|count| count as f64 / total);
+	/// assert_eq!(frequencies, array![0., 1.].into_dyn());
+	/// ```
+	pub fn map_counts<B>(&self, f: impl Fn(usize) -> B) -> Array<B, D> {
+		self.counts.mapv(f)
+	}
+
+	/// Returns the counts of the histogram converted into `T` losslessly.
+	///
+	/// **Note** that only few types implement `From<usize>`, e.g. neither `u64` nor `f64` do. Use
+	/// [`try_counts_as`] for fallible integer conversions, e.g. into `u64`, and [`map_counts`] for
+	/// lossy conversions, e.g. `histogram.map_counts(|count| count as f64)`.
+	///
+	/// [`try_counts_as`]: #method.try_counts_as
+	/// [`map_counts`]: #method.map_counts
+	pub fn counts_as<T: From<usize>>(&self) -> Array<T, D> {
+		self.counts.mapv(T::from)
+	}
+
+	/// Returns the counts of the histogram converted into `T`.
+	///
+	/// Returns `Err(T::Error)` if any count does not fit into `T`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let histogram = array![[0], [1], [1]].histogram(grid);
+	/// assert_eq!(histogram.try_counts_as::<u64>()?, array![1, 2].into_dyn());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn try_counts_as<T: TryFrom<usize>>(&self) -> Result<Array<T, D>, T::Error> {
+		let counts = self
+			.counts
+			.iter()
+			.map(|&count| T::try_from(count))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(Array::from_shape_vec(self.counts.raw_dim(), counts).unwrap())
+	}
+
+	/// Returns the probability mass of each bin, i.e. its count divided by the total count.
+	///
+	/// The probabilities sum up to `1.` unless the histogram is empty, in which case all of them
+	/// are `0.`. Unlike a density, they are not divided by the volumes of the bins.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 5]))]);
+	/// let histogram = array![[0], [3], [4], [4]].histogram(grid);
+	/// assert_eq!(histogram.as_probabilities(), array![0.25, 0.75].into_dyn());
+	/// ```
+	pub fn as_probabilities(&self) -> Array<f64, D> {
+		let total = self.counts.sum();
+		if total == 0 {
+			return Array::zeros(self.counts.raw_dim());
+		}
+		self.counts.mapv(|count| count as f64 / total as f64)
+	}
+
+	/// Returns the conditional distributions of the other axis of a 2-dimensional histogram given
+	/// each bin along `given_axis`.
+	///
+	/// The counts are normalized to sum up to `1.` along each slice of fixed `given_axis` bin,
+	/// e.g. each row if `given_axis` is `0`, whereas slices of zero counts remain `0.`. The
+	/// returned matrix keeps the orientation of the counts.
+	///
+	/// Returns `Err(DimensionMismatch)` if `self.ndim() != 2`.
+	///
+	/// **Panics** if `given_axis` is out of bounds.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let histogram = array![[0, 0], [0, 1], [0, 1], [0, 1], [2, 2]].histogram(grid);
+	/// let conditional = histogram.conditional(0)?;
+	/// assert_eq!(
+	/// 	conditional,
+	/// 	array![[0.25, 0.75, 0.], [0., 0., 0.], [0., 0., 1.]]
+	/// );
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn conditional(&self, given_axis: usize) -> Result<Array2<f64>, DimensionMismatch> {
+		let counts = self.counts_2d()?;
+		assert!(
+			given_axis < 2,
+			"Axis {} is out of bounds for 2 dimensions",
+			given_axis
+		);
+		let mut conditional = counts.mapv(|count| count as f64);
+		for mut slice in conditional.axis_iter_mut(Axis(given_axis)) {
+			let total = slice.sum();
+			if total > 0. {
+				slice.mapv_inplace(|count| count / total);
+			}
+		}
+		Ok(conditional)
+	}
+
+	/// Returns the counts normalized by their total and smoothed by convolving them with `kernel`
+	/// along each axis, e.g. to turn a noisy histogram into a smoother density estimate.
+	///
+	/// The `kernel` is centered on each bin, e.g. `[0.25, 0.5, 0.25]` or a box kernel. It is not
+	/// normalized, hence the smoothed counts sum to one as long as the kernel sums to one and no
+	/// mass is convolved beyond the edges. The counts are zero-padded at the edges, so bins near
+	/// the edges lose the mass spread outside of the grid.
+	///
+	/// Returns an array of zeros if the histogram is empty.
+	///
+	/// **Panics** if the length of `kernel` is even.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4, 5]))]);
+	/// let histogram = array![[2], [2]].histogram(grid);
+	/// let smoothed = histogram.smooth(&[0.25, 0.5, 0.25]);
+	/// assert_eq!(smoothed, array![0., 0.25, 0.5, 0.25, 0.].into_dyn());
+	/// ```
+	pub fn smooth(&self, kernel: &[f64]) -> Array<f64, D> {
+		assert!(kernel.len() % 2 == 1, "Kernel length must be odd");
+		let radius = kernel.len() / 2;
+		let total = self.counts.sum();
+		let mut density = self.counts.mapv(|count| count as f64);
+		if total > 0 {
+			density /= total as f64;
+		}
+		for axis in 0..density.ndim() {
+			let mut smoothed = Array::zeros(density.raw_dim());
+			Zip::from(smoothed.lanes_mut(Axis(axis)))
+				.and(density.lanes(Axis(axis)))
+				.for_each(|mut smoothed, lane| {
+					for (i, value) in smoothed.iter_mut().enumerate() {
+						*value = kernel
+							.iter()
+							.enumerate()
+							.filter_map(|(k, weight)| {
+								let j = (i + k).checked_sub(radius)?;
+								lane.get(j).map(|density| weight * density)
+							})
+							.sum();
+					}
+				});
+			density = smoothed;
+		}
+		density
+	}
+
+	/// Zeroes the counts of all bins for which `predicate` returns `false`.
+	///
+	/// The `predicate` receives the `n`-dimensional index and the count of each bin, e.g. to
+	/// suppress sparsely populated bins.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+	/// let mut histogram = array![[0], [1], [1], [2], [2], [2]].histogram(grid);
+	/// histogram.retain(|_index, count| count >= 2);
+	/// assert_eq!(histogram.counts(), array![0, 2, 3].into_dyn());
+	/// ```
+	pub fn retain<F: Fn(&[usize], usize) -> bool>(&mut self, predicate: F) {
+		for (index, count) in self.counts.indexed_iter_mut() {
+			if !predicate(index.into_dimension().slice(), *count) {
+				*count = 0;
+			}
+		}
+	}
+
+	/// Borrows an immutable reference to the histogram grid.
+	pub fn grid(&self) -> &Grid<A> {
+		&self.grid
+	}
+
+	/// Returns `true` if both histograms have equal grids, independent of their counts.
+	///
+	/// This is a cheap check whether histograms are comparable, e.g. via [`intersection`] or
+	/// [`chi_square_distance`].
+	///
+	/// [`intersection`]: #method.intersection
+	/// [`chi_square_distance`]: #method.chi_square_distance
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let a = array![[0], [1]].histogram(grid.clone());
+	/// let b = array![[1], [1], [1]].histogram(grid);
+	/// assert!(a.same_grid(&b));
+	/// ```
+	pub fn same_grid(&self, other: &Self) -> bool {
+		self.grid == other.grid
+	}
+
+	/// Returns the `n`-dimensional index of the bin with the highest count.
+	///
+	/// If multiple bins are equally populated, the first one in logical order is returned.
+	///
+	/// Returns `None` if all counts are zero.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let observations = array![[0, 1], [1, 0], [1, 0]];
+	/// let histogram = observations.histogram(grid);
+	/// assert_eq!(histogram.mode_bin(), Some(vec![1, 0]));
+	/// ```
+	pub fn mode_bin(&self) -> Option<Vec<usize>> {
+		let mut mode = None;
+		for (index, &count) in self.counts.indexed_iter() {
+			if count > mode.as_ref().map_or(0, |&(_, max)| max) {
+				mode = Some((index, count));
+			}
+		}
+		mode.map(|(index, _count)| index.into_dimension().slice().to_vec())
+	}
+
+	/// Returns the `n`-dimensional indices of all bins with the highest count in logical order.
+	///
+	/// Returns an empty vector if all counts are zero.
+	pub fn mode_bins(&self) -> Vec<Vec<usize>> {
+		let max = self.counts.iter().copied().max().unwrap_or(0);
+		if max == 0 {
+			return Vec::new();
+		}
+		self.counts
+			.indexed_iter()
+			.filter(|&(_, &count)| count == max)
+			.map(|(index, _count)| index.into_dimension().slice().to_vec())
+			.collect()
+	}
+
+	/// Returns the indices of the first and the last bin of the narrowest contiguous range of bins
+	/// of a 1-dimensional histogram containing at least `fraction` of the total count, e.g. to
+	/// approximate the highest density interval where 90% of the mass lives for `fraction = 0.9`.
+	///
+	/// The range is the one spanning the fewest bins regardless of their widths. If multiple
+	/// ranges span equally few bins, the one with the highest count is returned, and of those the
+	/// first one. It is found by a sliding window scan over the counts in O(`n`) where `n` is the
+	/// number of bins.
+	///
+	/// Returns `None` if the histogram is empty.
+	///
+	/// **Panics** if `fraction` is not in `(0, 1]` or if `self.ndim() != 1`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4, 5]))]);
+	/// let histogram = array![[0], [1], [2], [2], [2], [2], [3], [3], [3], [4]].histogram(grid);
+	/// assert_eq!(histogram.coverage_interval(0.4), Some((2, 2)));
+	/// assert_eq!(histogram.coverage_interval(0.7), Some((2, 3)));
+	/// assert_eq!(histogram.coverage_interval(1.), Some((0, 4)));
+	/// ```
+	pub fn coverage_interval(&self, fraction: f64) -> Option<(usize, usize)> {
+		assert!(
+			fraction > 0. && fraction <= 1.,
+			"Fraction must be in (0, 1]"
+		);
+		let counts = self
+			.counts_1d()
+			.expect("Coverage interval requires a 1-dimensional histogram");
+		let total = counts.sum();
+		if total == 0 {
+			return None;
+		}
+		let target = fraction * total as f64;
+		// The product is rounded by at most half an ulp, which must not require one more count,
+		// e.g. `0.07 * 100.` is `7.000000000000001`.
+		let needed = (FloatCore::ceil(target - target * f64::EPSILON) as usize).clamp(1, total);
+		let mut best: Option<(usize, usize, usize)> = None;
+		let (mut end, mut sum) = (0, 0);
+		for start in 0..counts.len() {
+			while end < counts.len() && sum < needed {
+				sum += counts[end];
+				end += 1;
+			}
+			if sum < needed {
+				break;
+			}
+			let is_better = best.map_or(true, |(best_start, best_end, best_sum)| {
+				let (len, best_len) = (end - start, best_end - best_start);
+				len < best_len || (len == best_len && sum > best_sum)
+			});
+			if is_better {
+				best = Some((start, end, sum));
+			}
+			sum -= counts[start];
+		}
+		best.map(|(start, end, _sum)| (start, end - 1))
+	}
+
+	/// Returns an iterator over the `n`-dimensional indices and counts of all populated bins in
+	/// logical order, skipping the bins with a count of zero.
+	///
+	/// This is convenient for sparse histograms, e.g. high-dimensional ones, whose consumers only
+	/// care about the few populated bins. The counts are still stored densely, so the iterator
+	/// visits every bin but only allocates indices of populated ones.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let histogram = array![[0, 1], [1, 0], [1, 0]].histogram(grid);
+	/// let populated: Vec<_> = histogram.nonzero_bins().collect();
+	/// assert_eq!(populated, vec![(vec![0, 1], 1), (vec![1, 0], 2)]);
+	/// ```
+	pub fn nonzero_bins(&self) -> impl Iterator<Item = (Vec<usize>, usize)> + '_ {
+		self.counts
+			.indexed_iter()
+			.filter(|&(_, &count)| count > 0)
+			.map(|(index, &count)| (index.into_dimension().slice().to_vec(), count))
+	}
+
+	/// Returns the centroid of the histogram, i.e. the mean position of its observations with each
+	/// observation placed at the center of its bin along each axis.
+	///
+	/// Along each axis, this is the first moment `sum(count_i * center_i) / sum(count_i)` of the
+	/// [`project`]ed counts at the [`bin_centers`] of the grid. Observations counted in the
+	/// extreme bins of a grid with open ends are placed at the centers of these bins.
+	///
+	/// Returns `None` if all counts are zero.
+	///
+	/// **Panics** if an edge cannot be converted into `f64`.
+	///
+	/// [`project`]: #method.project
+	/// [`bin_centers`]: struct.Grid.html#method.bin_centers
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 2, 4]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let histogram = array![[0, 0], [3, 1], [3, 3]].histogram(grid);
+	/// assert_eq!(histogram.centroid(), Some(vec![7. / 3., 5. / 3.]));
+	/// ```
+	pub fn centroid(&self) -> Option<Vec<f64>>
+	where
+		A: ToPrimitive,
+	{
+		let total = self.counts.sum();
+		if total == 0 {
+			return None;
+		}
+		let centroid = (0..self.ndim())
+			.map(|axis| {
+				let centers = self.grid.bin_centers(axis).unwrap();
+				let moment = izip!(self.project(axis), centers)
+					.map(|(count, center)| count as f64 * center)
+					.sum::<f64>();
+				moment / total as f64
+			})
+			.collect();
+		Some(centroid)
+	}
+
+	/// Returns a new histogram over a coarser grid by summing the counts of the bins of `self`
+	/// nested in the bins of `coarse_grid`.
+	///
+	/// Every edge of `coarse_grid` has to be an edge of the grid of `self` along the same axis, so
+	/// that each fine bin lies within exactly one coarse bin. Counts of fine bins outside of
+	/// `coarse_grid` are discarded.
+	///
+	/// Returns `Err(RebinError::DimensionMismatch)` if the grids differ in dimensions and
+	/// `Err(RebinError::MisalignedEdges)` if the grids are not nested.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let fine_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4]))]);
+	/// let coarse_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4]))]);
+	/// let observations = array![[0], [1], [1], [2], [3], [3], [3]];
+	/// let histogram = observations.histogram(fine_grid);
+	/// let coarse_histogram = histogram.rebin(&coarse_grid)?;
+	/// assert_eq!(coarse_histogram.counts(), array![3, 4].into_dyn());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn rebin(&self, coarse_grid: &Grid<A>) -> Result<Self, RebinError>
+	where
+		A: Clone,
+	{
+		if self.grid.ndim() != coarse_grid.ndim() {
+			return Err(RebinError::DimensionMismatch);
+		}
+		let mut coarse_indices = Vec::with_capacity(self.ndim());
+		for (fine, coarse) in izip!(self.grid.projections(), coarse_grid.projections()) {
+			// Both edge sets are sorted, so a single pass tests whether one is a subset of the other.
+			let mut fine_edges = fine.edges().iter();
+			let mut coarse_edges = coarse.edges().iter();
+			if !coarse_edges.all(|edge| fine_edges.any(|e| e == edge)) {
+				return Err(RebinError::MisalignedEdges);
+			}
+			// Without coarse edges in between, the left edge determines the coarse bin.
+			let indices = fine
+				.edges()
+				.iter()
+				.take(fine.len())
+				.map(|edge| coarse.index_of(edge))
+				.collect::<Vec<_>>();
+			coarse_indices.push(indices);
+		}
+		let mut histogram = Histogram::new(coarse_grid.clone());
+		for (index, &count) in self.counts.indexed_iter() {
+			let coarse_index = index
+				.into_dimension()
+				.slice()
+				.iter()
+				.zip(&coarse_indices)
+				.map(|(&i, indices)| indices[i])
+				.collect::<Option<Vec<_>>>();
+			if let Some(coarse_index) = coarse_index {
+				histogram.counts[&*coarse_index] += count;
+			}
+		}
+		Ok(histogram
+			.into_dimensionality()
+			.expect("Dimensions of both grids are equal"))
+	}
+
+	/// Returns a new histogram over `new_grid` by moving the count of each bin of `self` into the
+	/// bin of `new_grid` containing its center, along with the sum of the counts whose centers lie
+	/// outside of `new_grid` which are dropped.
+	///
+	/// Unlike [`rebin`], this does not require nested grids, e.g. to grow the grid as the range of
+	/// the data extends. It is an approximation as the observations within a bin are assumed to lie
+	/// at its center. The approximation is exact if each bin of `self` lies within a single bin of
+	/// `new_grid`, e.g. if `new_grid` extends the grid of `self` by additional edges.
+	///
+	/// Returns `Err(DimensionMismatch)` if the grids differ in dimensions.
+	///
+	/// **Panics** if an edge cannot be converted into `f64`.
+	///
+	/// [`rebin`]: #method.rebin
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4]))]);
+	/// let histogram = array![[0], [1], [3]].histogram(grid);
+	/// let wider_grid = Grid::from(vec![Bins::new(Edges::from(vec![-4, 0, 4, 8]))]);
+	/// let (wider_histogram, dropped) = histogram.reproject(&wider_grid)?;
+	/// assert_eq!(wider_histogram.counts(), array![0, 3, 0].into_dyn());
+	/// assert_eq!(dropped, 0);
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn reproject(&self, new_grid: &Grid<A>) -> Result<(Self, usize), DimensionMismatch>
+	where
+		A: Clone + ToPrimitive,
+	{
+		new_grid.check_ndim(self.ndim())?;
+		let new_indices = izip!(self.grid.projections(), new_grid.projections())
+			.map(|(old, new)| {
+				let edges = new
+					.edges()
+					.iter()
+					.map(|edge| edge.to_f64().unwrap())
+					.collect::<Vec<_>>();
+				old.centers()
+					.iter()
+					.map(|&center| {
+						let right = edges.partition_point(|&edge| edge <= center);
+						if new_grid.has_open_ends() && !new.is_empty() {
+							Some(right.clamp(1, new.len()) - 1)
+						} else {
+							(1..=new.len()).contains(&right).then(|| right - 1)
+						}
+					})
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let mut histogram = Histogram::new(new_grid.clone());
+		let mut dropped = 0;
+		for (index, &count) in self.counts.indexed_iter() {
+			let new_index = index
+				.into_dimension()
+				.slice()
+				.iter()
+				.zip(&new_indices)
+				.map(|(&i, indices)| indices[i])
+				.collect::<Option<Vec<_>>>();
+			match new_index {
+				Some(new_index) => histogram.counts[&*new_index] += count,
+				None => dropped += count,
+			}
+		}
+		let histogram = histogram
+			.into_dimensionality()
+			.expect("Dimensions of both grids are equal");
+		Ok((histogram, dropped))
+	}
+
+	/// Returns the [histogram intersection] of `self` and `other`, i.e. the sum of the bin-wise
+	/// minima of the counts normalized by the smaller of both totals.
+	///
+	/// The intersection ranges from `0.` for histograms without any common non-empty bin to `1.`
+	/// for identical histograms. It is `NaN` if one of the histograms is empty.
+	///
+	/// Returns `Err(GridMismatch)` if the grids of both histograms are not equal.
+	///
+	/// [histogram intersection]: https://en.wikipedia.org/wiki/Histogram_matching
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+	/// let a = array![[0], [1], [1], [2]].histogram(grid.clone());
+	/// let b = array![[0], [0], [1], [2]].histogram(grid);
+	/// assert_eq!(a.intersection(&b)?, 0.75);
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn intersection(&self, other: &Self) -> Result<f64, GridMismatch> {
+		self.check_grid(other, "histogram intersection")?;
+		let overlap = izip!(&self.counts, &other.counts)
+			.map(|(&a, &b)| a.min(b))
+			.sum::<usize>();
+		let total = self.counts.sum().min(other.counts.sum());
+		Ok(overlap as f64 / total as f64)
+	}
+
+	/// Returns the chi-square distance of `self` and `other`, i.e. half the sum of the squared
+	/// differences of the counts divided by their sum over all non-empty bins.
+	///
+	/// The distance is `0.` for identical histograms and equals half the total number of
+	/// observations of both histograms if they do not have any common non-empty bin.
+	///
+	/// Returns `Err(GridMismatch)` if the grids of both histograms are not equal.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+	/// let a = array![[0], [1], [1], [2]].histogram(grid.clone());
+	/// let b = array![[0], [0], [1], [2]].histogram(grid);
+	/// assert_eq!(a.chi_square_distance(&b)?, 1. / 3.);
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn chi_square_distance(&self, other: &Self) -> Result<f64, GridMismatch> {
+		self.check_grid(other, "histogram chi-square distance")?;
+		let distance = izip!(&self.counts, &other.counts)
+			.filter(|&(&a, &b)| a + b > 0)
+			.map(|(&a, &b)| {
+				let (a, b) = (a as f64, b as f64);
+				(a - b) * (a - b) / (a + b)
+			})
+			.sum::<f64>();
+		Ok(0.5 * distance)
+	}
+
+	/// Returns the [Shannon entropy] in bits of the distribution of the counts, i.e.
+	/// `-sum(p_i * log2(p_i))` over all non-empty bins where `p_i = count_i / total`.
+	///
+	/// Returns `0.` if the histogram is empty or if only a single bin is non-empty.
+	///
+	/// [Shannon entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4]))]);
+	/// let histogram = array![[0], [1], [1], [2], [2], [2], [2], [3]].histogram(grid);
+	/// assert_eq!(histogram.entropy(), 1.75);
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn entropy(&self) -> f64 {
+		let total = self.counts.sum();
+		if total == 0 {
+			return 0.;
+		}
+		let total = total as f64;
+		-self
+			.counts
+			.iter()
+			.filter(|&&count| count > 0)
+			.map(|&count| {
+				let p = count as f64 / total;
+				p * p.log2()
+			})
+			.sum::<f64>()
+	}
+
+	/// Returns the [`entropy`] normalized by its maximum `log2(n)` where `n` is the number of
+	/// non-empty bins, i.e. a value in `[0, 1]`.
+	///
+	/// Returns `0.` if less than two bins are non-empty.
+	///
+	/// [`entropy`]: #method.entropy
+	#[cfg(feature = "std")]
+	pub fn normalized_entropy(&self) -> f64 {
+		let n_nonempty = self.counts.iter().filter(|&&count| count > 0).count();
+		if n_nonempty < 2 {
+			return 0.;
+		}
+		self.entropy() / (n_nonempty as f64).log2()
+	}
+
+	/// Returns the [mutual information] in bits between the coordinate axes `axis_a` and `axis_b`,
+	/// i.e. `sum(p_ab * log2(p_ab / (p_a * p_b)))` over all non-empty bins of the joint
+	/// distribution `p_ab` of the counts summed over all other axes, where `p_a` and `p_b` are its
+	/// marginal distributions.
+	///
+	/// It is `0.` for independent axes and equals the [`entropy`] of the projection onto either
+	/// axis if one axis determines the other, e.g. if `axis_a == axis_b`. Returns `0.` if the
+	/// histogram is empty.
+	///
+	/// **Panics** if `axis_a` or `axis_b` is out of bounds.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+	/// let grid = Grid::from(vec![bins.clone(), bins]);
+	/// let histogram = array![[0, 0], [1, 1]].histogram(grid.clone());
+	/// assert_eq!(histogram.mutual_information(0, 1), 1.);
+	/// let histogram = array![[0, 0], [0, 1], [1, 0], [1, 1]].histogram(grid);
+	/// assert_eq!(histogram.mutual_information(0, 1), 0.);
+	/// ```
+	///
+	/// [mutual information]: https://en.wikipedia.org/wiki/Mutual_information
+	/// [`entropy`]: #method.entropy
+	#[cfg(feature = "std")]
+	pub fn mutual_information(&self, axis_a: usize, axis_b: usize) -> f64 {
+		for axis in [axis_a, axis_b] {
+			assert!(
+				axis < self.ndim(),
+				"Axis {} is out of bounds for {} dimensions",
+				axis,
+				self.ndim()
+			);
+		}
+		let total = self.counts.sum();
+		if total == 0 {
+			return 0.;
+		}
+		let total = total as f64;
+		let shape = self.counts.shape();
+		let mut joint = Array2::<usize>::zeros((shape[axis_a], shape[axis_b]));
+		for (index, &count) in self.counts.indexed_iter() {
+			let index = index.into_dimension();
+			joint[[index[axis_a], index[axis_b]]] += count;
+		}
+		let marginal_a = joint.sum_axis(Axis(1));
+		let marginal_b = joint.sum_axis(Axis(0));
+		joint
+			.indexed_iter()
+			.filter(|&(_, &count)| count > 0)
+			.map(|((a, b), &count)| {
+				let p_ab = count as f64 / total;
+				let p_a = marginal_a[a] as f64 / total;
+				let p_b = marginal_b[b] as f64 / total;
+				p_ab * (p_ab / (p_a * p_b)).log2()
+			})
+			.sum()
+	}
+
+	/// Writes the counts of a 1-dimensional or 2-dimensional histogram as CSV to `w`, e.g. for a
+	/// quick inspection in a spreadsheet.
+	///
+	/// A 1-dimensional histogram is written as a `left_edge,right_edge,count` header followed by one
+	/// row per bin. A 2-dimensional histogram is written as a matrix of counts whose rows and
+	/// columns follow the bins along the first and second axis, respectively. Its header row and
+	/// its first column label the bins as `left_edge..right_edge`.
+	///
+	/// Returns an error of kind `InvalidInput` if the histogram is neither 1-dimensional nor
+	/// 2-dimensional, or any error of writing to `w`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 3]))]);
+	/// let histogram = array![[0], [1], [2]].histogram(grid);
+	/// let mut csv = Vec::new();
+	/// histogram.write_csv(&mut csv)?;
+	/// assert_eq!(
+	/// 	String::from_utf8(csv)?,
+	/// 	"left_edge,right_edge,count\n0,1,1\n1,3,2\n"
+	/// );
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn write_csv<W: std::io::Write>(&self, mut w: W) -> std::io::Result<()>
+	where
+		A: core::fmt::Display,
+	{
+		let counts = self.counts.view().into_dyn();
+		match self.grid.projections() {
+			[bins] => {
+				let edges = bins.edges();
+				writeln!(w, "left_edge,right_edge,count")?;
+				for (i, count) in counts.iter().enumerate() {
+					writeln!(w, "{},{},{}", edges[i], edges[i + 1], count)?;
+				}
+			}
+			[row_bins, column_bins] => {
+				let (row_edges, column_edges) = (row_bins.edges(), column_bins.edges());
+				for j in 0..column_bins.len() {
+					write!(w, ",{}..{}", column_edges[j], column_edges[j + 1])?;
+				}
+				writeln!(w)?;
+				for (i, row) in counts.outer_iter().enumerate() {
+					write!(w, "{}..{}", row_edges[i], row_edges[i + 1])?;
+					for count in row {
+						write!(w, ",{}", count)?;
+					}
+					writeln!(w)?;
+				}
+			}
+			projections => {
+				return Err(std::io::Error::new(
+					std::io::ErrorKind::InvalidInput,
+					format!(
+						"CSV requires 1 or 2 dimensions, the histogram has {} dimensions.",
+						projections.len()
+					),
+				))
+			}
+		}
+		Ok(())
+	}
+
+	fn check_grid(&self, other: &Self, context: &'static str) -> Result<(), GridMismatch> {
+		if self.same_grid(other) {
+			Ok(())
+		} else {
+			Err(GridMismatch::new(
+				context,
+				ShapeMismatch {
+					first_shape: self.counts.shape().to_vec(),
+					second_shape: other.counts.shape().to_vec(),
+				},
+			))
+		}
+	}
+}
+
+/// Extension trait for `ArrayBase` providing methods to compute histograms.
+///
+/// It is implemented for 2-dimensional arrays and for dynamic-dimensional arrays which are
+/// required to be 2-dimensional at runtime.
+pub trait HistogramExt<A, S>
+where
+	S: Data<Elem = A>,
+{
+	/// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram)
+	/// for a 2-dimensional array of points `M`.
+	///
+	/// Let `(n, d)` be the shape of `M`:
+	/// - `n` is the number of points;
+	/// - `d` is the number of dimensions of the space those points belong to.
+	/// It follows that every column in `M` is a `d`-dimensional point.
+	///
+	/// For example: a (3, 4) matrix `M` is a collection of 3 points in a
+	/// 4-dimensional space.
+	///
+	/// Important: points outside the grid are ignored!
+	///
+	/// **Panics** if `d` is different from `grid.ndim()` or if `M` is a dynamic-dimensional array
+	/// which is not 2-dimensional.
+	///
+	/// # Example:
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{strategies::Sqrt, Bins, Edges, Grid, GridBuilder, Histogram},
+	/// 	o64, HistogramExt, O64,
+	/// };
+	///
+	/// let observations = array![
+	/// 	[o64(1.), o64(0.5)],
+	/// 	[o64(-0.5), o64(1.)],
+	/// 	[o64(-1.), o64(-0.5)],
+	/// 	[o64(0.5), o64(-1.)]
+	/// ];
+	/// let grid = GridBuilder::<Sqrt<O64>>::from_array(&observations)
+	/// 	.unwrap()
+	/// 	.build();
+	/// let expected_grid = Grid::from(vec![
+	/// 	Bins::new(Edges::from(vec![o64(-1.), o64(0.), o64(1.), o64(2.)])),
+	/// 	Bins::new(Edges::from(vec![o64(-1.), o64(0.), o64(1.), o64(2.)])),
+	/// ]);
+	/// assert_eq!(grid, expected_grid);
+	///
+	/// let histogram = observations.histogram(grid);
+	///
+	/// let histogram_matrix = histogram.counts();
+	/// // Bins are left inclusive, right exclusive!
+	/// let expected = array![[1, 0, 1], [1, 0, 0], [0, 1, 0],];
+	/// assert_eq!(histogram_matrix, expected.into_dyn());
+	/// ```
+	#[must_use]
+	fn histogram(&self, grid: Grid<A>) -> Histogram<A>
+	where
+		A: Ord + Send;
+
+	/// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram)
+	/// for a 2-dimensional array of points `M` without panicking on dimension mismatch.
+	///
+	/// Returns [`DimensionMismatch`] if the number of columns of `M` is different from
+	/// `grid.ndim()`. If `M` is a dynamic-dimensional array which is not 2-dimensional, it is
+	/// returned with `expected` being `2` and `found` being the number of dimensions of `M`.
+	///
+	/// # Example:
+	///
+	/// ```
+	/// use ndarray::{array, Array};
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let observations = array![[0], [1], [1]].into_dyn();
+	/// let histogram = observations.try_histogram(grid.clone())?;
+	/// assert_eq!(histogram.counts(), array![1, 2].into_dyn());
+	/// let observations = Array::from_elem(vec![3, 1, 1], 0);
+	/// assert!(observations.try_histogram(grid).is_err());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	///
+	/// See [`histogram`](#tymethod.histogram) for details.
+	///
+	/// [`DimensionMismatch`]: errors/struct.DimensionMismatch.html
+	fn try_histogram(&self, grid: Grid<A>) -> Result<Histogram<A>, DimensionMismatch>
+	where
+		A: Ord + Send;
+
+	/// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram)
+	/// for a 2-dimensional array of points `M` clamping points outside the grid into it.
+	///
+	/// Along each axis, coordinates below the first edge are counted in the first bin and
+	/// coordinates above or equal to the last edge are counted in the last bin, as if clamped to the
+	/// right-open range of the grid. Hence no point is dropped without requiring extra bins. Note
+	/// that this biases the counts of the edge bins.
+	///
+	/// See [`histogram`](#tymethod.histogram) for details.
+	///
+	/// **Panics** if `d` is different from `grid.ndim()` or if `M` is a dynamic-dimensional array
+	/// which is not 2-dimensional.
+	///
+	/// # Example:
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{
+	/// 	histogram::{Bins, Edges, Grid},
+	/// 	HistogramExt,
+	/// };
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let observations = array![[-5], [0], [1], [2], [7]];
+	/// let histogram = observations.histogram_clamped(grid);
+	/// assert_eq!(histogram.counts(), array![2, 3].into_dyn());
+	/// ```
+	#[must_use]
+	fn histogram_clamped(&self, grid: Grid<A>) -> Histogram<A>
+	where
+		A: Ord + Send;
+
+	/// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram)
+	/// for a 2-dimensional array of points `M` clamping points outside the grid into it without
+	/// panicking on dimension mismatch.
+	///
+	/// Returns [`DimensionMismatch`] as [`try_histogram`] does.
+	///
+	/// See [`histogram_clamped`](#tymethod.histogram_clamped) for details.
+	///
+	/// [`DimensionMismatch`]: errors/struct.DimensionMismatch.html
+	/// [`try_histogram`]: #tymethod.try_histogram
+	fn try_histogram_clamped(&self, grid: Grid<A>) -> Result<Histogram<A>, DimensionMismatch>
+	where
+		A: Ord + Send;
+
+	private_decl! {}
+}
+
+impl<A, S> HistogramExt<A, S> for ArrayBase<S, Ix2>
+where
+	S: Data<Elem = A>,
+	A: Ord + Send,
+{
+	fn histogram(&self, grid: Grid<A>) -> Histogram<A> {
+		let mut histogram = Histogram::new(grid);
+		histogram.add_observations(self);
+		histogram
+	}
+
+	fn try_histogram(&self, grid: Grid<A>) -> Result<Histogram<A>, DimensionMismatch> {
+		grid.check_ndim(self.ncols())?;
+		Ok(self.histogram(grid))
+	}
+
+	fn try_histogram_clamped(&self, grid: Grid<A>) -> Result<Histogram<A>, DimensionMismatch> {
+		grid.check_ndim(self.ncols())?;
+		Ok(self.histogram_clamped(grid))
+	}
+
+	fn histogram_clamped(&self, grid: Grid<A>) -> Histogram<A> {
+		let mut histogram = Histogram::new(grid);
+		for point in self.axis_iter(Axis(0)) {
+			assert_eq!(
+				point.len(),
+				histogram.ndim(),
+				"Dimension mismatch: the point has {:?} dimensions, the grid \
+				 expected {:?} dimensions.",
+				point.len(),
+				histogram.ndim()
+			);
+			let index = izip!(histogram.grid.projections(), &point)
+				.map(|(bins, value)| bins.clamped_index_of(value))
+				.collect::<Option<Vec<_>>>();
+			if let Some(index) = index {
+				histogram.counts[&*index] += 1;
+			}
+		}
+		histogram
+	}
+
+	private_impl! {}
+}
+
+impl<A, S> HistogramExt<A, S> for ArrayBase<S, IxDyn>
+where
+	S: Data<Elem = A>,
+	A: Ord + Send,
+{
+	fn histogram(&self, grid: Grid<A>) -> Histogram<A> {
+		observation_matrix(self)
+			.expect("Observations must be 2-dimensional")
+			.histogram(grid)
+	}
+
+	fn try_histogram(&self, grid: Grid<A>) -> Result<Histogram<A>, DimensionMismatch> {
+		observation_matrix(self)?.try_histogram(grid)
+	}
+
+	fn try_histogram_clamped(&self, grid: Grid<A>) -> Result<Histogram<A>, DimensionMismatch> {
+		observation_matrix(self)?.try_histogram_clamped(grid)
+	}
+
+	fn histogram_clamped(&self, grid: Grid<A>) -> Histogram<A> {
+		observation_matrix(self)
+			.expect("Observations must be 2-dimensional")
+			.histogram_clamped(grid)
+	}
+
+	private_impl! {}
+}
+
+/// Returns the 2-dimensional [histogram](https://en.wikipedia.org/wiki/Histogram) of the points
+/// whose coordinates along the first and second axis are given by `x` and `y`, respectively.
+///
+/// This mirrors `numpy.histogram2d` and is equivalent to the [`histogram`] of the `(n, 2)` matrix
+/// whose columns are `x` and `y` over the grid of `x_bins` and `y_bins`, without assembling the
+/// matrix. Points outside the grid are ignored.
+///
+/// Returns `Err(ShapeMismatch)` if `x` and `y` have different lengths.
+///
+/// **Panics** if the grid has too many bins, see [`Histogram::new`].
+///
+/// # Example:
+/// ```
+/// use ndarray::array;
+/// use ndarray_histogram::histogram::{histogram2d, Bins, Edges};
+///
+/// let x = array![0, 1, 1, 2];
+/// let y = array![0, 0, 1, 1];
+/// let x_bins = Bins::new(Edges::from(vec![0, 1, 2]));
+/// let y_bins = Bins::new(Edges::from(vec![0, 1, 2]));
+/// let histogram = histogram2d(&x, &y, x_bins, y_bins)?;
+/// assert_eq!(histogram.counts(), array![[1, 0], [1, 1]].into_dyn());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`histogram`]: trait.HistogramExt.html#tymethod.histogram
+/// [`Histogram::new`]: struct.Histogram.html#method.new
+pub fn histogram2d<A, S, S2>(
+	x: &ArrayBase<S, Ix1>,
+	y: &ArrayBase<S2, Ix1>,
+	x_bins: Bins<A>,
+	y_bins: Bins<A>,
+) -> Result<Histogram<A>, ShapeMismatch>
+where
+	A: Ord + Send,
+	S: Data<Elem = A>,
+	S2: Data<Elem = A>,
+{
+	if x.len() != y.len() {
+		return Err(ShapeMismatch {
+			first_shape: x.shape().to_vec(),
+			second_shape: y.shape().to_vec(),
+		});
+	}
+	let mut histogram = Histogram::new(Grid::from(vec![x_bins, y_bins]));
+	let [x_bins, y_bins] = histogram.grid.projections() else {
+		unreachable!();
+	};
+	for (x, y) in x.iter().zip(y) {
+		if let (Some(i), Some(j)) = (x_bins.index_of(x), y_bins.index_of(y)) {
+			histogram.counts[&[i, j][..]] += 1;
+		}
+	}
+	Ok(histogram)
+}
+
+/// Returns a 2-dimensional view of the dynamic-dimensional `observations`.
+fn observation_matrix<A, S>(
+	observations: &ArrayBase<S, IxDyn>,
+) -> Result<ArrayView2<'_, A>, DimensionMismatch>
+where
+	S: Data<Elem = A>,
+{
+	observations
+		.view()
+		.into_dimensionality()
+		.map_err(|_| DimensionMismatch {
+			expected: 2,
+			found: observations.ndim(),
+		})
+}
+
+/// Returns an array of zeros shaped like `grid`.
+///
+/// Returns `Err(GridError::TooManyBins)` if the product of the numbers of bins along each axis
+/// overflows `usize`, if the array would exceed `isize::MAX` bytes, or if the allocator fails to
+/// provide them.
+pub(super) fn try_zeros<A, T>(grid: &Grid<A>) -> Result<ArrayD<T>, GridError>
+where
+	A: Ord + Send,
+	T: Clone + Zero,
+{
+	let shape = grid.shape();
+	let size = shape
+		.iter()
+		.try_fold(1usize, |size, &len| size.checked_mul(len))
+		.filter(|size| {
+			size.checked_mul(mem::size_of::<T>())
+				.is_some_and(|bytes| isize::try_from(bytes).is_ok())
+		})
+		.ok_or(GridError::TooManyBins)?;
+	let mut zeros = Vec::new();
+	zeros
+		.try_reserve_exact(size)
+		.map_err(|_| GridError::TooManyBins)?;
+	zeros.resize(size, T::zero());
+	Ok(ArrayD::from_shape_vec(shape, zeros).unwrap())
+}
+
+/// Returns the index of the bin of `grid` containing `observation`.
+///
+/// **Panics** if dimensions do not match: `grid.ndim() != observation.len()`.
+pub(super) fn bin_index<A, S, D>(
+	grid: &Grid<A>,
+	observation: &ArrayBase<S, Ix1>,
+) -> Result<D, BinNotFound>
+where
+	A: Ord + Send,
+	S: Data<Elem = A>,
+	D: Dimension,
+{
+	assert_eq!(
+		observation.len(),
+		grid.ndim(),
+		"Dimension mismatch: the point has {:?} dimensions, the grid \
+		 expected {:?} dimensions.",
+		observation.len(),
+		grid.ndim()
+	);
+	let mut bin_index = D::zeros(grid.ndim());
+	for (i, bins, value) in izip!(bin_index.slice_mut(), grid.projections(), observation) {
+		*i = grid.bin_index_of(bins, value).ok_or(BinNotFound)?;
+	}
+	Ok(bin_index)
+}
+
+#[cfg(test)]
+mod histogram_tests {
+	use super::{histogram2d, Histogram, HistogramExt};
+	use crate::errors::ShapeMismatch;
+	use crate::histogram::{
+		errors::{
+			BinNotFound, DimensionMismatch, GridError, ObservationError, PermuteError, RebinError,
+			SliceError,
+		},
+		Bins, Edges, Grid,
+	};
+	#[cfg(feature = "std")]
+	use alloc::string::String;
+	use alloc::{string::ToString, vec, vec::Vec};
+	use ndarray::{array, s, Array1, Array2, Axis, Dimension, Ix1, Ix2, Ix3};
+
+	#[test]
+	fn try_new_with_overflowing_shape_is_bad() {
+		// 2^70 bins overflow `usize`.
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins; 70]);
+		assert!(matches!(
+			Histogram::try_new(grid),
+			Err(GridError::TooManyBins)
+		));
+	}
+
+	#[test]
+	fn try_new_with_oversized_shape_is_bad() {
+		// 2^62 bins do not overflow `usize` but their counts exceed `isize::MAX` bytes.
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins; 62]);
+		assert!(matches!(
+			Histogram::try_new(grid),
+			Err(GridError::TooManyBins)
+		));
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	#[cfg_attr(miri, ignore)]
+	fn try_new_with_unallocatable_shape_is_bad() {
+		// The counts of 2^59 bins fit into `isize::MAX` bytes but not into memory.
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins; 59]);
+		assert!(matches!(
+			Histogram::try_new(grid),
+			Err(GridError::TooManyBins)
+		));
+	}
+
+	#[test]
+	fn counts_mut_writes_counts() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let mut histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		histogram.counts_mut()[[0, 1]] = 7;
+		histogram.counts_mut().index_axis_mut(Axis(0), 1).fill(3);
+		assert_eq!(histogram.counts(), array![[0, 7], [3, 3]].into_dyn());
+	}
+
+	#[test]
+	fn mode_bin_is_unique() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[0, 2], [1, 1], [2, 0], [1, 1], [0, 2], [1, 1]];
+		let histogram = observations.histogram(grid);
+		assert_eq!(histogram.mode_bin(), Some(vec![1, 1]));
+		assert_eq!(histogram.mode_bins(), vec![vec![1, 1]]);
+	}
+
+	#[test]
+	fn mode_bins_are_tied() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[2, 0], [0, 2], [1, 1], [2, 0], [0, 2]];
+		let histogram = observations.histogram(grid);
+		assert_eq!(histogram.mode_bin(), Some(vec![0, 2]));
+		assert_eq!(histogram.mode_bins(), vec![vec![0, 2], vec![2, 0]]);
+	}
+
+	#[test]
+	fn coverage_interval_of_peaked_histogram() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![
+			0, 1, 2, 3, 4, 5, 6, 7, 8,
+		]))]);
+		let mut histogram = Histogram::new(grid);
+		let counts = [1, 0, 2, 10, 30, 12, 3, 2];
+		histogram
+			.counts_mut()
+			.assign(&Array1::from(counts.to_vec()).into_dyn());
+		// The peak alone holds half of the 60 observations.
+		assert_eq!(histogram.coverage_interval(0.5), Some((4, 4)));
+		assert_eq!(histogram.coverage_interval(0.6), Some((4, 5)));
+		assert_eq!(histogram.coverage_interval(0.85), Some((3, 5)));
+		assert_eq!(histogram.coverage_interval(0.9), Some((3, 6)));
+		assert_eq!(histogram.coverage_interval(0.95), Some((2, 6)));
+		assert_eq!(histogram.coverage_interval(1.), Some((0, 7)));
+		assert_eq!(histogram.coverage_interval(1e-9), Some((4, 4)));
+		for fraction in [0.1, 0.3, 0.5, 0.7, 0.9, 1.] {
+			let (start, end) = histogram.coverage_interval(fraction).unwrap();
+			let covered = counts[start..=end].iter().sum::<usize>();
+			assert!(covered as f64 >= fraction * 60.);
+		}
+		let empty = Histogram::new(histogram.grid().clone());
+		assert_eq!(empty.coverage_interval(0.5), None);
+	}
+
+	#[test]
+	fn coverage_interval_is_not_widened_by_rounding() {
+		let grid = Grid::from(vec![Bins::new(Edges::from((0..=100).collect::<Vec<_>>()))]);
+		let mut histogram = Histogram::new(grid);
+		histogram.counts_mut().fill(1);
+		// `0.07 * 100.` is `7.000000000000001`.
+		assert_eq!(histogram.coverage_interval(0.07), Some((0, 6)));
+		assert_eq!(histogram.coverage_interval(0.071), Some((0, 7)));
+	}
+
+	#[test]
+	#[should_panic(expected = "Fraction must be in (0, 1]")]
+	fn coverage_interval_of_zero_fraction_is_bad() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+		let _ = Histogram::new(grid).coverage_interval(0.);
+	}
+
+	#[test]
+	#[should_panic(expected = "Coverage interval requires a 1-dimensional histogram")]
+	fn coverage_interval_of_2d_histogram_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let _ = Histogram::new(Grid::from(vec![bins.clone(), bins])).coverage_interval(0.5);
+	}
+
+	#[test]
+	fn nonzero_bins_of_sparse_histogram() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3, 4]));
+		let grid = Grid::from(vec![bins; 4]);
+		let observations = array![
+			[3, 0, 2, 1],
+			[0, 0, 0, 0],
+			[3, 0, 2, 1],
+			[1, 2, 3, 0],
+			[3, 0, 2, 1],
+			[0, 0, 0, 0],
+		];
+		let histogram = observations.histogram(grid);
+		assert_eq!(histogram.counts().len(), 256);
+		let populated: Vec<_> = histogram.nonzero_bins().collect();
+		assert_eq!(
+			populated,
+			vec![
+				(vec![0, 0, 0, 0], 2),
+				(vec![1, 2, 3, 0], 1),
+				(vec![3, 0, 2, 1], 3),
+			]
+		);
+		let empty = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1]))]));
+		assert_eq!(empty.nonzero_bins().count(), 0);
+	}
+
+	#[test]
+	fn centroid_of_symmetric_histogram_is_its_center() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4, 6, 8]))]);
+		let observations = array![[0], [1], [2], [3], [3], [3], [4], [5], [5], [5], [6], [7]];
+		let histogram = observations.histogram(grid);
+		assert_eq!(histogram.counts(), array![2, 4, 4, 2].into_dyn());
+		assert_eq!(histogram.centroid(), Some(vec![4.]));
+	}
+
+	#[test]
+	fn centroid_of_asymmetric_histogram() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 5]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[0, 4], [0, 4], [0, 4], [1, 0], [4, 1]];
+		let histogram = observations.histogram(grid);
+		let centroid = histogram.centroid().unwrap();
+		assert_eq!(centroid.len(), 2);
+		assert!((centroid[0] - (3. * 0.5 + 1.5 + 3.5) / 5.).abs() < 1e-12);
+		assert!((centroid[1] - (3. * 3.5 + 0.5 + 1.5) / 5.).abs() < 1e-12);
+		assert_eq!(Histogram::new(histogram.grid().clone()).centroid(), None);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn write_csv_of_1d_histogram() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![-2, 0, 5, 6]))]);
+		let histogram = array![[-2], [4], [0], [5], [1]].histogram(grid);
+		let mut csv = Vec::new();
+		histogram.write_csv(&mut csv).unwrap();
+		assert_eq!(
+			String::from_utf8(csv).unwrap(),
+			"left_edge,right_edge,count\n-2,0,1\n0,5,3\n5,6,1\n"
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn write_csv_of_2d_histogram() {
+		let grid = Grid::from(vec![
+			Bins::new(Edges::from(vec![0, 1, 2])),
+			Bins::new(Edges::from(vec![0, 10, 20, 40])),
+		]);
+		let histogram = array![[0, 5], [0, 5], [1, 30], [1, 15], [0, 39]].histogram(grid);
+		let mut csv = Vec::new();
+		histogram.write_csv(&mut csv).unwrap();
+		assert_eq!(
+			String::from_utf8(csv).unwrap(),
+			",0..10,10..20,20..40\n0..1,2,0,1\n1..2,0,1,1\n"
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn write_csv_of_3d_histogram_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1]));
+		let histogram = Histogram::new(Grid::from(vec![bins; 3]));
+		let mut csv = Vec::new();
+		let error = histogram.write_csv(&mut csv).unwrap_err();
+		assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+		assert!(csv.is_empty());
+	}
+
+	#[test]
+	fn mode_bin_of_empty_histogram_is_none() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		assert_eq!(histogram.mode_bin(), None);
+		assert!(histogram.mode_bins().is_empty());
+	}
+
+	#[test]
+	fn rebin_onto_nested_grid() {
+		let fine_grid = Grid::from(vec![Bins::new(Edges::from((0..=10).collect::<Vec<_>>()))]);
+		let coarse_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4, 6, 8, 10]))]);
+		let observations = array![[0], [1], [1], [3], [4], [5], [5], [6], [9], [9], [9], [10]];
+		let histogram = observations.histogram(fine_grid);
+		let coarse_histogram = histogram.rebin(&coarse_grid).unwrap();
+		assert_eq!(coarse_histogram.grid(), &coarse_grid);
+		assert_eq!(coarse_histogram.counts(), array![3, 1, 3, 1, 3].into_dyn());
+	}
+
+	#[test]
+	fn rebin_discards_bins_outside_coarse_grid() {
+		let fine_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4]))]);
+		let coarse_grid = Grid::from(vec![Bins::new(Edges::from(vec![1, 3]))]);
+		let observations = array![[0], [1], [2], [3]];
+		let histogram = observations.histogram(fine_grid);
+		let coarse_histogram = histogram.rebin(&coarse_grid).unwrap();
+		assert_eq!(coarse_histogram.counts(), array![2].into_dyn());
+	}
+
+	#[test]
+	fn rebin_onto_misaligned_grid_is_bad() {
+		let fine_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4, 6]))]);
+		let coarse_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 3, 6]))]);
+		let histogram = Histogram::new(fine_grid);
+		assert!(matches!(
+			histogram.rebin(&coarse_grid),
+			Err(RebinError::MisalignedEdges)
+		));
+	}
+
+	#[test]
+	fn rebin_onto_grid_of_other_dimension_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 2, 4, 6]));
+		let histogram = Histogram::new(Grid::from(vec![bins.clone(), bins.clone()]));
+		assert!(matches!(
+			histogram.rebin(&Grid::from(vec![bins])),
+			Err(RebinError::DimensionMismatch)
+		));
+	}
+
+	#[test]
+	fn add_observation_checked_with_wrong_dimension_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let mut histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		assert_eq!(
+			histogram.add_observation_checked(&array![1]),
+			Err(ObservationError::DimensionMismatch(DimensionMismatch {
+				expected: 2,
+				found: 1
+			}))
+		);
+		assert_eq!(
+			histogram.add_observation_checked(&array![1, 2]),
+			Err(ObservationError::BinNotFound)
+		);
+		assert_eq!(histogram.add_observation_checked(&array![1, 1]), Ok(()));
+		assert_eq!(histogram.counts(), array![[0, 0], [0, 1]].into_dyn());
+	}
+
+	#[test]
+	fn checked_add_observation_reports_newly_populated_bins() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let mut histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		assert!(histogram.checked_add_observation(&array![0, 1]).unwrap());
+		assert!(!histogram.checked_add_observation(&array![0, 1]).unwrap());
+		assert!(histogram.checked_add_observation(&array![1, 1]).unwrap());
+		assert!(matches!(
+			histogram.checked_add_observation(&array![2, 1]),
+			Err(BinNotFound)
+		));
+		assert_eq!(histogram.counts(), array![[0, 2], [0, 1]].into_dyn());
+	}
+
+	#[test]
+	fn reproject_onto_expanded_grid() {
+		let grid = Grid::from(vec![
+			Bins::new(Edges::from(vec![0, 1, 2])),
+			Bins::new(Edges::from(vec![0, 5, 10])),
+		]);
+		let observations = array![[0, 0], [1, 7], [1, 9], [0, 6]];
+		let histogram = observations.histogram(grid);
+		let expanded_grid = Grid::from(vec![
+			Bins::new(Edges::from(vec![-1, 0, 1, 2, 3])),
+			Bins::new(Edges::from(vec![0, 5, 10, 15])),
+		]);
+		let (expanded, dropped) = histogram.reproject(&expanded_grid).unwrap();
+		assert_eq!(dropped, 0);
+		assert_eq!(
+			expanded.counts(),
+			array![[0, 0, 0], [1, 1, 0], [0, 2, 0], [0, 0, 0]].into_dyn()
+		);
+		assert_eq!(
+			expanded.counts().slice(s![1..3, 0..2]),
+			histogram.counts().into_dimensionality::<Ix2>().unwrap()
+		);
+		assert!(histogram.reproject(&Grid::from(vec![])).is_err());
+	}
+
+	#[test]
+	fn reproject_onto_shifted_grid() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4, 6]))]);
+		let histogram = array![[0], [1], [2], [4], [5], [5]].histogram(grid);
+		assert_eq!(histogram.counts(), array![2, 1, 3].into_dyn());
+		// Centers 1, 3, and 5 lie within the bins starting at 1, 3, and 5.
+		let shifted_grid = Grid::from(vec![Bins::new(Edges::from(vec![1, 3, 5, 7]))]);
+		let (shifted, dropped) = histogram.reproject(&shifted_grid).unwrap();
+		assert_eq!(shifted.counts(), array![2, 1, 3].into_dyn());
+		assert_eq!(dropped, 0);
+		// The center 1 is outside unless the extreme bins are open-ended.
+		let shifted_grid = Grid::from(vec![Bins::new(Edges::from(vec![2, 4, 8]))]);
+		let (shifted, dropped) = histogram.reproject(&shifted_grid).unwrap();
+		assert_eq!(shifted.counts(), array![1, 3].into_dyn());
+		assert_eq!(dropped, 2);
+		let (shifted, dropped) = histogram.reproject(&shifted_grid.with_open_ends()).unwrap();
+		assert_eq!(shifted.counts(), array![3, 3].into_dyn());
+		assert_eq!(dropped, 0);
+	}
+
+	#[test]
+	fn try_histogram_with_wrong_dimension_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[0, 1, 1], [1, 1, 0]];
+		assert!(matches!(
+			observations.try_histogram(grid.clone()),
+			Err(DimensionMismatch {
+				expected: 2,
+				found: 3
+			})
+		));
+		let observations = array![[0, 1], [1, 1]];
+		let histogram = observations.try_histogram(grid).unwrap();
+		assert_eq!(histogram.counts(), array![[0, 1], [0, 1]].into_dyn());
+	}
+
+	#[test]
+	fn try_histogram_clamped_with_wrong_dimension_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		assert!(matches!(
+			array![[0], [5]].try_histogram_clamped(grid.clone()),
+			Err(DimensionMismatch {
+				expected: 2,
+				found: 1
+			})
+		));
+		assert!(matches!(
+			array![0, 5].into_dyn().try_histogram_clamped(grid.clone()),
+			Err(DimensionMismatch {
+				expected: 2,
+				found: 1
+			})
+		));
+		let histogram = array![[-1, 5], [1, 1]].try_histogram_clamped(grid).unwrap();
+		assert_eq!(histogram.counts(), array![[0, 1], [0, 1]].into_dyn());
+	}
+
+	#[test]
+	fn try_add_observations_with_wrong_dimension_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let mut histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		assert_eq!(
+			histogram.try_add_observations(&array![[0, 1, 1]]),
+			Err(DimensionMismatch {
+				expected: 2,
+				found: 3
+			})
+		);
+		assert_eq!(histogram.counts().sum(), 0);
+		assert_eq!(
+			histogram.try_add_observations(&array![[0, 1], [2, 0]]),
+			Ok(1)
+		);
+		assert_eq!(histogram.counts(), array![[0, 1], [0, 0]].into_dyn());
+	}
+
+	#[test]
+	fn distances_of_identical_histograms() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let a = array![[0], [1], [1], [2], [2], [2]].histogram(grid);
+		assert_eq!(a.intersection(&a), Ok(1.));
+		assert_eq!(a.chi_square_distance(&a), Ok(0.));
+	}
+
+	#[test]
+	fn distances_of_disjoint_histograms() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let a = array![[0], [0], [1]].histogram(grid.clone());
+		let b = array![[2], [2], [2], [2]].histogram(grid);
+		assert_eq!(a.intersection(&b), Ok(0.));
+		// 0.5 * (2^2 / 2 + 1^2 / 1 + 4^2 / 4)
+		assert_eq!(a.chi_square_distance(&b), Ok(3.5));
+	}
+
+	#[test]
+	fn distances_of_histograms_with_different_grids_are_bad() {
+		let a = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]));
+		let b = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]));
+		let err = a.intersection(&b).unwrap_err();
+		assert!(!err.is_edges_mismatch());
+		assert_eq!(err.context(), "histogram intersection");
+		assert_eq!(
+			err.to_string(),
+			"Grids do not match during histogram intersection: shapes [2] and [3]."
+		);
+		let shape_mismatch = ShapeMismatch {
+			first_shape: vec![2],
+			second_shape: vec![3],
+		};
+		assert_eq!(err.shape_mismatch(), &shape_mismatch);
+		#[cfg(feature = "std")]
+		assert_eq!(
+			std::error::Error::source(&err).map(ToString::to_string),
+			Some(shape_mismatch.to_string())
+		);
+		let into_shape_mismatch = || -> Result<f64, ShapeMismatch> { Ok(a.intersection(&b)?) };
+		assert_eq!(into_shape_mismatch(), Err(shape_mismatch));
+		assert_eq!(
+			a.chi_square_distance(&b).unwrap_err().to_string(),
+			"Grids do not match during histogram chi-square distance: shapes [2] and [3]."
+		);
+	}
+
+	#[test]
+	fn distances_of_histograms_with_different_edges_are_bad() {
+		let a = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]));
+		let b = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 3]))]));
+		let err = a.intersection(&b).unwrap_err();
+		assert!(err.is_edges_mismatch());
+		assert_eq!(
+			err.to_string(),
+			"Grids do not match during histogram intersection: edges differ."
+		);
+		let err = a.chi_square_distance(&b).unwrap_err();
+		assert!(err.is_edges_mismatch());
+		assert_eq!(
+			err.to_string(),
+			"Grids do not match during histogram chi-square distance: edges differ."
+		);
+	}
+
+	#[test]
+	fn histogram2d_matches_matrix_histogram() {
+		let x = array![0, 4, 1, 1, 3, 2, -1, 2, 5, 0];
+		let y = array![1, 0, 2, 2, 1, 0, 1, 3, 1, 0];
+		let x_bins = Bins::new(Edges::from(vec![0, 1, 3, 5]));
+		let y_bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let histogram = histogram2d(&x, &y, x_bins.clone(), y_bins.clone()).unwrap();
+		let observations = ndarray::stack![Axis(1), x, y];
+		let expected = observations.histogram(Grid::from(vec![x_bins, y_bins]));
+		assert!(histogram.same_grid(&expected));
+		assert_eq!(histogram.counts(), expected.counts());
+		assert_eq!(histogram.counts().sum(), 7);
+	}
+
+	#[test]
+	fn histogram2d_with_different_lengths_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		assert!(matches!(
+			histogram2d(&array![0, 1], &array![0, 1, 1], bins.clone(), bins),
+			Err(ShapeMismatch { first_shape, second_shape })
+				if first_shape == [2] && second_shape == [3]
+		));
+	}
+
+	#[test]
+	fn open_ends_count_outliers_in_extreme_bins() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let observations = array![[-100], [0], [1], [2], [3], [100]];
+		let histogram = observations.histogram(grid.clone());
+		assert_eq!(histogram.counts(), array![1, 1, 1].into_dyn());
+		let histogram = observations.histogram(grid.with_open_ends());
+		assert_eq!(histogram.counts(), array![2, 1, 3].into_dyn());
+	}
+
+	#[test]
+	fn same_grid_ignores_counts() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let a = array![[0], [1], [1]].histogram(grid.clone());
+		let b = array![[2], [2]].histogram(grid);
+		let c = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 3]))]));
+		assert!(a.same_grid(&b));
+		assert!(!a.same_grid(&c));
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn entropy_of_uniform_histogram_is_maximal() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4]))]);
+		let histogram = array![[0], [1], [2], [3], [0], [1], [2], [3]].histogram(grid);
+		assert_eq!(histogram.entropy(), 2.);
+		assert_eq!(histogram.normalized_entropy(), 1.);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn entropy_of_single_bin_histogram_is_zero() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4]))]);
+		let histogram = array![[2], [2], [2]].histogram(grid.clone());
+		assert_eq!(histogram.entropy(), 0.);
+		assert_eq!(histogram.normalized_entropy(), 0.);
+		let histogram = Histogram::new(grid);
+		assert_eq!(histogram.entropy(), 0.);
+		assert_eq!(histogram.normalized_entropy(), 0.);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn mutual_information_of_independent_axes_is_zero() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let mut observations = Vec::new();
+		// Joint counts are the outer product of the marginal counts `[1, 2, 3]` and `[2, 1, 1]`.
+		for (a, n_a) in [(0, 1), (1, 2), (2, 3)] {
+			for (b, n_b) in [(0, 2), (1, 1), (2, 1)] {
+				for _ in 0..n_a * n_b {
+					observations.extend([a, b]);
+				}
+			}
+		}
+		let observations = Array2::from_shape_vec((observations.len() / 2, 2), observations);
+		let histogram = observations.unwrap().histogram(grid);
+		assert!(histogram.mutual_information(0, 1).abs() < 1e-12);
+		assert!(histogram.mutual_information(1, 0).abs() < 1e-12);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn mutual_information_of_correlated_axes_is_entropy() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3, 4]));
+		let grid = Grid::from(vec![bins.clone(), bins.clone(), bins]);
+		let observations = array![
+			[0, 0, 3],
+			[1, 1, 0],
+			[1, 1, 2],
+			[2, 2, 1],
+			[2, 2, 1],
+			[3, 3, 0]
+		];
+		let histogram = observations.histogram(grid);
+		let marginal = Histogram::from_counts(
+			Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4]))]),
+			histogram.project(0).into_dyn(),
+		)
+		.unwrap();
+		let entropy = marginal.entropy();
+		assert!((histogram.mutual_information(0, 1) - entropy).abs() < 1e-12);
+		assert!((histogram.mutual_information(1, 0) - entropy).abs() < 1e-12);
+		assert!((histogram.mutual_information(0, 0) - entropy).abs() < 1e-12);
+		assert!(Histogram::new(histogram.grid().clone()).mutual_information(0, 2) == 0.);
+	}
+
+	#[test]
+	fn map_counts_applies_log_transform() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let histogram = array![[0], [2], [2], [2]].histogram(grid);
+		let log_counts = histogram.map_counts(|count| (count as f64).ln_1p());
+		assert_eq!(log_counts, array![2_f64.ln(), 0., 4_f64.ln()].into_dyn());
+		assert_eq!(histogram.counts(), array![1, 0, 3].into_dyn());
+	}
+
+	#[test]
+	fn conditional_slices_sum_up_to_one() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), Bins::new(Edges::from(vec![0, 1, 2]))]);
+		let observations = array![[0, 0], [0, 1], [0, 1], [2, 0], [2, 0], [2, 1], [1, 1]];
+		let histogram = observations.histogram(grid);
+		let given_0 = histogram.conditional(0).unwrap();
+		assert_eq!(given_0.shape(), &[3, 2]);
+		for row in given_0.rows() {
+			assert!((row.sum() - 1.).abs() < 1e-12);
+		}
+		assert_eq!(given_0.row(0), array![1. / 3., 2. / 3.]);
+		assert_eq!(given_0.row(1), array![0., 1.]);
+		let given_1 = histogram.conditional(1).unwrap();
+		for column in given_1.columns() {
+			assert!((column.sum() - 1.).abs() < 1e-12);
+		}
+		assert_eq!(given_1.column(0), array![1. / 3., 0., 2. / 3.]);
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let histogram = array![[0, 0], [2, 1]].histogram(grid);
+		assert_eq!(histogram.conditional(0).unwrap().row(1), array![0., 0., 0.]);
+	}
+
+	#[test]
+	fn conditional_requires_two_dimensions() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+		let histogram = array![[0], [1]].histogram(grid);
+		assert!(histogram.conditional(0).is_err());
+	}
+
+	#[test]
+	fn probabilities_sum_up_to_one() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[0, 0], [1, 2], [2, 2], [2, 2], [0, 1], [1, 1], [2, 0]];
+		let probabilities = observations.histogram(grid.clone()).as_probabilities();
+		assert!((probabilities.sum() - 1.).abs() < 1e-12);
+		assert_eq!(probabilities[[2, 2]], 2. / 7.);
+		assert_eq!(probabilities[[0, 2]], 0.);
+		let empty = Histogram::new(grid).as_probabilities();
+		assert_eq!(empty.shape(), &[3, 3]);
+		assert!(empty.iter().all(|&probability| probability == 0.));
+	}
+
+	#[test]
+	#[cfg(feature = "approx")]
+	fn probabilities_approx_eq_frequencies() {
+		use approx::assert_abs_diff_eq;
+
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let histogram = array![[0], [1], [1], [2], [2], [2], [2]].histogram(grid);
+		let frequencies = array![1., 2., 4.].into_dyn() / 7.;
+		assert_abs_diff_eq!(histogram.as_probabilities(), frequencies, epsilon = 1e-15);
+	}
+
+	#[test]
+	fn counts_as_converts_counts() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let mut histogram = array![[0], [2], [2], [2]].histogram(grid);
+		assert_eq!(histogram.counts_as::<usize>(), histogram.counts());
+		assert_eq!(
+			histogram.try_counts_as::<u64>(),
+			Ok(array![1_u64, 0, 3].into_dyn())
+		);
+		histogram.counts_mut()[[1]] = 256;
+		assert!(histogram.try_counts_as::<u8>().is_err());
+		assert_eq!(
+			histogram.try_counts_as::<u16>(),
+			Ok(array![1_u16, 256, 3].into_dyn())
+		);
+	}
+
+	#[test]
+	fn static_counts_match_dynamic_counts() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let histogram = array![[0], [2], [2]].histogram(Grid::from(vec![bins.clone()]));
+		assert_eq!(
+			histogram.counts_1d().unwrap().into_dyn(),
+			histogram.counts()
+		);
+		assert_eq!(
+			histogram.counts_2d(),
+			Err(DimensionMismatch {
+				expected: 2,
+				found: 1
+			})
+		);
+		let histogram = array![[0, 1], [2, 2]].histogram(Grid::from(vec![bins.clone(), bins]));
+		assert_eq!(
+			histogram.counts_2d().unwrap().into_dyn(),
+			histogram.counts()
+		);
+		assert_eq!(
+			histogram.counts_1d(),
+			Err(DimensionMismatch {
+				expected: 1,
+				found: 2
+			})
+		);
+	}
+
+	#[test]
+	fn histograms_batched_match_individual_histograms() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let batches = [
+			array![[0, 1], [2, 2], [2, 2]],
+			array![[1, 1]],
+			array![[3, 0], [0, 0]],
+		];
+		let views = batches.iter().map(|batch| batch.view()).collect::<Vec<_>>();
+		let histograms = Histogram::histograms_batched(&views, &grid);
+		assert_eq!(histograms.len(), batches.len());
+		for (histogram, batch) in histograms.iter().zip(&batches) {
+			assert_eq!(histogram.counts(), batch.histogram(grid.clone()).counts());
+		}
+		#[cfg(feature = "rayon")]
+		for (histogram, par_histogram) in histograms
+			.iter()
+			.zip(Histogram::par_histograms_batched(&views, &grid))
+		{
+			assert_eq!(histogram.counts(), par_histogram.counts());
+		}
+	}
+
+	#[test]
+	fn histogram_clamped_keeps_outliers_in_edge_bins() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[-1, 1], [-9, -9], [1, 3], [7, 9], [2, 1]];
+		let histogram = observations.histogram_clamped(grid.clone());
+		assert_eq!(
+			histogram.counts(),
+			array![[1, 1, 0], [0, 0, 1], [0, 1, 1]].into_dyn()
+		);
+		let histogram = observations.histogram(grid);
+		assert_eq!(histogram.counts().sum(), 1);
+	}
+
+	#[test]
+	#[should_panic(expected = "Dimension mismatch")]
+	fn histogram_clamped_with_wrong_dimension_panics() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1]))]);
+		let _ = array![[0, 0]].histogram_clamped(grid);
+	}
+
+	#[test]
+	fn from_counts_with_matching_shape() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let histogram = array![[0, 1], [2, 2], [2, 2]].histogram(grid.clone());
+		let counts = histogram.counts().to_owned();
+		let rebuilt = Histogram::from_counts(grid.clone(), counts).unwrap();
+		assert_eq!(rebuilt.counts(), histogram.counts());
+		assert_eq!(rebuilt.grid(), &grid);
+	}
+
+	#[test]
+	fn from_counts_with_mismatched_shape_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let counts = array![[1, 2], [3, 4]].into_dyn();
+		assert!(matches!(
+			Histogram::from_counts(grid.clone(), counts),
+			Err(ShapeMismatch { first_shape, second_shape })
+				if first_shape == [3, 3] && second_shape == [2, 2]
+		));
+		let counts = array![1, 2, 3].into_dyn();
+		assert!(Histogram::from_counts(grid, counts).is_err());
+	}
+
+	fn static_histogram_matches_dynamic<D: Dimension>(observations: Array2<i32>) {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins; observations.ncols()]);
+		let mut histogram = Histogram::new(grid.clone())
+			.into_dimensionality::<D>()
+			.unwrap();
+		for observation in observations.rows() {
+			let _ = histogram.add_observation(&observation);
+		}
+		assert_eq!(histogram.counts().ndim(), D::NDIM.unwrap());
+		let expected = observations.histogram(grid);
+		assert_eq!(histogram.mode_bins(), expected.mode_bins());
+		assert_eq!(histogram.into_dyn().counts(), expected.counts());
+	}
+
+	#[test]
+	fn static_histograms_match_dynamic() {
+		static_histogram_matches_dynamic::<Ix1>(array![[0], [2], [2], [5]]);
+		static_histogram_matches_dynamic::<Ix2>(array![[0, 1], [2, 2], [2, 2], [3, 0]]);
+		static_histogram_matches_dynamic::<Ix3>(array![
+			[0, 1, 2],
+			[2, 2, 0],
+			[2, 2, 0],
+			[1, 1, -1]
+		]);
+	}
+
+	#[test]
+	fn static_histogram_rebins() {
+		let fine_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4])); 2]);
+		let coarse_grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4])); 2]);
+		let histogram = array![[0, 1], [1, 3], [3, 3]]
+			.histogram(fine_grid)
+			.into_dimensionality::<Ix2>()
+			.unwrap();
+		let coarse_histogram = histogram.rebin(&coarse_grid).unwrap();
+		assert_eq!(coarse_histogram.counts(), array![[1, 1], [0, 1]]);
+	}
+
+	#[test]
+	fn into_dimensionality_with_wrong_dimension_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		assert!(matches!(
+			histogram.into_dimensionality::<Ix3>(),
+			Err(DimensionMismatch {
+				expected: 3,
+				found: 2
+			})
+		));
+	}
+
+	#[test]
+	fn retain_zeroes_bins_below_threshold() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[0, 0], [0, 0], [0, 0], [1, 2], [2, 1], [2, 1], [2, 2]];
+		let mut histogram = observations.histogram(grid);
+		let counts = histogram.counts().to_owned();
+		histogram.retain(|_index, count| count >= 2);
+		assert_eq!(
+			histogram.counts(),
+			array![[3, 0, 0], [0, 0, 0], [0, 2, 0]].into_dyn()
+		);
+		for (count, &before) in histogram.counts().iter().zip(&counts) {
+			assert!(*count == before || *count == 0);
+		}
+		histogram.retain(|index, _count| index != [0, 0]);
+		assert_eq!(histogram.counts().sum(), 2);
+	}
+
+	#[test]
+	fn add_observations_accumulates_chunks() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[0, 1], [2, 2], [5, 0], [1, 1], [2, 2], [0, -1], [1, 0]];
+		let mut histogram = Histogram::new(grid.clone());
+		assert_eq!(
+			histogram.add_observations(&observations.slice(s![..3, ..])),
+			1
+		);
+		assert_eq!(
+			histogram.add_observations(&observations.slice(s![3.., ..])),
+			1
+		);
+		assert_eq!(histogram.counts(), observations.histogram(grid).counts());
+		assert_eq!(histogram.counts().sum(), 5);
+	}
+
+	#[test]
+	#[should_panic(expected = "Dimension mismatch")]
+	fn add_observations_with_wrong_dimension_panics() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1]))]);
+		let _ = Histogram::new(grid).add_observations(&array![[0, 0]]);
+	}
+
+	#[test]
+	fn smooth_spreads_delta_with_box_kernel() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3, 4, 5]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let histogram = array![[2, 2], [2, 2], [2, 2]].histogram(grid);
+		let kernel = [1. / 3.; 3];
+		let smoothed = histogram.smooth(&kernel);
+		assert_eq!(smoothed.shape(), &[5, 5]);
+		for ((i, j), &density) in smoothed
+			.view()
+			.into_dimensionality::<Ix2>()
+			.unwrap()
+			.indexed_iter()
+		{
+			let expected = if (1..=3).contains(&i) && (1..=3).contains(&j) {
+				1. / 9.
+			} else {
+				0.
+			};
+			assert!((density - expected).abs() < 1e-12);
+		}
+		assert!((smoothed.sum() - 1.).abs() < 1e-12);
+		assert_eq!(histogram.counts()[[2, 2]], 3);
+	}
+
+	#[test]
+	fn smooth_zero_pads_edges() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let histogram = array![[0], [0], [2], [2]].histogram(grid.clone());
+		let smoothed = histogram.smooth(&[0.5, 0., 0.5]);
+		assert_eq!(smoothed, array![0., 0.5, 0.].into_dyn());
+		assert_eq!(histogram.smooth(&[1.]), array![0.5, 0., 0.5].into_dyn());
+		let empty = Histogram::new(grid);
+		assert_eq!(empty.smooth(&[0.5, 0., 0.5]), array![0., 0., 0.].into_dyn());
+	}
+
+	#[test]
+	#[should_panic(expected = "Kernel length must be odd")]
+	fn smooth_with_even_kernel_panics() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3]))]);
+		let _ = Histogram::new(grid).smooth(&[0.5, 0.5]);
+	}
+
+	#[test]
+	fn dynamic_observations_match_static_observations() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let observations = array![[0, 1], [2, 2], [5, 0], [2, 2]];
+		let dynamic = observations.clone().into_dyn();
+		let expected = observations.histogram(grid.clone());
+		assert_eq!(dynamic.histogram(grid.clone()).counts(), expected.counts());
+		assert_eq!(
+			dynamic.try_histogram(grid.clone()).unwrap().counts(),
+			expected.counts()
+		);
+		assert_eq!(
+			dynamic.histogram_clamped(grid.clone()).counts(),
+			observations.histogram_clamped(grid).counts()
+		);
+	}
+
+	#[test]
+	fn dynamic_observations_of_wrong_rank_are_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		assert_eq!(
+			array![0, 1].into_dyn().try_histogram(grid.clone()).err(),
+			Some(DimensionMismatch {
+				expected: 2,
+				found: 1
+			})
+		);
+		assert_eq!(
+			array![[[0, 1]]]
+				.into_dyn()
+				.try_histogram(grid.clone())
+				.err(),
+			Some(DimensionMismatch {
+				expected: 2,
+				found: 3
+			})
+		);
+		assert_eq!(
+			array![[0, 1, 2]].into_dyn().try_histogram(grid).err(),
+			Some(DimensionMismatch {
+				expected: 2,
+				found: 3
+			})
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Observations must be 2-dimensional")]
+	fn dynamic_observations_of_wrong_rank_panic() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1]))]);
+		let _ = array![0, 1].into_dyn().histogram(grid);
+	}
+
+	#[test]
+	fn project_sums_over_other_axes() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins.clone(), bins]);
+		let observations = array![
+			[0, 1, 2],
+			[2, 2, 0],
+			[2, 2, 1],
+			[1, 0, 0],
+			[0, 0, 0],
+			[0, 2, 2]
+		];
+		let histogram = observations.histogram(grid);
+		assert_eq!(histogram.project(0), array![3, 1, 2]);
+		assert_eq!(histogram.project(1), array![2, 1, 3]);
+		assert_eq!(histogram.project(2), array![3, 1, 2]);
+		for axis in 0..3 {
+			let mut expected = histogram.counts().to_owned();
+			for other in (0..3).rev().filter(|&other| other != axis) {
+				expected = expected.sum_axis(Axis(other));
+			}
+			assert_eq!(histogram.project(axis).into_dyn(), expected);
+		}
+		let histogram = histogram.into_dimensionality::<Ix3>().unwrap();
+		assert_eq!(histogram.project(1), array![2, 1, 3]);
+	}
+
+	#[test]
+	fn slice_3d_histogram_at_bin() {
+		let x = Bins::new(Edges::from(vec![0, 1, 2]));
+		let y = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let z = Bins::new(Edges::from(vec![0, 1, 2, 3, 4]));
+		let grid = Grid::from(vec![x.clone(), y.clone(), z.clone()]);
+		let observations = array![
+			[0, 1, 2],
+			[1, 2, 3],
+			[1, 2, 3],
+			[1, 0, 0],
+			[0, 0, 3],
+			[0, 2, 2]
+		];
+		let histogram = observations.histogram(grid);
+		let slice = histogram.slice_at(2, 3).unwrap();
+		assert_eq!(slice.grid(), &Grid::from(vec![x.clone(), y]));
+		assert_eq!(slice.counts(), array![[1, 0, 0], [0, 0, 2]].into_dyn());
+		for (axis, n_bins) in [(0, 2), (1, 3), (2, 4)] {
+			for index in 0..n_bins {
+				let slice = histogram.slice_at(axis, index).unwrap();
+				assert_eq!(slice.ndim(), 2);
+				assert_eq!(
+					slice.counts(),
+					histogram.counts().index_axis(Axis(axis), index)
+				);
+			}
+		}
+		let slice = histogram.slice_at(1, 2).unwrap();
+		assert_eq!(slice.grid(), &Grid::from(vec![x, z]));
+		assert_eq!(
+			slice.counts(),
+			array![[0, 0, 1, 0], [0, 0, 0, 2]].into_dyn()
+		);
+	}
+
+	#[test]
+	fn slice_keeps_open_ends() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins.clone(), bins]).with_open_ends();
+		let histogram = Histogram::new(grid);
+		assert!(histogram.slice_at(0, 0).unwrap().grid().has_open_ends());
+	}
+
+	#[test]
+	fn slice_out_of_bounds_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		assert!(matches!(
+			histogram.slice_at(2, 0),
+			Err(SliceError::AxisOutOfBounds)
+		));
+		assert!(matches!(
+			histogram.slice_at(1, 2),
+			Err(SliceError::IndexOutOfBounds)
+		));
+	}
+
+	#[test]
+	fn transpose_2d_histogram() {
+		let x = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let y = Bins::new(Edges::from(vec![-1, 0, 1]));
+		let grid = Grid::from(vec![x.clone(), y.clone()]).with_open_ends();
+		let observations = array![[0, -1], [1, 0], [1, 0], [2, -1], [2, 0], [2, 0]];
+		let histogram = observations.histogram(grid);
+		let transposed = histogram.permute_axes(&[1, 0]).unwrap();
+		assert_eq!(transposed.grid(), &Grid::from(vec![y, x]).with_open_ends());
+		assert_eq!(transposed.counts(), histogram.counts().t());
+		assert_eq!(transposed.counts(), array![[1, 0, 1], [0, 2, 2]].into_dyn());
+		// Observations with swapped columns end up in the same bins.
+		let swapped = observations
+			.slice(s![.., ..;-1])
+			.histogram(transposed.grid().clone());
+		assert_eq!(swapped.counts(), transposed.counts());
+		let identity = transposed.permute_axes(&[1, 0]).unwrap();
+		assert!(identity.same_grid(&histogram));
+		assert_eq!(identity.counts(), histogram.counts());
+	}
+
+	#[test]
+	fn permute_3d_histogram() {
+		let bins = |n: i32| Bins::new(Edges::from((0..=n).collect::<Vec<_>>()));
+		let grid = Grid::from(vec![bins(2), bins(3), bins(4)]);
+		let observations = array![[0, 1, 2], [1, 2, 3], [1, 2, 3], [1, 0, 0], [0, 0, 3]];
+		let histogram = observations.histogram(grid);
+		let permuted = histogram.permute_axes(&[2, 0, 1]).unwrap();
+		assert_eq!(permuted.grid().shape(), vec![4, 2, 3]);
+		for (index, &count) in histogram.counts().indexed_iter() {
+			assert_eq!(permuted.counts()[[index[2], index[0], index[1]]], count);
+		}
+	}
+
+	#[test]
+	fn permute_with_invalid_axes_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+		assert!(matches!(
+			histogram.permute_axes(&[0]),
+			Err(PermuteError::DimensionMismatch)
+		));
+		assert!(matches!(
+			histogram.permute_axes(&[1, 1]),
+			Err(PermuteError::NotAPermutation)
+		));
+		assert!(matches!(
+			histogram.permute_axes(&[0, 2]),
+			Err(PermuteError::NotAPermutation)
+		));
+	}
+
+	#[test]
+	#[cfg(feature = "rand")]
+	fn samples_reproduce_histogram() {
+		use itertools::izip;
+		use rand::{rngs::StdRng, SeedableRng};
+
+		let x = Bins::new(Edges::from(vec![0, 2, 4, 6]));
+		let y = Bins::new(Edges::from(vec![-3, 0, 3]));
+		let grid = Grid::from(vec![x, y]);
+		let mut histogram = Histogram::new(grid.clone());
+		histogram
+			.counts_mut()
+			.assign(&array![[10, 40], [0, 25], [5, 20]].into_dyn());
+		let n = 10_000;
+		let samples = histogram.sample(&mut StdRng::seed_from_u64(42), n);
+		assert_eq!(samples.shape(), &[n, 2]);
+		// Flooring preserves the bins as their edges are integers.
+		#[allow(clippy::cast_possible_truncation)]
+		let samples = samples.mapv(|value| value.floor() as i32);
+		let resampled = samples.histogram(grid);
+		let total = histogram.counts().sum() as f64;
+		let chi_square = izip!(histogram.counts(), resampled.counts())
+			.filter(|&(&count, _)| count > 0)
+			.map(|(&count, &resampled)| {
+				let expected = count as f64 / total * n as f64;
+				(resampled as f64 - expected).powi(2) / expected
+			})
+			.sum::<f64>();
+		// 99.9th percentile of the chi-square distribution with 4 degrees of freedom.
+		assert!(chi_square < 18.47, "{chi_square}");
+		assert_eq!(resampled.counts()[[1, 0]], 0);
+	}
+
+	#[test]
+	#[cfg(feature = "rand")]
+	#[should_panic(expected = "Histogram is empty")]
+	fn sampling_empty_histogram_panics() {
+		use rand::{rngs::StdRng, SeedableRng};
+
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1]))]);
+		let _ = Histogram::new(grid).sample(&mut StdRng::seed_from_u64(42), 1);
+	}
+
+	#[test]
+	#[should_panic(expected = "out of bounds")]
+	fn project_onto_out_of_bounds_axis_panics() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1]))]);
+		let _ = Histogram::new(grid).project(1);
+	}
+
+	#[test]
+	#[should_panic(expected = "Too many bins")]
+	fn new_with_overflowing_shape_panics() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins; 70]);
+		let _ = Histogram::new(grid);
+	}
 }
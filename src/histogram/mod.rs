@@ -1,10 +1,17 @@
 //! Histogram functionalities.
-pub use self::bins::{Bins, Edges};
-pub use self::grid::{Grid, GridBuilder};
-pub use self::histograms::{Histogram, HistogramExt};
+pub use self::bins::{Bins, Edges, Side};
+pub use self::dynamic_histograms::DynamicHistogram;
+pub use self::grid::Grid;
+#[cfg(feature = "std")]
+pub use self::grid::{GridBuilder, SortedColumns};
+pub use self::histograms::{histogram2d, Histogram, HistogramExt};
+pub use self::weighted_histograms::WeightedHistogram;
 
 mod bins;
+mod dynamic_histograms;
 pub mod errors;
 mod grid;
 mod histograms;
+#[cfg(feature = "std")]
 pub mod strategies;
+mod weighted_histograms;
@@ -16,6 +16,8 @@
 //!
 //! - [`Auto`]: Maximum of the [`Sturges`] and [`FreedmanDiaconis`] strategies. Provides good all
 //!   around performance.
+//! - [`Best`]: Median choice among the [`Sqrt`], [`Rice`], [`Sturges`], [`FreedmanDiaconis`], and
+//!   Scott's rule strategies. Avoids the extremes of any single rule.
 //! - [`FreedmanDiaconis`]: Robust (resilient to outliers) strategy that takes into account data
 //!   variability and data size.
 //! - [`Rice`]: A strategy that does not take variability into account, only data size. Commonly
@@ -24,12 +26,18 @@
 //!   for its speed and simplicity.
 //! - [`Sturges`]: R’s default strategy, only accounts for data size. Only optimal for gaussian data
 //!   and underestimates number of bins for large non-gaussian datasets.
+//! - [`TerrellScott`]: Oversmoothed strategy, only accounts for data size. Provides an upper bound
+//!   of the number of bins reasonable for any smooth density.
+//! - [`ShimazakiShinomoto`]: Searches the number of bins minimizing an estimate of the mean
+//!   integrated squared error by binning the data at each candidate number of bins.
+//! - [`Wand`]: Plug-in strategy adapting the bin width to the curvature of the density via a
+//!   kernel estimate, assuming approximate normality only for its pilot estimate.
 //!
 //! # Notes
 //!
 //! In general, successful inference on optimal bin width and number of bins relies on
 //! **variability** of data. In other word, the provided observations should not be empty or
-//! constant.
+//! constant. Constant observations are reported as [`BinsBuildError::ConstantInput`].
 //!
 //! In addition, [`Auto`] and [`FreedmanDiaconis`] requires the [`interquartile range (IQR)`][iqr],
 //! i.e. the difference between upper and lower quartiles, to be positive.
@@ -37,18 +45,24 @@
 //! [`GridBuilder`]: ../struct.GridBuilder.html
 //! [`Bins`]: ../struct.Bins.html
 //! [`Grid`]: ../struct.Grid.html
+//! [`BinsBuildError::ConstantInput`]: ../errors/enum.BinsBuildError.html#variant.ConstantInput
 //! [`NumPy`]: https://docs.scipy.org/doc/numpy/reference/generated/numpy.histogram_bin_edges.html#numpy.histogram_bin_edges
 //! [`Auto`]: struct.Auto.html
+//! [`Best`]: struct.Best.html
 //! [`Sturges`]: struct.Sturges.html
 //! [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
 //! [`Rice`]: struct.Rice.html
 //! [`Sqrt`]: struct.Sqrt.html
+//! [`ShimazakiShinomoto`]: struct.ShimazakiShinomoto.html
+//! [`Wand`]: struct.Wand.html
+//! [`TerrellScott`]: struct.TerrellScott.html
 //! [iqr]: https://www.wikiwand.com/en/Interquartile_range
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 
 use crate::{
 	histogram::{errors::BinsBuildError, Bins, Edges},
-	quantile::{interpolate::Nearest, Quantile1dExt, QuantileExt},
+	n64,
+	quantile::{interpolate::Nearest, weighted_quantile, Quantile1dExt, QuantileExt},
 };
 use ndarray::{prelude::*, Data};
 use num_traits::{FromPrimitive, NumOps, ToPrimitive, Zero};
@@ -103,11 +117,163 @@ pub trait BinsBuildingStrategy {
 		S: Data<Elem = Self::Elem>,
 		Self: std::marker::Sized;
 
+	/// Returns a strategy that has learnt the required parameter for building [`Bins`] for given
+	/// 1-dimensional array of observations with given `weights`, or an `Err` if it is not possible
+	/// to infer the required parameter with the given data and specified strategy.
+	///
+	/// Calls [`Self::from_array_weighted_with_max`] with `max_n_bins` of [`u16::MAX`].
+	///
+	/// # Errors
+	///
+	/// See [`Self::from_array_weighted_with_max`].
+	///
+	/// [`Bins`]: ../struct.Bins.html
+	fn from_array_weighted<S, S2>(
+		array: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+		Self: std::marker::Sized,
+	{
+		Self::from_array_weighted_with_max(array, weights, u16::MAX.into())
+	}
+
+	/// Returns a strategy that has learnt the required parameter for building [`Bins`] for given
+	/// 1-dimensional array of observations with given `weights`, or an `Err` if it is not possible
+	/// to infer the required parameter with the given data and specified strategy.
+	///
+	/// Strategies only accounting for data size ([`Sqrt`], [`Rice`], [`Sturges`], and
+	/// [`TerrellScott`]) use the effective sample size `(sum w)^2 / sum w^2` instead of the number
+	/// of observations. [`FreedmanDiaconis`] additionally uses weighted quartiles and a weighted
+	/// standard deviation, and so does [`Auto`]. Other strategies ignore the weights by default,
+	/// i.e. calling [`Self::from_array_with_max`] after validating the weights.
+	///
+	/// # Errors
+	///
+	/// Returns [`BinsBuildError::InvalidWeights`] if the number of weights differs from the number
+	/// of observations or if the weights are negative, not finite, or sum up to zero. See
+	/// [`Self::from_array_with_max`] for other errors.
+	///
+	/// [`Bins`]: ../struct.Bins.html
+	fn from_array_weighted_with_max<S, S2>(
+		array: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+		Self: std::marker::Sized,
+	{
+		effective_sample_size(array.len(), weights)?;
+		Self::from_array_with_max(array, max_n_bins)
+	}
+
+	/// Returns a strategy that has learnt the required parameter for building [`Bins`] for given
+	/// 1-dimensional array and a `sorted` copy of it, or an `Err` if it is not possible to infer
+	/// the required parameter with the given data and specified strategy.
+	///
+	/// Calls [`Self::from_sorted_with_max`] with `max_n_bins` of [`u16::MAX`].
+	///
+	/// # Errors
+	///
+	/// See [`Self::from_sorted_with_max`].
+	///
+	/// [`Bins`]: ../struct.Bins.html
+	fn from_sorted<S, S2>(
+		array: &ArrayBase<S, Ix1>,
+		sorted: &ArrayBase<S2, Ix1>,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = Self::Elem>,
+		Self: std::marker::Sized,
+	{
+		Self::from_sorted_with_max(array, sorted, u16::MAX.into())
+	}
+
+	/// Returns a strategy that has learnt the required parameter for building [`Bins`] for given
+	/// 1-dimensional array and a `sorted` copy of it, or an `Err` if it is not possible to infer
+	/// the required parameter with the given data and specified strategy.
+	///
+	/// This is used by [`GridBuilder::from_sorted`] to share the sorted observations among
+	/// strategies. Strategies depending on quantiles ([`FreedmanDiaconis`] and [`Auto`]) look them
+	/// up in `sorted` instead of selecting them from a copy of `array`, other strategies call
+	/// [`Self::from_array_with_max`] by default. Either way, the result is the same as of
+	/// [`Self::from_array_with_max`].
+	///
+	/// # Errors
+	///
+	/// See [`Self::from_array_with_max`].
+	///
+	/// [`Bins`]: ../struct.Bins.html
+	/// [`GridBuilder::from_sorted`]: ../struct.GridBuilder.html#method.from_sorted
+	fn from_sorted_with_max<S, S2>(
+		array: &ArrayBase<S, Ix1>,
+		sorted: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = Self::Elem>,
+		Self: std::marker::Sized,
+	{
+		debug_assert_eq!(array.len(), sorted.len());
+		Self::from_array_with_max(array, max_n_bins)
+	}
+
 	/// Returns a [`Bins`] instance, according to parameters inferred from observations.
 	///
 	/// [`Bins`]: ../struct.Bins.html
 	fn build(&self) -> Bins<Self::Elem>;
 
+	/// Returns a [`Bins`] instance with the bin width inferred from observations but laid out
+	/// across the given range `[min, max]` instead of the inferred one, e.g. `[0, 360]` for angles.
+	///
+	/// The bins start at `min` and, as for inferred ranges, an extra bin is added when necessary to
+	/// include `max`.
+	///
+	/// # Errors
+	///
+	/// Returns [`BinsBuildError::Strategy`] if `min >= max` or if the range is narrower than the
+	/// inferred bin width.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{strategies::BinsBuildingStrategy, strategies::Sqrt};
+	///
+	/// let angles = array![80, 90, 95, 100, 110, 115, 120, 125, 130, 170];
+	/// let strategy = Sqrt::from_array(&angles).unwrap();
+	/// assert_eq!(strategy.bin_width(), 30);
+	/// let bins = strategy.build_with_range(0, 360).unwrap();
+	/// assert_eq!(bins.index(0), 0..30);
+	/// assert_eq!(bins.index(11), 330..360);
+	/// ```
+	///
+	/// [`Bins`]: ../struct.Bins.html
+	fn build_with_range(
+		&self,
+		min: Self::Elem,
+		max: Self::Elem,
+	) -> Result<Bins<Self::Elem>, BinsBuildError>
+	where
+		Self::Elem: Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+	{
+		if min >= max {
+			return Err(BinsBuildError::Strategy);
+		}
+		let bin = self.build().index(0);
+		let bin_width = bin.end - bin.start;
+		if max.clone() - min.clone() < bin_width {
+			return Err(BinsBuildError::Strategy);
+		}
+		Ok(EquiSpaced::new(bin_width, min, max)?.build())
+	}
+
 	/// Returns the optimal number of bins, according to parameters inferred from observations.
 	fn n_bins(&self) -> usize;
 }
@@ -240,14 +406,140 @@ pub struct Auto<T> {
 	builder: SturgesOrFD<T>,
 }
 
+/// Oversmoothed strategy, only accounts for data size. Provides an upper bound of the number of
+/// bins reasonable for any smooth density.
+///
+/// Let `n` be the number of observations and `n_bins` be the number of bins.
+///
+/// `n_bins` = ⌈(2`n`)<sup>1/3</sup>⌉
+///
+/// Terrell and Scott have shown that this is the maximum number of bins of a histogram minimizing
+/// the asymptotic mean integrated squared error for any density of a given variance. It is close
+/// to [`Rice`] but smaller by a factor of 2<sup>2/3</sup>, which makes it a reasonable upper bound
+/// when sweeping over numbers of bins.
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+///
+/// [`Rice`]: struct.Rice.html
+#[derive(Debug)]
+pub struct TerrellScott<T> {
+	builder: EquiSpaced<T>,
+}
+
+/// Strategy minimizing an estimate of the mean integrated squared error (MISE) of the histogram
+/// with respect to the unknown underlying density.
+///
+/// Let `k` be the counts of the equally wide bins of width `bin_width` spanning the data, `m`
+/// their mean and `v` their biased variance. Then the cost function
+///
+/// `cost` = (2`m` − `v`) / `bin_width`<sup>2</sup>
+///
+/// is minimized by binning the data at the bin width of each candidate number of bins from 1 up
+/// to the number of observations or `max_n_bins`, whichever is smaller. Each candidate is scored
+/// on the bins actually built, i.e. with its bin width in `T` and the extra bin for the maximum.
+/// Candidates whose bin width is zero in `T` or which exceed `max_n_bins` including the extra bin
+/// are skipped, and candidates whose bin width in `T` equals the previous one, e.g. truncated
+/// integer widths, are scored once.
+///
+/// As this is a search instead of a closed form, it requires sorting the observations once and
+/// O(min(`n`, `b` log `n`)) operations per candidate of `b` bins where `n` is the number of
+/// observations. Hence, instead of [`u16::MAX`], `max_n_bins` defaults to four times the number of
+/// bins of [`FreedmanDiaconis`], which grows with `n`<sup>1/3</sup>, or to `n` if the latter fails.
+/// Pass another upper bound via [`BinsBuildingStrategy::from_array_with_max`] for a finer or
+/// coarser search, e.g. the number of bins of [`Rice`].
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+///
+/// [`Rice`]: struct.Rice.html
+/// [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
+#[derive(Debug)]
+pub struct ShimazakiShinomoto<T> {
+	builder: EquiSpaced<T>,
+}
+
+/// Plug-in strategy estimating the bin width minimizing the asymptotic mean integrated squared
+/// error from a kernel estimate of the density's roughness.
+///
+/// Let `n` be the number of observations and `ψ₂` = ∫ `f''` `f` = −∫ `f'`<sup>2</sup> the negated
+/// roughness of the unknown density `f`. Then
+///
+/// `bin_width` = (6 / (−`ψ₂` × `n`))<sup>1/3</sup>
+///
+/// This is the one-stage rule of Wand, i.e. `level = 1` of `dpih` in R's `KernSmooth`, where `ψ₂`
+/// is estimated with a gaussian kernel whose pilot bandwidth is chosen by assuming approximate
+/// normality of the data with scale `σ`, the minimum of the standard deviation and the [`IQR`]
+/// divided by 1.349 (or the standard deviation if the [`IQR`] is zero). For normal data, the
+/// bin width approaches `3.49σn`<sup>−1/3</sup> of Scott's rule, whereas it adapts to the
+/// curvature of the density otherwise.
+///
+/// Like `dpih`, the kernel estimate is computed on a linear binning of the data onto a grid of 401
+/// equally spaced points spanning the data. Hence, besides sorting the data for the [`IQR`], it
+/// requires O(`n`) operations for binning and a constant number of operations for the kernel
+/// estimate. This is heavier than the closed-form strategies but scales to large inputs.
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+///
+/// [`IQR`]: https://en.wikipedia.org/wiki/Interquartile_range
+#[derive(Debug)]
+pub struct Wand<T> {
+	builder: EquiSpaced<T>,
+}
+
+/// Median choice among the [`Sqrt`], [`Rice`], [`Sturges`], [`FreedmanDiaconis`], and Scott's rule
+/// strategies. Avoids the extremes of any single rule.
+///
+/// Let `s` be the standard deviation and `n` the number of observations. Scott's rule is
+///
+/// `bin_width` = 3.49`s` × `n`<sup>−1/3</sup>
+///
+/// All five strategies are fitted to the data and the one whose number of bins is the median of
+/// the successfully fitted strategies is chosen, taking the lower median for an even number of
+/// them. [`Sqrt`] and [`Rice`] tend to overestimate the number of bins for large datasets, whereas
+/// [`Sturges`] underestimates it for large non-gaussian datasets. The median discards such
+/// extremes, while [`FreedmanDiaconis`] and Scott's rule usually agree for gaussian data.
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+///
+/// [`Sqrt`]: struct.Sqrt.html
+/// [`Rice`]: struct.Rice.html
+/// [`Sturges`]: struct.Sturges.html
+/// [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
+#[derive(Debug)]
+pub struct Best<T> {
+	builder: EquiSpaced<T>,
+}
+
 impl<T> EquiSpaced<T>
 where
 	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
 {
-	/// Returns `Err(BinsBuildError::Strategy)` if `bin_width<=0` or `min` >= `max`.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if `min == max`.
+	/// Returns `Err(BinsBuildError::Strategy)` if `bin_width<=0` or `min` > `max`.
 	/// Returns `Ok(Self)` otherwise.
 	fn new(bin_width: T, min: T, max: T) -> Result<Self, BinsBuildError> {
-		if (bin_width <= T::zero()) || (min >= max) {
+		if min == max {
+			Err(BinsBuildError::ConstantInput)
+		} else if (bin_width <= T::zero()) || (min > max) {
 			Err(BinsBuildError::Strategy)
 		} else {
 			Ok(Self {
@@ -286,7 +578,7 @@ where
 {
 	type Elem = T;
 
-	/// Returns `Err(BinsBuildError::Strategy)` if the array is constant.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
 	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
 	/// Returns `Ok(Self)` otherwise.
 	fn from_array_with_max<S>(
@@ -296,22 +588,28 @@ where
 	where
 		S: Data<Elem = Self::Elem>,
 	{
-		let n_elems = a.len();
 		// casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
 		// but it's not relevant here
 		#[allow(clippy::cast_precision_loss)]
-		// casting the rounded square root from `f64` to `usize` is safe
-		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-		let n_bins = (n_elems as f64).sqrt().round() as usize;
-		let min = a.min()?;
-		let max = a.max()?;
-		let bin_width = compute_bin_width(min.clone(), max.clone(), n_bins);
-		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
-		if builder.n_bins() > max_n_bins {
-			Err(BinsBuildError::Strategy)
-		} else {
-			Ok(Self { builder })
-		}
+		let n_elems = a.len() as f64;
+		Self::from_sample_size(a, n_elems, max_n_bins)
+	}
+
+	/// Returns `Err(BinsBuildError::InvalidWeights)` if the weights are invalid.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_weighted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+	{
+		let n_elems = effective_sample_size(a.len(), weights)?;
+		Self::from_sample_size(a, n_elems, max_n_bins)
 	}
 
 	fn build(&self) -> Bins<T> {
@@ -327,6 +625,28 @@ impl<T> Sqrt<T>
 where
 	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
 {
+	fn from_sample_size<S>(
+		a: &ArrayBase<S, Ix1>,
+		n_elems: f64,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = T>,
+	{
+		// casting the rounded square root from `f64` to `usize` is safe
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let n_bins = n_elems.sqrt().round() as usize;
+		let min = a.min()?;
+		let max = a.max()?;
+		let bin_width = compute_bin_width(min.clone(), max.clone(), n_bins);
+		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
+		if builder.n_bins() > max_n_bins {
+			Err(BinsBuildError::Strategy)
+		} else {
+			Ok(Self { builder })
+		}
+	}
+
 	/// The bin width (or bin length) according to the fitted strategy.
 	pub fn bin_width(&self) -> T {
 		self.builder.bin_width()
@@ -339,7 +659,7 @@ where
 {
 	type Elem = T;
 
-	/// Returns `Err(BinsBuildError::Strategy)` if the array is constant.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
 	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
 	/// Returns `Ok(Self)` otherwise.
 	fn from_array_with_max<S>(
@@ -349,22 +669,28 @@ where
 	where
 		S: Data<Elem = Self::Elem>,
 	{
-		let n_elems = a.len();
 		// casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
 		// but it's not relevant here
 		#[allow(clippy::cast_precision_loss)]
-		// casting the rounded cube root from `f64` to `usize` is safe
-		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-		let n_bins = (2. * (n_elems as f64).powf(1. / 3.)).round() as usize;
-		let min = a.min()?;
-		let max = a.max()?;
-		let bin_width = compute_bin_width(min.clone(), max.clone(), n_bins);
-		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
-		if builder.n_bins() > max_n_bins {
-			Err(BinsBuildError::Strategy)
-		} else {
-			Ok(Self { builder })
-		}
+		let n_elems = a.len() as f64;
+		Self::from_sample_size(a, n_elems, max_n_bins)
+	}
+
+	/// Returns `Err(BinsBuildError::InvalidWeights)` if the weights are invalid.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_weighted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+	{
+		let n_elems = effective_sample_size(a.len(), weights)?;
+		Self::from_sample_size(a, n_elems, max_n_bins)
 	}
 
 	fn build(&self) -> Bins<T> {
@@ -380,35 +706,17 @@ impl<T> Rice<T>
 where
 	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
 {
-	/// The bin width (or bin length) according to the fitted strategy.
-	pub fn bin_width(&self) -> T {
-		self.builder.bin_width()
-	}
-}
-
-impl<T> BinsBuildingStrategy for Sturges<T>
-where
-	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
-{
-	type Elem = T;
-
-	/// Returns `Err(BinsBuildError::Strategy)` if the array is constant.
-	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
-	/// Returns `Ok(Self)` otherwise.
-	fn from_array_with_max<S>(
+	fn from_sample_size<S>(
 		a: &ArrayBase<S, Ix1>,
+		n_elems: f64,
 		max_n_bins: usize,
 	) -> Result<Self, BinsBuildError>
 	where
-		S: Data<Elem = Self::Elem>,
+		S: Data<Elem = T>,
 	{
-		let n_elems = a.len();
-		// casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
-		// but it's not relevant here
-		#[allow(clippy::cast_precision_loss)]
-		// casting the rounded base-2 log from `f64` to `usize` is safe
+		// casting the rounded cube root from `f64` to `usize` is safe
 		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-		let n_bins = (n_elems as f64).log2().round() as usize + 1;
+		let n_bins = (2. * n_elems.powf(1. / 3.)).round() as usize;
 		let min = a.min()?;
 		let max = a.max()?;
 		let bin_width = compute_bin_width(min.clone(), max.clone(), n_bins);
@@ -420,53 +728,123 @@ where
 		}
 	}
 
-	fn build(&self) -> Bins<T> {
-		self.builder.build()
-	}
-
-	fn n_bins(&self) -> usize {
-		self.builder.n_bins()
-	}
-}
-
-impl<T> Sturges<T>
-where
-	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
-{
 	/// The bin width (or bin length) according to the fitted strategy.
 	pub fn bin_width(&self) -> T {
 		self.builder.bin_width()
 	}
 }
 
-impl<T> BinsBuildingStrategy for FreedmanDiaconis<T>
+impl<T> BinsBuildingStrategy for Sturges<T>
 where
 	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
 {
 	type Elem = T;
 
-	/// Returns `Err(BinsBuildError::Strategy)` if improper IQR and SD are close to zero.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
 	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
 	/// Returns `Ok(Self)` otherwise.
-	fn from_array<S>(a: &ArrayBase<S, Ix1>) -> Result<Self, BinsBuildError>
+	fn from_array_with_max<S>(
+		a: &ArrayBase<S, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
 	where
 		S: Data<Elem = Self::Elem>,
 	{
-		Self::from_array_with_max(a, u16::MAX.into())
+		// casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
+		// but it's not relevant here
+		#[allow(clippy::cast_precision_loss)]
+		let n_elems = a.len() as f64;
+		Self::from_sample_size(a, n_elems, max_n_bins)
 	}
 
-	/// Returns `Err(BinsBuildError::Strategy)` if improper IQR and SD are close to zero.
+	/// Returns `Err(BinsBuildError::InvalidWeights)` if the weights are invalid.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
 	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
 	/// Returns `Ok(Self)` otherwise.
-	fn from_array_with_max<S>(
+	fn from_array_weighted_with_max<S, S2>(
 		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
 		max_n_bins: usize,
 	) -> Result<Self, BinsBuildError>
 	where
 		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
 	{
-		let n_points = a.len();
-		if n_points == 0 {
+		let n_elems = effective_sample_size(a.len(), weights)?;
+		Self::from_sample_size(a, n_elems, max_n_bins)
+	}
+
+	fn build(&self) -> Bins<T> {
+		self.builder.build()
+	}
+
+	fn n_bins(&self) -> usize {
+		self.builder.n_bins()
+	}
+}
+
+impl<T> Sturges<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	fn from_sample_size<S>(
+		a: &ArrayBase<S, Ix1>,
+		n_elems: f64,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = T>,
+	{
+		// casting the rounded base-2 log from `f64` to `usize` is safe
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let n_bins = n_elems.log2().round() as usize + 1;
+		let min = a.min()?;
+		let max = a.max()?;
+		let bin_width = compute_bin_width(min.clone(), max.clone(), n_bins);
+		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
+		if builder.n_bins() > max_n_bins {
+			Err(BinsBuildError::Strategy)
+		} else {
+			Ok(Self { builder })
+		}
+	}
+
+	/// The bin width (or bin length) according to the fitted strategy.
+	pub fn bin_width(&self) -> T {
+		self.builder.bin_width()
+	}
+}
+
+impl<T> BinsBuildingStrategy for FreedmanDiaconis<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	type Elem = T;
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if improper IQR and SD are close to zero.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array<S>(a: &ArrayBase<S, Ix1>) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+	{
+		Self::from_array_with_max(a, u16::MAX.into())
+	}
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if improper IQR and SD are close to zero.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_with_max<S>(
+		a: &ArrayBase<S, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+	{
+		let n_points = a.len();
+		if n_points == 0 {
 			return Err(BinsBuildError::EmptyInput);
 		}
 
@@ -474,6 +852,102 @@ where
 		let min = a.min()?;
 		let max = a.max()?;
 		let mut a_copy = a.to_owned();
+		let quartiles = |at: f64| {
+			let qs = [at, 1. - at];
+			#[cfg(feature = "rayon")]
+			let quartiles = a_copy.par_quantiles_mut(&aview1(&qs), &Nearest).unwrap();
+			#[cfg(not(feature = "rayon"))]
+			let quartiles = a_copy.quantiles_mut(&aview1(&qs), &Nearest).unwrap();
+			quartiles[1].clone() - quartiles[0].clone()
+		};
+		if let Some(builder) = Self::from_iqr(min, max, n_cbrt, max_n_bins, quartiles)? {
+			return Ok(Self { builder });
+		}
+		Self::from_scott(a, min, max, n_cbrt, max_n_bins)
+	}
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if improper IQR and SD are close to zero.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_sorted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		sorted: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = Self::Elem>,
+	{
+		debug_assert_eq!(a.len(), sorted.len());
+		let (Some(min), Some(max)) = (sorted.first(), sorted.last()) else {
+			return Err(BinsBuildError::EmptyInput);
+		};
+		let n_cbrt = f64::from_usize(sorted.len()).unwrap().powf(1. / 3.);
+		let quartile = |q: f64| sorted.quantile_sorted(n64(q), &Nearest).unwrap();
+		let quartiles = |at: f64| quartile(1. - at) - quartile(at);
+		if let Some(builder) = Self::from_iqr(min, max, n_cbrt, max_n_bins, quartiles)? {
+			return Ok(Self { builder });
+		}
+		Self::from_scott(a, min, max, n_cbrt, max_n_bins)
+	}
+
+	/// Returns `Err(BinsBuildError::InvalidWeights)` if the weights are invalid.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if improper IQR and SD are close to zero.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_weighted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+	{
+		let n_points = effective_sample_size(a.len(), weights)?;
+		let n_cbrt = n_points.cbrt();
+		let min = a.min()?;
+		let max = a.max()?;
+		let total = weights.sum();
+		let mut pairs = a
+			.iter()
+			.cloned()
+			.zip(weights.iter().copied())
+			.collect::<Vec<_>>();
+		pairs.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+		let quartiles = |at: f64| {
+			weighted_quantile(&pairs, total, 1. - at) - weighted_quantile(&pairs, total, at)
+		};
+		if let Some(builder) = Self::from_iqr(min, max, n_cbrt, max_n_bins, quartiles)? {
+			return Ok(Self { builder });
+		}
+		Self::from_weighted_scott(a, weights, min, max, max_n_bins)
+	}
+
+	fn build(&self) -> Bins<T> {
+		self.builder.build()
+	}
+
+	fn n_bins(&self) -> usize {
+		self.builder.n_bins()
+	}
+}
+
+impl<T> FreedmanDiaconis<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	/// Returns the builder of the first (improper) IQR not exceeding `max_n_bins`, if any, where
+	/// `iqr` returns the IQR from `at` to `1 - at`.
+	fn from_iqr(
+		min: &T,
+		max: &T,
+		n_cbrt: f64,
+		max_n_bins: usize,
+		mut iqr: impl FnMut(f64) -> T,
+	) -> Result<Option<EquiSpaced<T>>, BinsBuildError> {
 		// As there is no one-fit-all epsilon to decide whether IQR is zero, translate it into
 		// number of bins and compare it against `max_n_bins`. More bins than `max_n_bins` is a hint
 		// for an IQR close to zero. If so, deviate from proper Freedman-Diaconis rule by widening
@@ -481,22 +955,38 @@ where
 		let mut at = 0.5;
 		while at >= 1. / 512. {
 			at *= 0.5;
-			let first_quartile = a_copy.quantile_mut(at, &Nearest).unwrap();
-			let third_quartile = a_copy.quantile_mut(1. - at, &Nearest).unwrap();
-			let iqr = third_quartile - first_quartile;
+			let iqr = iqr(at);
 			let denom = T::from_f64((1. - 2. * at) * n_cbrt).unwrap();
 			if denom == T::zero() {
 				continue;
 			}
-			let bin_width = iqr.clone() / denom;
+			let bin_width = iqr / denom;
 			let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
 			if builder.n_bins() > max_n_bins {
 				continue;
 			}
-			return Ok(Self { builder });
+			return Ok(Some(builder));
+		}
+		Ok(None)
+	}
+
+	/// Returns the builder according to Scott's rule as asymptotic resort before giving up if the
+	/// improper IQR is still close to zero, where `m` is the mean and `s` its SD.
+	fn from_scott<S>(
+		a: &ArrayBase<S, Ix1>,
+		min: &T,
+		max: &T,
+		n_cbrt: f64,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = T>,
+	{
+		// Also guards the unbiased SD against a single observation.
+		if min >= max {
+			return Err(BinsBuildError::ConstantInput);
 		}
-		// If the improper IQR is still close to zero, use Scott's rule as asymptotic resort before
-		// giving up where `m` is the mean and `s` its SD.
+		let n_points = a.len();
 		let m = a.iter().cloned().fold(T::zero(), |s, v| s + v) / T::from_usize(n_points).unwrap();
 		let s = a
 			.iter()
@@ -510,9 +1000,430 @@ where
 		let bin_width = T::from_f64(3.49 * s).unwrap() / T::from_f64(n_cbrt).unwrap();
 		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
 		if builder.n_bins() > max_n_bins {
-			return Err(BinsBuildError::Strategy);
+			return Err(BinsBuildError::Strategy);
+		}
+		Ok(Self { builder })
+	}
+
+	/// Returns the builder according to Scott's rule with the weighted mean `m` and its unbiased
+	/// weighted SD `s`.
+	fn from_weighted_scott<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		min: &T,
+		max: &T,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = T>,
+		S2: Data<Elem = f64>,
+	{
+		let n_points = effective_sample_size(a.len(), weights)?;
+		let total = weights.sum();
+		let pairs = || a.iter().zip(weights.iter());
+		let m = pairs().map(|(v, w)| w * v.to_f64().unwrap()).sum::<f64>() / total;
+		let s = pairs()
+			.map(|(v, w)| w * (v.to_f64().unwrap() - m).powi(2))
+			.sum::<f64>();
+		let s = (s / (total - total / n_points)).sqrt();
+		let bin_width = T::from_f64(3.49 * s / n_points.cbrt()).ok_or(BinsBuildError::Strategy)?;
+		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
+		if builder.n_bins() > max_n_bins {
+			return Err(BinsBuildError::Strategy);
+		}
+		Ok(Self { builder })
+	}
+
+	/// The bin width (or bin length) according to the fitted strategy.
+	pub fn bin_width(&self) -> T {
+		self.builder.bin_width()
+	}
+}
+
+impl<T> BinsBuildingStrategy for Auto<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	type Elem = T;
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if `IQR==0`.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_with_max<S>(
+		a: &ArrayBase<S, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+	{
+		let fd_builder = FreedmanDiaconis::from_array_with_max(a, max_n_bins);
+		let sturges_builder = Sturges::from_array_with_max(a, max_n_bins);
+		Self::from_builders(fd_builder, sturges_builder)
+	}
+
+	/// Returns `Err(BinsBuildError::InvalidWeights)` if the weights are invalid.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if `IQR==0`.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_weighted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+	{
+		let fd_builder = FreedmanDiaconis::from_array_weighted_with_max(a, weights, max_n_bins);
+		let sturges_builder = Sturges::from_array_weighted_with_max(a, weights, max_n_bins);
+		Self::from_builders(fd_builder, sturges_builder)
+	}
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if `IQR==0`.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_sorted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		sorted: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = Self::Elem>,
+	{
+		let fd_builder = FreedmanDiaconis::from_sorted_with_max(a, sorted, max_n_bins);
+		let sturges_builder = Sturges::from_sorted_with_max(a, sorted, max_n_bins);
+		Self::from_builders(fd_builder, sturges_builder)
+	}
+
+	fn build(&self) -> Bins<T> {
+		// Ugly
+		match &self.builder {
+			SturgesOrFD::FreedmanDiaconis(b) => b.build(),
+			SturgesOrFD::Sturges(b) => b.build(),
+		}
+	}
+
+	fn n_bins(&self) -> usize {
+		// Ugly
+		match &self.builder {
+			SturgesOrFD::FreedmanDiaconis(b) => b.n_bins(),
+			SturgesOrFD::Sturges(b) => b.n_bins(),
+		}
+	}
+}
+
+impl<T> Auto<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	fn from_builders(
+		fd_builder: Result<FreedmanDiaconis<T>, BinsBuildError>,
+		sturges_builder: Result<Sturges<T>, BinsBuildError>,
+	) -> Result<Self, BinsBuildError> {
+		match (fd_builder, sturges_builder) {
+			(Err(_), Ok(sturges_builder)) => {
+				let builder = SturgesOrFD::Sturges(sturges_builder);
+				Ok(Self { builder })
+			}
+			(Ok(fd_builder), Err(_)) => {
+				let builder = SturgesOrFD::FreedmanDiaconis(fd_builder);
+				Ok(Self { builder })
+			}
+			(Ok(fd_builder), Ok(sturges_builder)) => {
+				let builder = if fd_builder.bin_width() > sturges_builder.bin_width() {
+					SturgesOrFD::Sturges(sturges_builder)
+				} else {
+					SturgesOrFD::FreedmanDiaconis(fd_builder)
+				};
+				Ok(Self { builder })
+			}
+			(Err(err), Err(_)) => Err(err),
+		}
+	}
+
+	/// The bin width (or bin length) according to the fitted strategy.
+	pub fn bin_width(&self) -> T {
+		// Ugly
+		match &self.builder {
+			SturgesOrFD::FreedmanDiaconis(b) => b.bin_width(),
+			SturgesOrFD::Sturges(b) => b.bin_width(),
+		}
+	}
+}
+
+impl<T> BinsBuildingStrategy for TerrellScott<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	type Elem = T;
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_with_max<S>(
+		a: &ArrayBase<S, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+	{
+		// casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
+		// but it's not relevant here
+		#[allow(clippy::cast_precision_loss)]
+		let n_elems = a.len() as f64;
+		Self::from_sample_size(a, n_elems, max_n_bins)
+	}
+
+	/// Returns `Err(BinsBuildError::InvalidWeights)` if the weights are invalid.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_weighted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+	{
+		let n_elems = effective_sample_size(a.len(), weights)?;
+		Self::from_sample_size(a, n_elems, max_n_bins)
+	}
+
+	fn build(&self) -> Bins<T> {
+		self.builder.build()
+	}
+
+	fn n_bins(&self) -> usize {
+		self.builder.n_bins()
+	}
+}
+
+impl<T> TerrellScott<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	fn from_sample_size<S>(
+		a: &ArrayBase<S, Ix1>,
+		n_elems: f64,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = T>,
+	{
+		// casting the rounded up cube root from `f64` to `usize` is safe
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let n_bins = (2. * n_elems).cbrt().ceil() as usize;
+		let min = a.min()?;
+		let max = a.max()?;
+		let bin_width = compute_bin_width(min.clone(), max.clone(), n_bins);
+		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
+		if builder.n_bins() > max_n_bins {
+			Err(BinsBuildError::Strategy)
+		} else {
+			Ok(Self { builder })
+		}
+	}
+
+	/// The bin width (or bin length) according to the fitted strategy.
+	pub fn bin_width(&self) -> T {
+		self.builder.bin_width()
+	}
+}
+
+impl<T> BinsBuildingStrategy for ShimazakiShinomoto<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	type Elem = T;
+
+	/// Calls [`Self::from_array_with_max`] with `max_n_bins` of four times the number of bins of
+	/// [`FreedmanDiaconis`].
+	///
+	/// [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
+	fn from_array<S>(a: &ArrayBase<S, Ix1>) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+	{
+		let max_n_bins = default_max_n_bins(FreedmanDiaconis::from_array(a), a.len());
+		Self::from_array_with_max(a, max_n_bins)
+	}
+
+	/// Calls [`Self::from_array_weighted_with_max`] with `max_n_bins` of four times the number of
+	/// bins of [`FreedmanDiaconis`] ignoring the weights.
+	///
+	/// [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
+	fn from_array_weighted<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+	{
+		let max_n_bins = default_max_n_bins(FreedmanDiaconis::from_array(a), a.len());
+		Self::from_array_weighted_with_max(a, weights, max_n_bins)
+	}
+
+	/// Calls [`Self::from_sorted_with_max`] with `max_n_bins` of four times the number of bins of
+	/// [`FreedmanDiaconis`].
+	///
+	/// [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
+	fn from_sorted<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		sorted: &ArrayBase<S2, Ix1>,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = Self::Elem>,
+	{
+		let max_n_bins = default_max_n_bins(
+			FreedmanDiaconis::from_sorted_with_max(a, sorted, u16::MAX.into()),
+			a.len(),
+		);
+		Self::from_sorted_with_max(a, sorted, max_n_bins)
+	}
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_with_max<S>(
+		a: &ArrayBase<S, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+	{
+		let min = a.min()?;
+		let max = a.max()?;
+		if min >= max {
+			return Err(BinsBuildError::ConstantInput);
+		}
+		let min_f64 = min.to_f64().unwrap();
+		let mut values = a
+			.iter()
+			.map(|value| value.to_f64().unwrap())
+			.collect::<Vec<_>>();
+		values.sort_unstable_by(f64::total_cmp);
+		let mut counts = Vec::new();
+		let mut previous_bin_width = None;
+		let mut optimum: Option<(f64, EquiSpaced<T>)> = None;
+		for n_bins in 1..=a.len().min(max_n_bins) {
+			let bin_width = compute_bin_width(min.clone(), max.clone(), n_bins);
+			if previous_bin_width.as_ref() == Some(&bin_width) {
+				continue;
+			}
+			previous_bin_width = Some(bin_width.clone());
+			let Ok(builder) = EquiSpaced::new(bin_width, min.clone(), max.clone()) else {
+				continue;
+			};
+			let n_bins = builder.n_bins();
+			if n_bins > max_n_bins {
+				continue;
+			}
+			let width = builder.bin_width().to_f64().unwrap();
+			sorted_bin_counts(&values, min_f64, width, n_bins, &mut counts);
+			#[allow(clippy::cast_precision_loss)]
+			let (mean, var) = {
+				let n = n_bins as f64;
+				let mean = counts.iter().sum::<usize>() as f64 / n;
+				let var = counts
+					.iter()
+					.map(|&count| (count as f64 - mean).powi(2))
+					.sum::<f64>() / n;
+				(mean, var)
+			};
+			let cost = (2. * mean - var) / (width * width);
+			if optimum
+				.as_ref()
+				.map_or(true, |(optimum, _)| cost < *optimum)
+			{
+				optimum = Some((cost, builder));
+			}
+		}
+		let (_cost, builder) = optimum.ok_or(BinsBuildError::Strategy)?;
+		Ok(Self { builder })
+	}
+
+	fn build(&self) -> Bins<T> {
+		self.builder.build()
+	}
+
+	fn n_bins(&self) -> usize {
+		self.builder.n_bins()
+	}
+}
+
+impl<T> ShimazakiShinomoto<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	/// The bin width (or bin length) according to the fitted strategy.
+	pub fn bin_width(&self) -> T {
+		self.builder.bin_width()
+	}
+}
+
+impl<T> BinsBuildingStrategy for Wand<T>
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	type Elem = T;
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_with_max<S>(
+		a: &ArrayBase<S, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+	{
+		let min = a.min()?;
+		let max = a.max()?;
+		if min >= max {
+			return Err(BinsBuildError::ConstantInput);
+		}
+		let mut values = a.iter().map(|v| v.to_f64().unwrap()).collect::<Vec<_>>();
+		// casting `n: usize` to `f64` may cause off-by-one error here if `n` > 2 ^ 53,
+		// but it's not relevant here
+		#[allow(clippy::cast_precision_loss)]
+		let n = values.len() as f64;
+		let mean = values.iter().sum::<f64>() / n;
+		let sd = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.)).sqrt();
+		values.sort_unstable_by(f64::total_cmp);
+		let quartile = |q: f64| {
+			let position = (n - 1.) * q;
+			// casting the non-negative floored index from `f64` to `usize` is safe
+			#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+			let index = position.floor() as usize;
+			let lower = values[index];
+			values
+				.get(index + 1)
+				.map_or(lower, |&higher| lower + position.fract() * (higher - lower))
+		};
+		let iqr = quartile(0.75) - quartile(0.25);
+		let sigma = if iqr > 0. { sd.min(iqr / 1.349) } else { sd };
+		// Normal reference pilot bandwidth for estimating `ψ₂`.
+		let alpha = (2. / (3. * n)).powf(0.2) * std::f64::consts::SQRT_2 * sigma;
+		let psi2 = binned_psi2(&values, alpha) / (n * n);
+		if psi2.is_nan() || psi2 >= 0. {
+			return Err(BinsBuildError::Strategy);
+		}
+		let bin_width = (6. / (-psi2 * n)).cbrt();
+		let bin_width = T::from_f64(bin_width).ok_or(BinsBuildError::Strategy)?;
+		let builder = EquiSpaced::new(bin_width, min.clone(), max.clone())?;
+		if builder.n_bins() > max_n_bins {
+			Err(BinsBuildError::Strategy)
+		} else {
+			Ok(Self { builder })
 		}
-		Ok(Self { builder })
 	}
 
 	fn build(&self) -> Bins<T> {
@@ -524,7 +1435,7 @@ where
 	}
 }
 
-impl<T> FreedmanDiaconis<T>
+impl<T> Wand<T>
 where
 	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
 {
@@ -534,13 +1445,14 @@ where
 	}
 }
 
-impl<T> BinsBuildingStrategy for Auto<T>
+impl<T> BinsBuildingStrategy for Best<T>
 where
 	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
 {
 	type Elem = T;
 
-	/// Returns `Err(BinsBuildError::Strategy)` if `IQR==0`.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if all strategies fail.
 	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
 	/// Returns `Ok(Self)` otherwise.
 	fn from_array_with_max<S>(
@@ -550,60 +1462,224 @@ where
 	where
 		S: Data<Elem = Self::Elem>,
 	{
-		let fd_builder = FreedmanDiaconis::from_array_with_max(a, max_n_bins);
-		let sturges_builder = Sturges::from_array_with_max(a, max_n_bins);
-		match (fd_builder, sturges_builder) {
-			(Err(_), Ok(sturges_builder)) => {
-				let builder = SturgesOrFD::Sturges(sturges_builder);
-				Ok(Self { builder })
-			}
-			(Ok(fd_builder), Err(_)) => {
-				let builder = SturgesOrFD::FreedmanDiaconis(fd_builder);
-				Ok(Self { builder })
-			}
-			(Ok(fd_builder), Ok(sturges_builder)) => {
-				let builder = if fd_builder.bin_width() > sturges_builder.bin_width() {
-					SturgesOrFD::Sturges(sturges_builder)
-				} else {
-					SturgesOrFD::FreedmanDiaconis(fd_builder)
-				};
-				Ok(Self { builder })
+		let n_cbrt = f64::from_usize(a.len()).unwrap().cbrt();
+		let scott = FreedmanDiaconis::from_scott(a, a.min()?, a.max()?, n_cbrt, max_n_bins);
+		Self::from_builders([
+			Sqrt::from_array_with_max(a, max_n_bins).map(|b| b.builder),
+			Rice::from_array_with_max(a, max_n_bins).map(|b| b.builder),
+			Sturges::from_array_with_max(a, max_n_bins).map(|b| b.builder),
+			FreedmanDiaconis::from_array_with_max(a, max_n_bins).map(|b| b.builder),
+			scott.map(|b| b.builder),
+		])
+	}
+
+	/// Returns `Err(BinsBuildError::InvalidWeights)` if the weights are invalid.
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if all strategies fail.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_array_weighted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		weights: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = f64>,
+	{
+		effective_sample_size(a.len(), weights)?;
+		let scott =
+			FreedmanDiaconis::from_weighted_scott(a, weights, a.min()?, a.max()?, max_n_bins);
+		Self::from_builders([
+			Sqrt::from_array_weighted_with_max(a, weights, max_n_bins).map(|b| b.builder),
+			Rice::from_array_weighted_with_max(a, weights, max_n_bins).map(|b| b.builder),
+			Sturges::from_array_weighted_with_max(a, weights, max_n_bins).map(|b| b.builder),
+			FreedmanDiaconis::from_array_weighted_with_max(a, weights, max_n_bins)
+				.map(|b| b.builder),
+			scott.map(|b| b.builder),
+		])
+	}
+
+	/// Returns `Err(BinsBuildError::ConstantInput)` if the array is constant.
+	/// Returns `Err(BinsBuildError::Strategy)` if all strategies fail.
+	/// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+	/// Returns `Ok(Self)` otherwise.
+	fn from_sorted_with_max<S, S2>(
+		a: &ArrayBase<S, Ix1>,
+		sorted: &ArrayBase<S2, Ix1>,
+		max_n_bins: usize,
+	) -> Result<Self, BinsBuildError>
+	where
+		S: Data<Elem = Self::Elem>,
+		S2: Data<Elem = Self::Elem>,
+	{
+		let scott = match (sorted.first(), sorted.last()) {
+			(Some(min), Some(max)) => {
+				let n_cbrt = f64::from_usize(sorted.len()).unwrap().cbrt();
+				FreedmanDiaconis::from_scott(a, min, max, n_cbrt, max_n_bins)
 			}
-			(Err(err), Err(_)) => Err(err),
-		}
+			_ => Err(BinsBuildError::Strategy),
+		};
+		Self::from_builders([
+			Sqrt::from_sorted_with_max(a, sorted, max_n_bins).map(|b| b.builder),
+			Rice::from_sorted_with_max(a, sorted, max_n_bins).map(|b| b.builder),
+			Sturges::from_sorted_with_max(a, sorted, max_n_bins).map(|b| b.builder),
+			FreedmanDiaconis::from_sorted_with_max(a, sorted, max_n_bins).map(|b| b.builder),
+			scott.map(|b| b.builder),
+		])
 	}
 
 	fn build(&self) -> Bins<T> {
-		// Ugly
-		match &self.builder {
-			SturgesOrFD::FreedmanDiaconis(b) => b.build(),
-			SturgesOrFD::Sturges(b) => b.build(),
-		}
+		self.builder.build()
 	}
 
 	fn n_bins(&self) -> usize {
-		// Ugly
-		match &self.builder {
-			SturgesOrFD::FreedmanDiaconis(b) => b.n_bins(),
-			SturgesOrFD::Sturges(b) => b.n_bins(),
-		}
+		self.builder.n_bins()
 	}
 }
 
-impl<T> Auto<T>
+impl<T> Best<T>
 where
 	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
 {
-	/// The bin width (or bin length) according to the fitted strategy.
+	/// Chooses the builder with the (lower) median number of bins among the successful ones or
+	/// returns the error of the first failing one if none succeeded.
+	fn from_builders(
+		builders: [Result<EquiSpaced<T>, BinsBuildError>; 5],
+	) -> Result<Self, BinsBuildError> {
+		let mut first_err = None;
+		let mut fitted = Vec::with_capacity(builders.len());
+		for builder in builders {
+			match builder {
+				Ok(builder) => fitted.push(builder),
+				Err(err) => {
+					first_err.get_or_insert(err);
+				}
+			}
+		}
+		if fitted.is_empty() {
+			return Err(first_err.unwrap());
+		}
+		fitted.sort_by_key(EquiSpaced::n_bins);
+		let builder = fitted.swap_remove((fitted.len() - 1) / 2);
+		Ok(Self { builder })
+	}
+
+	/// The bin width (or bin length) according to the chosen strategy.
 	pub fn bin_width(&self) -> T {
-		// Ugly
-		match &self.builder {
-			SturgesOrFD::FreedmanDiaconis(b) => b.bin_width(),
-			SturgesOrFD::Sturges(b) => b.bin_width(),
+		self.builder.bin_width()
+	}
+}
+
+/// Returns the effective sample size `(sum w)^2 / sum w^2` of `n_elems` observations with given
+/// `weights`.
+///
+/// Returns `Err(BinsBuildError::InvalidWeights)` if `weights.len() != n_elems` or if the weights
+/// are negative, not finite, or sum up to zero.
+/// Returns `Err(BinsBuildError::EmptyInput)` if `n_elems == 0`.
+fn effective_sample_size<S>(
+	n_elems: usize,
+	weights: &ArrayBase<S, Ix1>,
+) -> Result<f64, BinsBuildError>
+where
+	S: Data<Elem = f64>,
+{
+	if weights.len() != n_elems {
+		return Err(BinsBuildError::InvalidWeights);
+	}
+	if n_elems == 0 {
+		return Err(BinsBuildError::EmptyInput);
+	}
+	if !weights.iter().all(|&w| w >= 0. && w.is_finite()) {
+		return Err(BinsBuildError::InvalidWeights);
+	}
+	let sum = weights.sum();
+	let sum_of_squares = weights.iter().map(|w| w * w).sum::<f64>();
+	let n_eff = sum * sum / sum_of_squares;
+	if n_eff.is_finite() {
+		Ok(n_eff)
+	} else {
+		Err(BinsBuildError::InvalidWeights)
+	}
+}
+
+/// Returns the default `max_n_bins` of [`ShimazakiShinomoto`], i.e. four times the number of bins
+/// of the [`FreedmanDiaconis`] strategy for the same observations, or their number `n` if it fails,
+/// but at most [`u16::MAX`].
+fn default_max_n_bins<T>(fd: Result<FreedmanDiaconis<T>, BinsBuildError>, n: usize) -> usize
+where
+	T: Ord + Send + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+	fd.map_or(n, |fd| fd.n_bins().saturating_mul(4))
+		.min(u16::MAX.into())
+}
+
+/// Counts the sorted `values` per bin of `width` starting at `min`, either by bisecting the edges
+/// or by a linear pass, whichever is cheaper.
+fn sorted_bin_counts(values: &[f64], min: f64, width: f64, n_bins: usize, counts: &mut Vec<usize>) {
+	// casting the non-negative floored bin index from `f64` to `usize` is safe
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let index_of = |value: f64| (((value - min) / width).floor() as usize).min(n_bins - 1);
+	counts.clear();
+	counts.resize(n_bins, 0);
+	let log_len = usize::BITS - values.len().leading_zeros();
+	if n_bins.saturating_mul(log_len as usize) < values.len() {
+		let mut start = 0;
+		for (index, count) in counts.iter_mut().enumerate() {
+			let end = start + values[start..].partition_point(|&value| index_of(value) <= index);
+			*count = end - start;
+			start = end;
+		}
+	} else {
+		for &value in values {
+			counts[index_of(value)] += 1;
 		}
 	}
 }
 
+/// Returns the unnormalized kernel estimate of `ψ₂` of the sorted `values` with a gaussian kernel of
+/// `bandwidth` on a linear binning of the values onto 401 grid points, as in `bkfe` of R's
+/// `KernSmooth`.
+// casting grid indices of at most 401 from `usize` to `f64` is lossless
+#[allow(clippy::cast_precision_loss)]
+fn binned_psi2(values: &[f64], bandwidth: f64) -> f64 {
+	const GRID_SIZE: usize = 401;
+	let min = values[0];
+	let delta = (values[values.len() - 1] - min) / (GRID_SIZE - 1) as f64;
+	let mut counts = [0_f64; GRID_SIZE];
+	for value in values {
+		let position = (value - min) / delta;
+		// casting the non-negative floored grid index from `f64` to `usize` is safe, the maximum
+		// is assigned to the last grid point
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let index = (position.floor() as usize).min(GRID_SIZE - 2);
+		let remainder = position - index as f64;
+		counts[index] += 1. - remainder;
+		counts[index + 1] += remainder;
+	}
+	// The kernel is truncated beyond 6 bandwidths where it is negligible.
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let width = ((6. * bandwidth / delta).floor() as usize).min(GRID_SIZE - 1);
+	let kernel = (0..=width)
+		.map(|lag| {
+			let z = lag as f64 * delta / bandwidth;
+			(z * z - 1.) * (-0.5 * z * z).exp()
+				/ (2. * std::f64::consts::PI).sqrt()
+				/ bandwidth.powi(3)
+		})
+		.collect::<Vec<_>>();
+	(0..GRID_SIZE)
+		.map(|i| {
+			let lower = i.saturating_sub(width);
+			let upper = (i + width).min(GRID_SIZE - 1);
+			counts[i]
+				* (lower..=upper)
+					.map(|j| kernel[i.abs_diff(j)] * counts[j])
+					.sum::<f64>()
+		})
+		.sum()
+}
+
 /// Returns the `bin_width`, given the two end points of a range (`max`, `min`), and the number of
 /// bins, consuming endpoints
 ///
@@ -642,7 +1718,7 @@ mod sqrt_tests {
 	fn constant_array_are_bad() {
 		assert!(Sqrt::from_array(&array![1, 1, 1, 1, 1, 1, 1])
 			.unwrap_err()
-			.is_strategy());
+			.is_constant_input());
 	}
 
 	#[test]
@@ -651,6 +1727,27 @@ mod sqrt_tests {
 			.unwrap_err()
 			.is_empty_input());
 	}
+
+	#[test]
+	fn build_with_range_keeps_bin_width() {
+		let strategy = Sqrt::from_array(&array![12, 14, 15, 17, 20, 21, 22, 24, 25]).unwrap();
+		assert_eq!(strategy.bin_width(), 4);
+		let bins = strategy.build_with_range(0, 40).unwrap();
+		assert_eq!(bins.len(), 11);
+		for i in 0..bins.len() {
+			assert_eq!(bins.index(i), 4 * i..4 * (i + 1));
+		}
+		assert_eq!(bins.range_of(&40), Some(40..44));
+	}
+
+	#[test]
+	fn build_with_range_rejects_narrow_ranges() {
+		let strategy = Sqrt::from_array(&array![12, 14, 15, 17, 20, 21, 22, 24, 25]).unwrap();
+		assert!(strategy.build_with_range(10, 14).is_ok());
+		assert!(strategy.build_with_range(10, 13).unwrap_err().is_strategy());
+		assert!(strategy.build_with_range(10, 10).unwrap_err().is_strategy());
+		assert!(strategy.build_with_range(10, 0).unwrap_err().is_strategy());
+	}
 }
 
 #[cfg(test)]
@@ -662,7 +1759,7 @@ mod rice_tests {
 	fn constant_array_are_bad() {
 		assert!(Rice::from_array(&array![1, 1, 1, 1, 1, 1, 1])
 			.unwrap_err()
-			.is_strategy());
+			.is_constant_input());
 	}
 
 	#[test]
@@ -682,7 +1779,7 @@ mod sturges_tests {
 	fn constant_array_are_bad() {
 		assert!(Sturges::from_array(&array![1, 1, 1, 1, 1, 1, 1])
 			.unwrap_err()
-			.is_strategy());
+			.is_constant_input());
 	}
 
 	#[test]
@@ -693,6 +1790,37 @@ mod sturges_tests {
 	}
 }
 
+#[cfg(test)]
+mod terrell_scott_tests {
+	use super::{BinsBuildingStrategy, TerrellScott};
+	use crate::o64;
+	use ndarray::{array, Array1};
+
+	#[test]
+	fn n_bins_is_rounded_up_cube_root_of_twice_the_size() {
+		for (n_elems, n_bins) in [(4, 2), (32, 4), (100, 6), (500, 10)] {
+			let a = Array1::from_iter((0..n_elems).map(f64::from).map(o64));
+			let strategy = TerrellScott::from_array(&a).unwrap();
+			let range = f64::from(n_elems - 1);
+			assert_eq!(strategy.bin_width(), o64(range) / o64(n_bins.into()));
+		}
+	}
+
+	#[test]
+	fn constant_array_are_bad() {
+		assert!(TerrellScott::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+			.unwrap_err()
+			.is_constant_input());
+	}
+
+	#[test]
+	fn empty_arrays_are_bad() {
+		assert!(TerrellScott::<usize>::from_array(&array![])
+			.unwrap_err()
+			.is_empty_input());
+	}
+}
+
 #[cfg(test)]
 mod fd_tests {
 	use super::{BinsBuildingStrategy, FreedmanDiaconis};
@@ -702,7 +1830,14 @@ mod fd_tests {
 	fn constant_array_are_bad() {
 		assert!(FreedmanDiaconis::from_array(&array![1, 1, 1, 1, 1, 1, 1])
 			.unwrap_err()
-			.is_strategy());
+			.is_constant_input());
+	}
+
+	#[test]
+	fn single_element_arrays_are_bad() {
+		assert!(FreedmanDiaconis::from_array(&array![1])
+			.unwrap_err()
+			.is_constant_input());
 	}
 
 	#[test]
@@ -731,7 +1866,14 @@ mod auto_tests {
 	fn constant_array_are_bad() {
 		assert!(Auto::from_array(&array![1, 1, 1, 1, 1, 1, 1])
 			.unwrap_err()
-			.is_strategy());
+			.is_constant_input());
+	}
+
+	#[test]
+	fn single_element_arrays_are_bad() {
+		assert!(Auto::from_array(&array![1])
+			.unwrap_err()
+			.is_constant_input());
 	}
 
 	#[test]
@@ -746,3 +1888,366 @@ mod auto_tests {
 			.is_empty_input());
 	}
 }
+
+#[cfg(test)]
+mod shimazaki_shinomoto_tests {
+	use super::{sorted_bin_counts, BinsBuildingStrategy, FreedmanDiaconis, ShimazakiShinomoto};
+	use crate::o64;
+	use ndarray::{array, Array1};
+
+	#[test]
+	fn uniform_data_is_a_single_bin() {
+		let a = Array1::from_iter((0..1000).map(f64::from).map(o64));
+		let strategy = ShimazakiShinomoto::from_array(&a).unwrap();
+		assert_eq!(strategy.bin_width(), o64(999.));
+		let a = Array1::from_iter(0..1000);
+		let strategy = ShimazakiShinomoto::from_array(&a).unwrap();
+		assert_eq!(strategy.bin_width(), 999);
+	}
+
+	#[test]
+	fn truncated_integer_widths_are_scored_as_built() {
+		let a = Array1::from_iter((0..100).map(|i| if i < 50 { 0 } else { 10 }));
+		let strategy = ShimazakiShinomoto::from_array(&a).unwrap();
+		// Widths below 1 truncate to zero, the finest built width separates both spikes.
+		assert_eq!(strategy.bin_width(), 1);
+		assert_eq!(strategy.n_bins(), 11);
+		let bins = strategy.build();
+		assert_eq!(bins.index(0), 0..1);
+		assert_eq!(bins.index(10), 10..11);
+	}
+
+	#[test]
+	fn bisected_and_linear_counts_agree() {
+		let values = (0..1000)
+			.map(|i| f64::from((i * 7919) % 1009) / 10.)
+			.collect::<Vec<_>>();
+		let mut sorted = values.clone();
+		sorted.sort_unstable_by(f64::total_cmp);
+		let (mut bisected, mut linear) = (Vec::new(), Vec::new());
+		for n_bins in [1_u8, 2, 7, 50] {
+			let width = 100.8 / f64::from(n_bins);
+			let n_bins = usize::from(n_bins) + 1;
+			assert!(n_bins * 10 < sorted.len(), "Bisects the edges");
+			sorted_bin_counts(&sorted, 0., width, n_bins, &mut bisected);
+			linear.clear();
+			linear.resize(n_bins, 0);
+			for &value in &values {
+				#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+				let index = (value / width).floor() as usize;
+				linear[index.min(n_bins - 1)] += 1;
+			}
+			assert_eq!(bisected, linear);
+			assert_eq!(bisected.iter().sum::<usize>(), sorted.len());
+		}
+	}
+
+	#[test]
+	fn separated_spikes_are_finely_binned() {
+		let a = Array1::from_iter((0..100).map(|i| o64(if i < 50 { 0. } else { 10. })));
+		let strategy = ShimazakiShinomoto::from_array_with_max(&a, 101).unwrap();
+		assert_eq!(strategy.bin_width(), o64(10.) / o64(100.));
+		let strategy = ShimazakiShinomoto::from_array_with_max(&a, 21).unwrap();
+		assert_eq!(strategy.bin_width(), o64(10.) / o64(20.));
+		// Four times the 3 bins of Freedman-Diaconis including the extra bin.
+		let strategy = ShimazakiShinomoto::from_array(&a).unwrap();
+		assert_eq!(strategy.bin_width(), o64(10.) / o64(11.));
+		assert_eq!(strategy.n_bins(), 12);
+	}
+
+	#[test]
+	fn default_search_is_bounded_by_the_data() {
+		let a = Array1::from_iter((0..100_000).map(|i| o64(f64::from((i * 7919) % 100_003))));
+		let fd = FreedmanDiaconis::from_array(&a).unwrap();
+		let strategy = ShimazakiShinomoto::from_array(&a).unwrap();
+		assert!(strategy.n_bins() <= 4 * fd.n_bins());
+		let mut sorted = a.clone();
+		sorted.as_slice_mut().unwrap().sort_unstable();
+		let from_sorted = ShimazakiShinomoto::from_sorted(&a, &sorted).unwrap();
+		assert_eq!(from_sorted.bin_width(), strategy.bin_width());
+	}
+
+	#[test]
+	fn constant_array_are_bad() {
+		assert!(ShimazakiShinomoto::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+			.unwrap_err()
+			.is_constant_input());
+	}
+
+	#[test]
+	fn empty_arrays_are_bad() {
+		assert!(ShimazakiShinomoto::<usize>::from_array(&array![])
+			.unwrap_err()
+			.is_empty_input());
+	}
+}
+
+#[cfg(test)]
+mod wand_tests {
+	use super::{BinsBuildingStrategy, Wand};
+	use crate::o64;
+	use ndarray::{array, Array1};
+
+	#[test]
+	fn gaussian_sample_matches_reference() {
+		// Gaussian sample with mean 10 and SD 2.
+		let data = array![
+			9.49, 11.02, 9.55, 9.37, 8.14, 9.57, 12.22, 10.85, 12.07, 10.5, 10.79, 10.37, 6.67,
+			11.71, 11.01, 11.0, 6.62, 6.51, 8.22, 9.06, 10.61, 9.91, 11.04, 8.72, 10.62, 10.79,
+			8.68, 13.44, 11.11, 12.39, 8.76, 8.52, 9.31, 9.79, 11.26, 10.5, 9.11, 8.09, 8.96,
+			12.44, 8.38, 10.49, 10.85, 7.02, 10.1, 12.61, 5.97, 9.36, 9.79, 8.37, 10.99, 9.88,
+			7.07, 11.66, 11.34, 11.89, 12.88, 10.72, 10.24, 7.4
+		];
+		let a = data.mapv(o64);
+		let strategy = Wand::from_array(&a).unwrap();
+		// Independent reference of the exact (unbinned) one-stage plug-in rule whose kernel
+		// estimate of `ψ₂` is the double sum over all pairs of observations.
+		let n = 60_f64;
+		// The IQR of 11.0125 − 8.75 divided by 1.349 is below the standard deviation.
+		let sigma = 2.2625 / 1.349;
+		assert!(sigma < data.std(1.));
+		let alpha = (2. / (3. * n)).powf(0.2) * std::f64::consts::SQRT_2 * sigma;
+		let psi2 = data
+			.iter()
+			.flat_map(|xi| data.iter().map(move |xj| (xi - xj) / alpha))
+			.map(|z| (z * z - 1.) * (-0.5 * z * z).exp() / (2. * std::f64::consts::PI).sqrt())
+			.sum::<f64>()
+			/ (n * n * alpha.powi(3));
+		let exact = (6. / (-psi2 * n)).cbrt();
+		// The binned estimate deviates from the exact one by less than 0.1 %.
+		assert!((strategy.bin_width().into_inner() / exact - 1.).abs() < 1e-3);
+		// Close to Scott's rule for normal data.
+		let sd = data.std(1.);
+		let scott = 3.49 * sd / 60_f64.cbrt();
+		assert!((strategy.bin_width().into_inner() / scott - 1.).abs() < 0.05);
+	}
+
+	#[test]
+	fn bin_width_is_scale_equivariant() {
+		let a = Array1::from_iter((0..100).map(|i| o64(f64::from(i * i % 37))));
+		let b = a.mapv(|v| v * 4.);
+		let a = Wand::from_array(&a).unwrap().bin_width();
+		let b = Wand::from_array(&b).unwrap().bin_width();
+		assert!((b / a - 4.).abs() < 1e-12);
+	}
+
+	#[test]
+	fn constant_array_are_bad() {
+		assert!(Wand::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+			.unwrap_err()
+			.is_constant_input());
+	}
+
+	#[test]
+	fn empty_arrays_are_bad() {
+		assert!(Wand::<usize>::from_array(&array![])
+			.unwrap_err()
+			.is_empty_input());
+	}
+}
+
+#[cfg(test)]
+mod best_tests {
+	use super::{Best, BinsBuildingStrategy, FreedmanDiaconis, Rice, Sqrt, Sturges};
+	use crate::{o64, QuantileExt, O64};
+	use ndarray::{array, Array1};
+
+	/// Returns the sorted numbers of bins of all strategies fitted by `Best`.
+	fn candidates(a: &Array1<O64>) -> Vec<usize> {
+		let n_cbrt = f64::from(u32::try_from(a.len()).unwrap()).cbrt();
+		let mut n_bins = vec![
+			Sqrt::from_array(a).unwrap().n_bins(),
+			Rice::from_array(a).unwrap().n_bins(),
+			Sturges::from_array(a).unwrap().n_bins(),
+			FreedmanDiaconis::from_array(a).unwrap().n_bins(),
+			FreedmanDiaconis::from_scott(a, a.min().unwrap(), a.max().unwrap(), n_cbrt, 1 << 16)
+				.unwrap()
+				.n_bins(),
+		];
+		n_bins.sort_unstable();
+		n_bins
+	}
+
+	#[test]
+	fn gaussian_sample_chooses_median() {
+		let a = array![
+			9.49, 11.02, 9.55, 9.37, 8.14, 9.57, 12.22, 10.85, 12.07, 10.5, 10.79, 10.37, 6.67,
+			11.71, 11.01, 11.0, 6.62, 6.51, 8.22, 9.06, 10.61, 9.91, 11.04, 8.72, 10.62, 10.79,
+			8.68, 13.44, 11.11, 12.39, 8.76, 8.52, 9.31, 9.79, 11.26, 10.5, 9.11, 8.09, 8.96,
+			12.44, 8.38, 10.49, 10.85, 7.02, 10.1, 12.61, 5.97, 9.36, 9.79, 8.37, 10.99, 9.88,
+			7.07, 11.66, 11.34, 11.89, 12.88, 10.72, 10.24, 7.4
+		]
+		.mapv(o64);
+		let n_bins = candidates(&a);
+		let strategy = Best::from_array(&a).unwrap();
+		assert_eq!(strategy.n_bins(), n_bins[2]);
+		assert_eq!(strategy.build().len(), n_bins[2]);
+	}
+
+	#[test]
+	fn skewed_sample_avoids_extremes() {
+		// Quantiles of the standard exponential distribution.
+		let a = Array1::from_iter((0..500).map(|i| o64(-(1. - (f64::from(i) + 0.5) / 500.).ln())));
+		let n_bins = candidates(&a);
+		let strategy = Best::from_array(&a).unwrap();
+		assert_eq!(strategy.n_bins(), n_bins[2]);
+		assert!(n_bins[0] < strategy.n_bins() && strategy.n_bins() < n_bins[4]);
+		assert_eq!(Sturges::from_array(&a).unwrap().n_bins(), n_bins[0]);
+	}
+
+	#[test]
+	fn tiny_sample_chooses_median() {
+		let a = array![1., 2., 4.].mapv(o64);
+		let n_bins = candidates(&a);
+		let strategy = Best::from_array(&a).unwrap();
+		assert_eq!(strategy.n_bins(), n_bins[2]);
+	}
+
+	#[test]
+	fn from_sorted_matches_from_array() {
+		let a = Array1::from_iter((0..100).map(|i| o64(f64::from(i * i % 37))));
+		let mut sorted = a.clone();
+		sorted.as_slice_mut().unwrap().sort_unstable();
+		assert_eq!(
+			Best::from_sorted_with_max(&a, &sorted, u16::MAX.into())
+				.unwrap()
+				.bin_width(),
+			Best::from_array(&a).unwrap().bin_width()
+		);
+	}
+
+	#[test]
+	fn constant_array_are_bad() {
+		assert!(Best::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+			.unwrap_err()
+			.is_constant_input());
+	}
+
+	#[test]
+	fn single_element_arrays_are_bad() {
+		assert!(Best::from_array(&array![1])
+			.unwrap_err()
+			.is_constant_input());
+	}
+
+	#[test]
+	fn empty_arrays_are_bad() {
+		assert!(Best::<usize>::from_array(&array![])
+			.unwrap_err()
+			.is_empty_input());
+	}
+}
+
+#[cfg(test)]
+mod weighted_tests {
+	use super::{
+		Auto, Best, BinsBuildingStrategy, FreedmanDiaconis, Rice, ShimazakiShinomoto, Sqrt,
+		Sturges, TerrellScott,
+	};
+	use crate::{o64, O64};
+	use ndarray::{array, Array1};
+
+	fn data() -> Array1<O64> {
+		Array1::from_iter((0..100).map(|i| o64(f64::from(i * i % 37))))
+	}
+
+	#[test]
+	fn unit_weights_match_unweighted() {
+		let a = data();
+		let w = Array1::ones(a.len());
+		assert_eq!(
+			Sqrt::from_array_weighted(&a, &w).unwrap().bin_width(),
+			Sqrt::from_array(&a).unwrap().bin_width()
+		);
+		assert_eq!(
+			Rice::from_array_weighted(&a, &w).unwrap().bin_width(),
+			Rice::from_array(&a).unwrap().bin_width()
+		);
+		assert_eq!(
+			Sturges::from_array_weighted(&a, &w).unwrap().bin_width(),
+			Sturges::from_array(&a).unwrap().bin_width()
+		);
+		assert_eq!(
+			TerrellScott::from_array_weighted(&a, &w)
+				.unwrap()
+				.bin_width(),
+			TerrellScott::from_array(&a).unwrap().bin_width()
+		);
+		assert_eq!(
+			ShimazakiShinomoto::from_array_weighted(&a, &w)
+				.unwrap()
+				.bin_width(),
+			ShimazakiShinomoto::from_array(&a).unwrap().bin_width()
+		);
+		assert_eq!(
+			Best::from_array_weighted(&a, &w).unwrap().n_bins(),
+			Best::from_array(&a).unwrap().n_bins()
+		);
+	}
+
+	#[test]
+	fn scaled_weights_match_unscaled() {
+		let a = data();
+		let w = Array1::from_iter((0..a.len()).map(|i| f64::from(u32::try_from(i % 3).unwrap())));
+		let v = &w * 8.;
+		assert_eq!(
+			FreedmanDiaconis::from_array_weighted(&a, &w)
+				.unwrap()
+				.bin_width(),
+			FreedmanDiaconis::from_array_weighted(&a, &v)
+				.unwrap()
+				.bin_width()
+		);
+		assert_eq!(
+			Auto::from_array_weighted(&a, &w).unwrap().bin_width(),
+			Auto::from_array_weighted(&a, &v).unwrap().bin_width()
+		);
+	}
+
+	#[test]
+	fn concentrated_weights_widen_bins() {
+		let a = data();
+		let w = Array1::from_iter((0..a.len()).map(|i| if i < 10 { 1. } else { 1e-3 }));
+		assert!(
+			Sturges::from_array_weighted(&a, &w).unwrap().bin_width()
+				> Sturges::from_array(&a).unwrap().bin_width()
+		);
+	}
+
+	#[test]
+	fn weighted_quartiles_narrow_bins() {
+		let a = Array1::from_iter((0..100).map(|i| o64(f64::from(i))));
+		let w = Array1::from_iter((0..100).map(|i| if i < 50 { 1. } else { 0. }));
+		let weighted = FreedmanDiaconis::from_array_weighted(&a, &w).unwrap();
+		let unweighted = FreedmanDiaconis::from_array(&a).unwrap();
+		assert!(weighted.bin_width() < unweighted.bin_width());
+	}
+
+	#[test]
+	fn invalid_weights_are_bad() {
+		let a = array![1, 2, 3];
+		assert!(Sqrt::from_array_weighted(&a, &array![1., 1.])
+			.unwrap_err()
+			.is_invalid_weights());
+		assert!(Sqrt::from_array_weighted(&a, &array![1., -1., 1.])
+			.unwrap_err()
+			.is_invalid_weights());
+		assert!(
+			FreedmanDiaconis::from_array_weighted(&a, &array![0., 0., 0.])
+				.unwrap_err()
+				.is_invalid_weights()
+		);
+		assert!(
+			ShimazakiShinomoto::from_array_weighted(&a, &array![1., f64::NAN, 1.])
+				.unwrap_err()
+				.is_invalid_weights()
+		);
+	}
+
+	#[test]
+	fn empty_arrays_are_bad() {
+		assert!(Auto::<usize>::from_array_weighted(&array![], &array![])
+			.unwrap_err()
+			.is_empty_input());
+	}
+}
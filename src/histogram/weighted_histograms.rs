@@ -0,0 +1,207 @@
+use super::errors::{BinNotFound, GridError};
+use super::grid::Grid;
+use super::histograms::{bin_index, try_zeros};
+use ndarray::prelude::*;
+use ndarray::Data;
+
+/// Weighted histogram data structure.
+///
+/// Each observation adds its weight to the count of the bin containing it. Optionally, the sum of
+/// the squared weights is accumulated per bin in order to estimate the statistical uncertainty of
+/// the weighted counts, see [`with_sumw2`] and [`errors`].
+///
+/// [`with_sumw2`]: #method.with_sumw2
+/// [`errors`]: #method.errors
+pub struct WeightedHistogram<A: Ord + Send> {
+	counts: ArrayD<f64>,
+	sumw2: Option<ArrayD<f64>>,
+	grid: Grid<A>,
+}
+
+impl<A: Ord + Send> WeightedHistogram<A> {
+	/// Returns a new instance of WeightedHistogram given a [`Grid`] without tracking the sum of
+	/// the squared weights.
+	///
+	/// **Panics** if the counts cannot be allocated, see [`Self::try_new`].
+	///
+	/// [`Grid`]: struct.Grid.html
+	#[must_use]
+	pub fn new(grid: Grid<A>) -> Self {
+		Self::try_new(grid).expect("Too many bins")
+	}
+
+	/// Returns a new instance of WeightedHistogram given a [`Grid`] without tracking the sum of
+	/// the squared weights.
+	///
+	/// Returns `Err(GridError::TooManyBins)` if the product of the numbers of bins along each axis
+	/// overflows `usize` or if the counts would exceed `isize::MAX` bytes.
+	///
+	/// [`Grid`]: struct.Grid.html
+	pub fn try_new(grid: Grid<A>) -> Result<Self, GridError> {
+		let counts = try_zeros(&grid)?;
+		Ok(WeightedHistogram {
+			counts,
+			sumw2: None,
+			grid,
+		})
+	}
+
+	/// Returns a new instance of WeightedHistogram given a [`Grid`] tracking the sum of the
+	/// squared weights per bin, like ROOT's `TH1::Sumw2`.
+	///
+	/// **Panics** if the counts cannot be allocated, see [`Self::try_with_sumw2`].
+	///
+	/// [`Grid`]: struct.Grid.html
+	pub fn with_sumw2(grid: Grid<A>) -> Self {
+		Self::try_with_sumw2(grid).expect("Too many bins")
+	}
+
+	/// Returns a new instance of WeightedHistogram given a [`Grid`] tracking the sum of the
+	/// squared weights per bin, like ROOT's `TH1::Sumw2`.
+	///
+	/// Returns `Err(GridError::TooManyBins)` if the product of the numbers of bins along each axis
+	/// overflows `usize` or if the counts would exceed `isize::MAX` bytes.
+	///
+	/// [`Grid`]: struct.Grid.html
+	pub fn try_with_sumw2(grid: Grid<A>) -> Result<Self, GridError> {
+		let mut histogram = Self::try_new(grid)?;
+		histogram.sumw2 = Some(try_zeros(&histogram.grid)?);
+		Ok(histogram)
+	}
+
+	/// Adds a single observation of a given `weight` to the histogram.
+	///
+	/// **Panics** if dimensions do not match: `self.ndim() != observation.len()`.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, WeightedHistogram};
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let mut histogram = WeightedHistogram::new(grid);
+	/// histogram.add_observation(&array![1], 0.5)?;
+	/// histogram.add_observation(&array![1], 2.)?;
+	/// assert!(histogram.add_observation(&array![2], 1.).is_err());
+	/// assert_eq!(histogram.counts(), array![0., 2.5].into_dyn());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	pub fn add_observation<S>(
+		&mut self,
+		observation: &ArrayBase<S, Ix1>,
+		weight: f64,
+	) -> Result<(), BinNotFound>
+	where
+		S: Data<Elem = A>,
+	{
+		let bin_index = bin_index::<A, S, IxDyn>(&self.grid, observation)?;
+		if let Some(sumw2) = &mut self.sumw2 {
+			sumw2[&bin_index] += weight * weight;
+		}
+		self.counts[bin_index] += weight;
+		Ok(())
+	}
+
+	/// Returns the number of dimensions of the space the histogram is covering.
+	pub fn ndim(&self) -> usize {
+		debug_assert_eq!(self.counts.ndim(), self.grid.ndim());
+		self.counts.ndim()
+	}
+
+	/// Borrows a view on the weighted counts, i.e. the sum of the weights per bin.
+	pub fn counts(&self) -> ArrayViewD<'_, f64> {
+		self.counts.view()
+	}
+
+	/// Borrows a view on the sum of the squared weights per bin, or `None` if it is not tracked.
+	pub fn sumw2(&self) -> Option<ArrayViewD<'_, f64>> {
+		self.sumw2.as_ref().map(ArrayD::view)
+	}
+
+	/// Returns the statistical uncertainty of the weighted counts, i.e. the square root of the sum
+	/// of the squared weights per bin.
+	///
+	/// Without tracking the sum of the squared weights, the square root of the weighted counts is
+	/// returned instead, i.e. the Poisson uncertainty which is only correct for unit weights.
+	///
+	/// # Example:
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::histogram::{Bins, Edges, Grid, WeightedHistogram};
+	///
+	/// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+	/// let mut histogram = WeightedHistogram::with_sumw2(grid);
+	/// histogram.add_observation(&array![0], 3.)?;
+	/// histogram.add_observation(&array![0], 4.)?;
+	/// assert_eq!(histogram.errors(), array![5., 0.].into_dyn());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn errors(&self) -> ArrayD<f64> {
+		self.sumw2.as_ref().unwrap_or(&self.counts).mapv(f64::sqrt)
+	}
+
+	/// Borrows an immutable reference to the histogram grid.
+	pub fn grid(&self) -> &Grid<A> {
+		&self.grid
+	}
+}
+
+#[cfg(test)]
+mod weighted_histogram_tests {
+	use super::WeightedHistogram;
+	use crate::histogram::{errors::GridError, Bins, Edges, Grid};
+	use alloc::vec;
+	#[cfg(feature = "std")]
+	use ndarray::array;
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn errors_are_root_of_sum_of_squared_weights() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+		let grid = Grid::from(vec![bins.clone(), bins]);
+		let mut histogram = WeightedHistogram::with_sumw2(grid);
+		let observations = [
+			(array![0, 0], 0.5),
+			(array![0, 0], 1.5),
+			(array![0, 0], 2.),
+			(array![2, 1], 3.),
+			(array![2, 1], 0.25),
+			(array![1, 2], 1.),
+		];
+		for (observation, weight) in &observations {
+			histogram.add_observation(observation, *weight).unwrap();
+		}
+		assert!(histogram.add_observation(&array![3, 0], 1.).is_err());
+		assert_eq!(
+			histogram.counts(),
+			array![[4., 0., 0.], [0., 0., 1.], [0., 3.25, 0.]].into_dyn()
+		);
+		let sumw2 = array![[6.5, 0., 0.], [0., 0., 1.], [0., 9.0625, 0.]].into_dyn();
+		assert_eq!(histogram.sumw2(), Some(sumw2.view()));
+		assert_eq!(histogram.errors(), sumw2.mapv(f64::sqrt));
+		assert_eq!(histogram.errors()[[0, 0]], 6.5f64.sqrt());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn errors_without_sumw2_are_poisson() {
+		let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+		let mut histogram = WeightedHistogram::new(grid);
+		for _ in 0..4 {
+			histogram.add_observation(&array![1], 1.).unwrap();
+		}
+		assert_eq!(histogram.sumw2(), None);
+		assert_eq!(histogram.errors(), array![0., 2.].into_dyn());
+	}
+
+	#[test]
+	fn try_new_with_overflowing_shape_is_bad() {
+		let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+		let grid = Grid::from(vec![bins; 70]);
+		assert!(matches!(
+			WeightedHistogram::try_with_sumw2(grid),
+			Err(GridError::TooManyBins)
+		));
+	}
+}
@@ -2,7 +2,17 @@
 //!
 //! # Features
 //!
-//!   * `rayon` for parallel sorting and bulk-selection as part of histogram computations.
+//!   * `std` (default) for quantiles, summary statistics, NaN handling, and the strategies of
+//!     [`GridBuilder`](histogram::GridBuilder) to infer grids from data. Without it, the crate is
+//!     `no_std` but requires `alloc`, providing histograms over manually constructed grids.
+//!   * `rayon` for parallel sorting and bulk-selection as part of histogram computations. Implies
+//!     `std`.
+//!   * `rand` for sampling observations from histograms and bootstrapping confidence intervals of
+//!     quantiles.
+//!   * `approx` for comparing grids of float-wrapped types like [`N64`] and [`O64`] and arrays
+//!     of densities or probabilities up to a tolerance via the traits of [`approx`].
+//!
+//! [`approx`]: https://docs.rs/approx
 
 #![deny(
 	missing_docs,
@@ -10,10 +20,23 @@
 	rustdoc::missing_crate_level_docs
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub use crate::histogram::HistogramExt;
-pub use crate::maybe_nan::{n32, n64, o32, o64, MaybeNan, MaybeNanExt, N32, N64, O32, O64};
-pub use crate::quantile::{interpolate, Quantile1dExt, QuantileExt};
+#[cfg(feature = "std")]
+pub use crate::maybe_nan::{
+	f32_array, f64_array, n32, n32_array, n64, n64_array, o32, o32_array, o64, o64_array, try_o32,
+	try_o64, MaybeNan, MaybeNanExt, N32, N64, O32, O64,
+};
+#[cfg(feature = "std")]
+pub use crate::quantile::{
+	gaussian_kde, interpolate, Bandwidth, GaussianKde, GeometricMedianExt, Quantile1dExt,
+	QuantileExt, RankMethod, RunningStats,
+};
+#[cfg(feature = "std")]
+pub use crate::summary_statistics::SummaryStatisticsExt;
 
 pub use ndarray;
 
@@ -52,5 +75,9 @@ mod private {
 
 pub mod errors;
 pub mod histogram;
+#[cfg(feature = "std")]
 mod maybe_nan;
+#[cfg(feature = "std")]
 mod quantile;
+#[cfg(feature = "std")]
+mod summary_statistics;
@@ -1,3 +1,5 @@
+use crate::errors::{NanFound, NotFinite, QuantileError};
+use crate::{interpolate::Interpolate, QuantileExt};
 use ndarray::prelude::*;
 use ndarray::{s, Data, DataMut, RemoveAxis};
 use ordered_float::{NotNan, OrderedFloat};
@@ -46,6 +48,148 @@ pub fn o64(num: f64) -> O64 {
 	OrderedFloat(num)
 }
 
+/// Casts [`f32`] into an ordered float if it is finite.
+///
+/// # Errors
+///
+/// Returns [`NotFinite`] if [`f32`] is NaN or infinite.
+#[inline]
+pub fn try_o32(num: f32) -> Result<O32, NotFinite> {
+	if num.is_finite() {
+		Ok(OrderedFloat(num))
+	} else {
+		Err(NotFinite)
+	}
+}
+/// Casts [`f64`] into an ordered float if it is finite.
+///
+/// This validates untrusted input instead of propagating NaN or infinity. Use
+/// [`OrderedFloat::into_inner`] to cast it back.
+///
+/// # Errors
+///
+/// Returns [`NotFinite`] if [`f64`] is NaN or infinite.
+///
+/// # Example
+///
+/// ```
+/// use ndarray_histogram::{errors::NotFinite, o64, try_o64};
+///
+/// assert_eq!(try_o64(1.5), Ok(o64(1.5)));
+/// assert_eq!(try_o64(1.5).map(|num| num.into_inner()), Ok(1.5));
+/// assert_eq!(try_o64(f64::NAN), Err(NotFinite));
+/// assert_eq!(try_o64(f64::NEG_INFINITY), Err(NotFinite));
+/// ```
+#[inline]
+pub fn try_o64(num: f64) -> Result<O64, NotFinite> {
+	if num.is_finite() {
+		Ok(OrderedFloat(num))
+	} else {
+		Err(NotFinite)
+	}
+}
+
+/// Casts an array of [`f32`] into an array of numbers.
+///
+/// # Errors
+///
+/// Returns [`NanFound`] if any element is NaN.
+pub fn n32_array<S, D>(array: &ArrayBase<S, D>) -> Result<Array<N32, D>, NanFound>
+where
+	S: Data<Elem = f32>,
+	D: Dimension,
+{
+	if array.iter().any(|num| num.is_nan()) {
+		return Err(NanFound);
+	}
+	Ok(array.mapv(n32))
+}
+
+/// Casts an array of [`f64`] into an array of numbers.
+///
+/// # Errors
+///
+/// Returns [`NanFound`] if any element is NaN.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray_histogram::{errors::NanFound, f64_array, n64, n64_array};
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let n = n64_array(&a)?;
+/// assert_eq!(n[[1, 0]], n64(3.));
+/// assert_eq!(f64_array(&n), a);
+///
+/// assert_eq!(n64_array(&array![1., f64::NAN]), Err(NanFound));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn n64_array<S, D>(array: &ArrayBase<S, D>) -> Result<Array<N64, D>, NanFound>
+where
+	S: Data<Elem = f64>,
+	D: Dimension,
+{
+	if array.iter().any(|num| num.is_nan()) {
+		return Err(NanFound);
+	}
+	Ok(array.mapv(n64))
+}
+
+/// Casts an array of [`f32`] into an array of ordered floats.
+#[must_use]
+pub fn o32_array<S, D>(array: &ArrayBase<S, D>) -> Array<O32, D>
+where
+	S: Data<Elem = f32>,
+	D: Dimension,
+{
+	array.mapv(o32)
+}
+
+/// Casts an array of [`f64`] into an array of ordered floats.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray_histogram::{f64_array, o64, o64_array};
+///
+/// let a = array![1., f64::NAN];
+/// let o = o64_array(&a);
+/// assert_eq!(o[0], o64(1.));
+/// assert!(f64_array(&o)[1].is_nan());
+/// ```
+#[must_use]
+pub fn o64_array<S, D>(array: &ArrayBase<S, D>) -> Array<O64, D>
+where
+	S: Data<Elem = f64>,
+	D: Dimension,
+{
+	array.mapv(o64)
+}
+
+/// Casts an array of numbers or ordered floats into an array of [`f32`].
+#[must_use]
+pub fn f32_array<A, S, D>(array: &ArrayBase<S, D>) -> Array<f32, D>
+where
+	A: Copy + Into<f32>,
+	S: Data<Elem = A>,
+	D: Dimension,
+{
+	array.mapv(Into::into)
+}
+
+/// Casts an array of numbers or ordered floats into an array of [`f64`].
+#[must_use]
+pub fn f64_array<A, S, D>(array: &ArrayBase<S, D>) -> Array<f64, D>
+where
+	A: Copy + Into<f64>,
+	S: Data<Elem = A>,
+	D: Dimension,
+{
+	array.mapv(Into::into)
+}
+
 /// A number type that can have not-a-number values.
 pub trait MaybeNan: Sized {
 	/// A type that is guaranteed not to be a NaN value.
@@ -476,6 +620,43 @@ where
 		D: RemoveAxis,
 		F: FnMut(ArrayViewMut1<'a, A::NotNan>) -> B;
 
+	/// Return the `q`th quantile of each lane along `axis`, skipping NaN values.
+	///
+	/// The NaN values are partitioned out of each lane **in place** before the quantile of the
+	/// remaining values is computed. Lanes consisting of NaN values only yield NaN. This is
+	/// [`QuantileExt::quantile_axis_skipnan_mut`] returning an array of dynamic dimension.
+	///
+	/// Returns `Err(EmptyInput)` when the specified axis has length 0.
+	///
+	/// **Panics** if `axis` is out of bounds.
+	///
+	/// [`QuantileExt::quantile_axis_skipnan_mut`]: trait.QuantileExt.html#tymethod.quantile_axis_skipnan_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::{array, Axis};
+	/// use ndarray_histogram::{interpolate::Linear, n64, MaybeNanExt};
+	///
+	/// let mut a = array![[1., f64::NAN, 3.], [f64::NAN, f64::NAN, f64::NAN]];
+	/// let medians = a.nan_quantile_axis_mut(Axis(1), n64(0.5), &Linear)?;
+	/// assert_eq!(medians[[0]], 2.);
+	/// assert!(medians[[1]].is_nan());
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	fn nan_quantile_axis_mut<I>(
+		&mut self,
+		axis: Axis,
+		q: N64,
+		interpolate: &I,
+	) -> Result<ArrayD<A>, QuantileError<f64>>
+	where
+		A: Send,
+		A::NotNan: Clone + Ord + Send,
+		S: DataMut,
+		D: RemoveAxis,
+		I: Interpolate<A::NotNan>;
+
 	private_decl! {}
 }
 
@@ -554,6 +735,23 @@ where
 		self.map_axis_mut(axis, |lane| mapping(A::remove_nan_mut(lane)))
 	}
 
+	fn nan_quantile_axis_mut<I>(
+		&mut self,
+		axis: Axis,
+		q: N64,
+		interpolate: &I,
+	) -> Result<ArrayD<A>, QuantileError<f64>>
+	where
+		A: Send,
+		A::NotNan: Clone + Ord + Send,
+		S: DataMut,
+		D: RemoveAxis,
+		I: Interpolate<A::NotNan>,
+	{
+		self.quantile_axis_skipnan_mut(axis, q.into_inner(), interpolate)
+			.map(Array::into_dyn)
+	}
+
 	private_impl! {}
 }
 
@@ -0,0 +1,94 @@
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::ToPrimitive;
+
+/// Geometric median method for 2-dimensional arrays whose rows are points.
+pub trait GeometricMedianExt<A, S>
+where
+	S: Data<Elem = A>,
+{
+	/// Returns the [geometric median] of the points, i.e. the point minimizing the sum of the
+	/// Euclidean distances to all rows of the array.
+	///
+	/// Unlike the per-axis medians of [`quantile_axis_mut`], the geometric median is a robust
+	/// center which is invariant to rotations. It is computed in `f64` precision with Weiszfeld's
+	/// iterative algorithm starting at the mean of the points. Its modification by Vardi and Zhang
+	/// allows to converge to a median which is one of the points, as happens for collinear points.
+	/// The iteration stops once an update moves the estimate by less than `tol`.
+	///
+	/// Returns `None` if there are no points or if the iteration has not converged within
+	/// `max_iter` updates.
+	///
+	/// **Panics** if an element cannot be converted into `f64`.
+	///
+	/// [geometric median]: https://en.wikipedia.org/wiki/Geometric_median
+	/// [`quantile_axis_mut`]: trait.QuantileExt.html#tymethod.quantile_axis_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::GeometricMedianExt;
+	///
+	/// // The outlier pulls the mean but not the geometric median.
+	/// let points = array![[0., 0.], [2., 0.], [0., 2.], [2., 2.], [100., 100.]];
+	/// let median = points.geometric_median(1e-12, 1_000).unwrap();
+	/// let expected = 1. + 1. / 3f64.sqrt();
+	/// assert!(median.iter().all(|x| (x - expected).abs() < 1e-9));
+	/// ```
+	fn geometric_median(&self, tol: f64, max_iter: usize) -> Option<Array1<f64>>
+	where
+		A: ToPrimitive;
+
+	private_decl! {}
+}
+
+impl<A, S> GeometricMedianExt<A, S> for ArrayBase<S, Ix2>
+where
+	S: Data<Elem = A>,
+{
+	fn geometric_median(&self, tol: f64, max_iter: usize) -> Option<Array1<f64>>
+	where
+		A: ToPrimitive,
+	{
+		let points = self.map(|x| x.to_f64().unwrap());
+		let mut median = points.mean_axis(Axis(0))?;
+		for _ in 0..max_iter {
+			// Weighted sum of the points and sum of the weights, both excluding coinciding points.
+			let mut weighted_sum = Array1::zeros(median.len());
+			let mut weights = 0.;
+			let mut n_coinciding = 0;
+			for point in points.rows() {
+				let distance = (&point - &median).mapv(|x| x * x).sum().sqrt();
+				if distance > 0. {
+					weighted_sum.scaled_add(1. / distance, &point);
+					weights += 1. / distance;
+				} else {
+					n_coinciding += 1;
+				}
+			}
+			if weights == 0. {
+				// All points coincide with the estimate.
+				return Some(median);
+			}
+			let weiszfeld = weighted_sum / weights;
+			let next = if n_coinciding == 0 {
+				weiszfeld
+			} else {
+				// Pull towards the coinciding point by the ratio of its weight to the residual.
+				let residual = (&weiszfeld - &median) * weights;
+				let norm = residual.mapv(|x| x * x).sum().sqrt();
+				let ratio = (n_coinciding as f64 / norm).min(1.);
+				weiszfeld * (1. - ratio) + &median * ratio
+			};
+			let step = (&next - &median).mapv(|x| x * x).sum().sqrt();
+			median = next;
+			if step < tol {
+				return Some(median);
+			}
+		}
+		None
+	}
+
+	private_impl! {}
+}
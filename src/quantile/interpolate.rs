@@ -1,4 +1,6 @@
 //! Interpolation strategies.
+use crate::N64;
+use core::cmp::Ordering;
 use num_traits::{Float, FromPrimitive, NumOps, ToPrimitive};
 
 fn float_quantile_index<F: Float>(q: F, len: usize) -> F {
@@ -47,6 +49,30 @@ pub trait Interpolate<T> {
 	private_decl! {}
 }
 
+/// Resolves the `q`th quantile of `len` sorted values from its bracketing `lower` and `higher`
+/// values according to the interpolation strategy `I`.
+///
+/// The `lower` and `higher` values are the ones that would be indexed as the floor and the ceiling
+/// of `(len - 1)q`, respectively.
+///
+/// **Panics** if `None` is provided for a value needed by the strategy, e.g. for either value in
+/// case of [`Linear`] or [`Midpoint`].
+///
+/// # Example
+///
+/// ```
+/// use ndarray_histogram::{
+/// 	interpolate::{resolve, Linear},
+/// 	n64,
+/// };
+///
+/// // The 0.35th quantile of 11 values lies at index 3.5, between the 4th and the 5th value.
+/// assert_eq!(resolve::<_, Linear>(Some(10.), Some(20.), n64(0.35), 11), 15.);
+/// ```
+pub fn resolve<T, I: Interpolate<T>>(lower: Option<T>, higher: Option<T>, q: N64, len: usize) -> T {
+	I::interpolate(lower, higher, q.into_inner(), len)
+}
+
 /// Select the higher value.
 pub struct Higher;
 /// Select the lower value.
@@ -54,10 +80,17 @@ pub struct Lower;
 /// Select the nearest value.
 pub struct Nearest;
 /// Select the midpoint of the two values (`(lower + higher) / 2`).
+///
+/// The midpoint of integers is rounded down, i.e. towards `lower`, and computed without overflow
+/// even at the extremes of signed and unsigned integer types.
 pub struct Midpoint;
 /// Linearly interpolate between the two values
 /// (`lower + (higher - lower) * fraction`, where `fraction` is the
 /// fractional part of the index surrounded by `lower` and `higher`).
+///
+/// The difference of the values is computed in `f64` precision and the interpolated value is
+/// truncated towards `lower` for integers. Integers of a magnitude beyond `2^53`, which are not
+/// exactly representable as `f64`, are interpolated in `i128` arithmetic instead.
 pub struct Linear;
 
 impl<T> Interpolate<T> for Higher {
@@ -105,7 +138,7 @@ impl<T> Interpolate<T> for Nearest {
 
 impl<T> Interpolate<T> for Midpoint
 where
-	T: NumOps + Clone + FromPrimitive,
+	T: NumOps + Clone + FromPrimitive + PartialOrd,
 {
 	fn needs_lower<F: Float>(_q: F, _len: usize) -> bool {
 		true
@@ -114,10 +147,29 @@ where
 		true
 	}
 	fn interpolate<F: Float>(lower: Option<T>, higher: Option<T>, _q: F, _len: usize) -> T {
-		let denom = T::from_u8(2).unwrap();
+		let zero = T::from_u8(0).unwrap();
+		let one = T::from_u8(1).unwrap();
+		let two = T::from_u8(2).unwrap();
 		let lower = lower.unwrap();
 		let higher = higher.unwrap();
-		lower.clone() + (higher - lower) / denom
+		debug_assert!(
+			higher.partial_cmp(&lower) != Some(Ordering::Less),
+			"Lower value is greater than higher value"
+		);
+		if (lower < zero) == (higher < zero) {
+			// The difference of values of the same sign does not overflow, e.g. of unsigned values.
+			lower.clone() + (higher - lower) / two
+		} else {
+			// The sum of values of opposite signs does not overflow.
+			let sum = lower + higher;
+			let midpoint = sum.clone() / two.clone();
+			// Integer division truncates towards zero, round down instead.
+			if one.clone() / two.clone() == zero && sum % two < zero {
+				midpoint - one
+			} else {
+				midpoint
+			}
+		}
 	}
 	private_impl! {}
 }
@@ -136,9 +188,35 @@ where
 		let fraction = float_quantile_index_fraction(q, len).to_f64().unwrap();
 		let lower = lower.unwrap();
 		let higher = higher.unwrap();
+		if let Some(value) = linear_large_integer(&lower, &higher, fraction) {
+			return value;
+		}
 		let lower_f64 = lower.to_f64().unwrap();
 		let higher_f64 = higher.to_f64().unwrap();
 		lower + T::from_f64(fraction * (higher_f64 - lower_f64)).unwrap()
 	}
 	private_impl! {}
 }
+
+/// Linearly interpolates integers of a magnitude beyond `2^53` in `i128` arithmetic with
+/// `fraction` as a multiple of `2^-53`.
+///
+/// Returns `None` for non-integer types, for integers exactly representable as `f64`, and if
+/// `i128` arithmetic overflows, e.g. for `u128`.
+fn linear_large_integer<T>(lower: &T, higher: &T, fraction: f64) -> Option<T>
+where
+	T: NumOps + FromPrimitive + ToPrimitive,
+{
+	const SCALE: i128 = 1 << f64::MANTISSA_DIGITS;
+	// Integer division truncates.
+	if (T::from_u8(1)? / T::from_u8(2)?).to_f64()? != 0. {
+		return None;
+	}
+	let (lower, higher) = (lower.to_i128()?, higher.to_i128()?);
+	if lower.unsigned_abs() <= SCALE as u128 && higher.unsigned_abs() <= SCALE as u128 {
+		return None;
+	}
+	let numerator = (fraction * SCALE as f64) as i128;
+	let offset = higher.checked_sub(lower)?.checked_mul(numerator)? / SCALE;
+	T::from_i128(lower + offset)
+}
@@ -0,0 +1,107 @@
+use crate::errors::KdeError;
+use crate::QuantileExt;
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::ToPrimitive;
+use std::f64::consts::PI;
+
+/// Bandwidth of a [`GaussianKde`], either given or selected from the sample.
+///
+/// The automatic rules scale the unbiased sample standard deviation `sd` of `n` observations as
+/// [SciPy's `gaussian_kde`] does for one dimension.
+///
+/// [`GaussianKde`]: struct.GaussianKde.html
+/// [SciPy's `gaussian_kde`]: https://docs.scipy.org/doc/scipy/reference/generated/scipy.stats.gaussian_kde.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bandwidth {
+	/// Scott's rule of thumb `sd * n^(-1/5)`.
+	Scott,
+	/// Silverman's rule of thumb `sd * (3 * n / 4)^(-1/5)`.
+	Silverman,
+	/// The given bandwidth.
+	Fixed(f64),
+}
+
+/// Gaussian kernel density estimate of a 1-dimensional sample.
+///
+/// The density is the mean of normal densities centered at the observations with the bandwidth as
+/// their standard deviation. It is a smooth alternative to a [`Histogram`] normalized to a density.
+///
+/// See [`gaussian_kde`] on how to construct it.
+///
+/// [`Histogram`]: histogram/struct.Histogram.html
+/// [`gaussian_kde`]: fn.gaussian_kde.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaussianKde {
+	sample: Array1<f64>,
+	bandwidth: f64,
+}
+
+/// Returns the Gaussian kernel density estimate of `sample` with the given or selected
+/// `bandwidth`.
+///
+/// Returns `Err(KdeError::EmptyInput)` if `sample` is empty and `Err(KdeError::InvalidBandwidth)`
+/// if the bandwidth is not positive and finite. This includes a selected bandwidth of a sample with
+/// less than two distinct observations.
+///
+/// **Panics** if an observation cannot be converted into `f64`.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray_histogram::{gaussian_kde, Bandwidth};
+///
+/// let kde = gaussian_kde(&array![-1., 1.], Bandwidth::Fixed(1.)).unwrap();
+/// let density = |x: f64| (-x * x / 2.).exp() / (2. * std::f64::consts::PI).sqrt();
+/// assert_eq!(kde.pdf(0.), (density(-1.) + density(1.)) / 2.);
+/// assert_eq!(kde.evaluate(&array![-1., 1.]), array![kde.pdf(-1.), kde.pdf(1.)]);
+/// ```
+pub fn gaussian_kde<A, S>(
+	sample: &ArrayBase<S, Ix1>,
+	bandwidth: Bandwidth,
+) -> Result<GaussianKde, KdeError>
+where
+	A: ToPrimitive,
+	S: Data<Elem = A>,
+{
+	if sample.is_empty() {
+		return Err(KdeError::EmptyInput);
+	}
+	let sample = sample.map(|x| x.to_f64().unwrap());
+	let n = sample.len() as f64;
+	let bandwidth = match bandwidth {
+		Bandwidth::Scott => QuantileExt::std(&sample, 1).unwrap_or(0.) * n.powf(-0.2),
+		Bandwidth::Silverman => QuantileExt::std(&sample, 1).unwrap_or(0.) * (n * 0.75).powf(-0.2),
+		Bandwidth::Fixed(bandwidth) => bandwidth,
+	};
+	if !(bandwidth.is_finite() && bandwidth > 0.) {
+		return Err(KdeError::InvalidBandwidth(bandwidth));
+	}
+	Ok(GaussianKde { sample, bandwidth })
+}
+
+impl GaussianKde {
+	/// Returns the bandwidth, i.e. the standard deviation of the kernel.
+	pub fn bandwidth(&self) -> f64 {
+		self.bandwidth
+	}
+
+	/// Returns the estimated probability density at `x`.
+	pub fn pdf(&self, x: f64) -> f64 {
+		let sum = self.sample.fold(0., |sum, &xi| {
+			sum + (-0.5 * ((x - xi) / self.bandwidth).powi(2)).exp()
+		});
+		sum / (self.sample.len() as f64 * self.bandwidth * (2. * PI).sqrt())
+	}
+
+	/// Returns the estimated probability densities at `xs`, see [`pdf`].
+	///
+	/// [`pdf`]: #method.pdf
+	pub fn evaluate<S>(&self, xs: &ArrayBase<S, Ix1>) -> Array1<f64>
+	where
+		S: Data<Elem = f64>,
+	{
+		xs.mapv(|x| self.pdf(x))
+	}
+}
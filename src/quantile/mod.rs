@@ -1,12 +1,40 @@
+pub use self::geometric_median::GeometricMedianExt;
 use self::interpolate::{higher_index, lower_index, Interpolate};
-use crate::errors::QuantileError;
+pub use self::kde::{gaussian_kde, Bandwidth, GaussianKde};
+pub use self::running::RunningStats;
 use crate::errors::{EmptyInput, MinMaxError, MinMaxError::UndefinedOrder};
-use crate::{MaybeNan, MaybeNanExt};
+use crate::errors::{QuantileError, RollingQuantileError};
+use crate::errors::{ShapeMismatch, WeightedQuantileError};
+use crate::{MaybeNan, MaybeNanExt, N64, O64};
 use ndarray::prelude::*;
 use ndarray::{Data, DataMut, RemoveAxis, Zip};
 use ndarray_slice::Slice1Ext;
-use num_traits::Float;
-use std::{cmp, collections::HashMap, fmt::Debug};
+use num_traits::{Float, FromPrimitive, ToPrimitive, Zero};
+#[cfg(feature = "rand")]
+use rand::Rng;
+use std::{
+	cmp,
+	collections::HashMap,
+	fmt::Debug,
+	ops::{Add, Div, Sub},
+};
+
+/// Methods of resolving ties of [`Quantile1dExt::rank_mut`].
+///
+/// [`Quantile1dExt::rank_mut`]: trait.Quantile1dExt.html#tymethod.rank_mut
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RankMethod {
+	/// Ties get the mean of the ranks they span, e.g. `[1, 2, 2, 3]` ranks `[1., 2.5, 2.5, 4.]`.
+	Average,
+	/// Ties get the lowest rank they span, e.g. `[1, 2, 2, 3]` ranks `[1., 2., 2., 4.]`.
+	Min,
+	/// Ties get the highest rank they span, e.g. `[1, 2, 2, 3]` ranks `[1., 3., 3., 4.]`.
+	Max,
+	/// Like `Min` but without gaps after ties, e.g. `[1, 2, 2, 3]` ranks `[1., 2., 2., 3.]`.
+	Dense,
+	/// Ties get distinct ranks in order of appearance, e.g. `[1, 2, 2, 3]` ranks `[1., 2., 3., 4.]`.
+	Ordinal,
+}
 
 /// Quantile methods for `ArrayBase`.
 pub trait QuantileExt<A, S, D>
@@ -169,6 +197,98 @@ where
 		A: MaybeNan,
 		A::NotNan: Ord + Send;
 
+	/// Finds the range of the array, i.e. the difference between its maximum and minimum.
+	///
+	/// Both extremes are tracked simultaneously in a single pass.
+	///
+	/// Returns `Err(MinMaxError::UndefinedOrder)` if any of the pairwise
+	/// orderings tested by the function are undefined. (For example, this
+	/// occurs if there are any floating-point NaN values in the array.)
+	///
+	/// Returns `Err(MinMaxError::EmptyInput)` if the array is empty.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::QuantileExt;
+	///
+	/// let a = array![[1., 3., 7.], [2., -5., 6.]];
+	/// assert_eq!(a.peak_to_peak(), Ok(12.));
+	/// ```
+	fn peak_to_peak(&self) -> Result<A, MinMaxError>
+	where
+		A: PartialOrd + Send + Clone + Sub<Output = A>;
+
+	/// Returns the arithmetic mean of all elements in the array, or `None` if it is empty.
+	///
+	/// The elements are summed in `A`, so integer means are truncated towards zero.
+	///
+	/// **Note** that the inherent methods `ArrayBase::mean`, `ArrayBase::var`, and
+	/// `ArrayBase::std` of [`ndarray`] take precedence over [`mean`], [`var`], and [`std`] in method
+	/// call syntax, so use fully qualified syntax instead.
+	///
+	/// [`mean`]: #tymethod.mean
+	/// [`var`]: #tymethod.var
+	/// [`std`]: #tymethod.std
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::QuantileExt;
+	///
+	/// let a = array![[1., 3.], [4., 8.]];
+	/// assert_eq!(QuantileExt::mean(&a), Some(4.));
+	/// ```
+	fn mean(&self) -> Option<A>
+	where
+		A: Clone + Zero + Add<Output = A> + Div<Output = A> + FromPrimitive;
+
+	/// Returns the variance of all elements in the array with `ddof` delta degrees of freedom.
+	///
+	/// The variance is computed as `sum((x - mean)^2) / (n - ddof)` where `n` is the number of
+	/// elements. Use `ddof = 0` for the population variance and `ddof = 1` for the unbiased sample
+	/// variance.
+	///
+	/// The elements are accumulated in `f64` precision in a single pass using [Welford's algorithm]
+	/// which avoids the catastrophic cancellation of the naive `mean(x^2) - mean(x)^2`.
+	///
+	/// Returns `None` if `ddof >= n`, i.e. if the array is empty for `ddof = 0`.
+	///
+	/// See [`mean`] on how to call this method.
+	///
+	/// [Welford's algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+	/// [`mean`]: #tymethod.mean
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::QuantileExt;
+	///
+	/// let a = array![2, 4, 4, 4, 5, 5, 7, 9];
+	/// assert_eq!(QuantileExt::var(&a, 0), Some(4.));
+	/// assert_eq!(QuantileExt::std(&a, 0), Some(2.));
+	/// assert_eq!(QuantileExt::var(&a, 8), None);
+	/// ```
+	fn var(&self, ddof: usize) -> Option<f64>
+	where
+		A: ToPrimitive;
+
+	/// Returns the standard deviation of all elements in the array with `ddof` delta degrees of
+	/// freedom, i.e. the square root of [`var`].
+	///
+	/// Returns `None` if `ddof >= n` where `n` is the number of elements.
+	///
+	/// See [`mean`] on how to call this method.
+	///
+	/// [`var`]: #tymethod.var
+	/// [`mean`]: #tymethod.mean
+	fn std(&self, ddof: usize) -> Option<f64>
+	where
+		A: ToPrimitive;
+
 	/// Return the qth quantile of the data along the specified axis.
 	///
 	/// `q` needs to be a float between 0 and 1, bounds included.
@@ -202,6 +322,19 @@ where
 	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
 	///
 	/// **Panics** if `axis` is out of bounds.
+	///
+	/// # Example
+	///
+	/// The result has one dimension less than the array, e.g. an `Array1` for an `Array2`.
+	///
+	/// ```
+	/// use ndarray::{array, Array1, Axis};
+	/// use ndarray_histogram::{interpolate::Midpoint, QuantileExt};
+	///
+	/// let mut data = array![[3, 1, 2], [8, 4, 6]];
+	/// let row_medians: Array1<i32> = data.quantile_axis_mut(Axis(1), 0.5, &Midpoint).unwrap();
+	/// assert_eq!(row_medians, array![2, 6]);
+	/// ```
 	fn quantile_axis_mut<F, I>(
 		&mut self,
 		axis: Axis,
@@ -279,6 +412,72 @@ where
 		F: Float + Debug,
 		I: Interpolate<A::NotNan>;
 
+	/// Return the `q`th quantile of all the data without mutating it.
+	///
+	/// The data is copied into a newly allocated 1-dimensional array in logical order, which is
+	/// then passed to [`Quantile1dExt::quantile_mut`]. This requires O(`m`) extra memory where `m`
+	/// is the number of elements in the array. Use [`Quantile1dExt::quantile_mut`] instead to
+	/// avoid the copy if the data can be shuffled in place.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Lower, n64, QuantileExt};
+	///
+	/// let data = array![[3, 1, 2], [6, 5, 4]];
+	/// // Copies all six elements, leaving `data` untouched.
+	/// assert_eq!(data.quantile(n64(0.5), &Lower), Ok(3));
+	/// assert_eq!(data, array![[3, 1, 2], [6, 5, 4]]);
+	/// ```
+	fn quantile<I>(&self, q: N64, interpolate: &I) -> Result<A, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		I: Interpolate<A>;
+
+	/// Return multiple quantiles of all the data at once, in the order of `qs`.
+	///
+	/// The elements are treated as a single 1-dimensional sequence and bulk-selected in a single
+	/// pass, see [`Quantile1dExt::quantiles_mut`]. Unlike [`quantiles_axis_mut`], the quantiles are
+	/// not computed per lane but across the whole array.
+	///
+	/// If the array is contiguous in memory, it is shuffled **in place** without allocating a copy.
+	/// Otherwise, the data is copied into a newly allocated buffer, requiring O(`m`) extra memory
+	/// where `m` is the number of elements in the array, and the array is left unchanged. No
+	/// assumptions should be made on the ordering of the array elements after this computation.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if any `q` in `qs` is not between `0.` and `1.`
+	/// (inclusive).
+	///
+	/// [`quantiles_axis_mut`]: #tymethod.quantiles_axis_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Lower, n64, QuantileExt};
+	///
+	/// let mut data = array![[3, 1, 2], [6, 5, 4]];
+	/// let qs = array![n64(1.), n64(0.), n64(0.5)];
+	/// assert_eq!(data.flat_quantiles_mut(&qs, &Lower), Ok(array![6, 1, 3]));
+	/// ```
+	fn flat_quantiles_mut<S2, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		interpolate: &I,
+	) -> Result<Array1<A>, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		S2: Data<Elem = N64>,
+		I: Interpolate<A>;
+
 	private_decl! {}
 }
 
@@ -422,6 +621,60 @@ where
 		}))
 	}
 
+	fn peak_to_peak(&self) -> Result<A, MinMaxError>
+	where
+		A: PartialOrd + Send + Clone + Sub<Output = A>,
+	{
+		let first = self.first().ok_or(EmptyInput)?;
+		let (min, max) = self.fold(Ok((first, first)), |acc: Result<_, MinMaxError>, elem| {
+			let (min, max) = acc?;
+			if elem.partial_cmp(min).ok_or(UndefinedOrder)? == cmp::Ordering::Less {
+				Ok((elem, max))
+			} else if elem.partial_cmp(max).ok_or(UndefinedOrder)? == cmp::Ordering::Greater {
+				Ok((min, elem))
+			} else {
+				Ok((min, max))
+			}
+		})?;
+		Ok(max.clone() - min.clone())
+	}
+
+	fn mean(&self) -> Option<A>
+	where
+		A: Clone + Zero + Add<Output = A> + Div<Output = A> + FromPrimitive,
+	{
+		if self.is_empty() {
+			return None;
+		}
+		let sum = self.fold(A::zero(), |sum, elem| sum + elem.clone());
+		Some(sum / A::from_usize(self.len())?)
+	}
+
+	fn var(&self, ddof: usize) -> Option<f64>
+	where
+		A: ToPrimitive,
+	{
+		if ddof >= self.len() {
+			return None;
+		}
+		let mut mean = 0.;
+		let mut sum_sq = 0.;
+		for (i, elem) in self.iter().enumerate() {
+			let elem = elem.to_f64().unwrap();
+			let delta = elem - mean;
+			mean += delta / (i + 1) as f64;
+			sum_sq += delta * (elem - mean);
+		}
+		Some(sum_sq / (self.len() - ddof) as f64)
+	}
+
+	fn std(&self, ddof: usize) -> Option<f64>
+	where
+		A: ToPrimitive,
+	{
+		QuantileExt::var(self, ddof).map(f64::sqrt)
+	}
+
 	fn quantiles_axis_mut<S2, F, I>(
 		&mut self,
 		axis: Axis,
@@ -436,85 +689,13 @@ where
 		F: Float + Debug,
 		I: Interpolate<A>,
 	{
-		// Minimize number of type parameters to avoid monomorphization bloat.
-		fn quantiles_axis_mut<A, D, F, I>(
-			mut data: ArrayViewMut<'_, A, D>,
-			axis: Axis,
-			qs: ArrayView1<'_, F>,
-			_interpolate: &I,
-		) -> Result<Array<A, D>, QuantileError<F>>
-		where
-			D: RemoveAxis,
-			A: Ord + Send + Clone,
-			F: Float + Debug,
-			I: Interpolate<A>,
-		{
-			for &q in qs {
-				if !(F::from(0.).unwrap()..=F::from(1.).unwrap()).contains(&q) {
-					return Err(QuantileError::InvalidQuantile(q));
-				}
-			}
-
-			let axis_len = data.len_of(axis);
-			if axis_len == 0 {
-				return Err(QuantileError::EmptyInput);
-			}
-
-			let mut results_shape = data.raw_dim();
-			results_shape[axis.index()] = qs.len();
-			if results_shape.size() == 0 {
-				return Ok(Array::from_shape_vec(results_shape, Vec::new()).unwrap());
-			}
-
-			let mut searched_indexes = Vec::with_capacity(2 * qs.len());
-			for &q in &qs {
-				if I::needs_lower(q, axis_len) {
-					searched_indexes.push(lower_index(q, axis_len));
-				}
-				if I::needs_higher(q, axis_len) {
-					searched_indexes.push(higher_index(q, axis_len));
-				}
-			}
-			let mut indexes = Array1::from_vec(searched_indexes);
-			indexes.sort_unstable();
-			let (indexes, _duplicates) = indexes.partition_dedup();
-
-			let mut results = Array::from_elem(results_shape, data.first().unwrap().clone());
-			Zip::from(results.lanes_mut(axis))
-				.and(data.lanes_mut(axis))
-				.for_each(|mut results, mut data| {
-					#[cfg(feature = "rayon")]
-					let values = {
-						let mut values = Vec::new();
-						data.par_select_many_nth_unstable(&indexes, &mut values);
-						HashMap::<usize, &mut A>::from_iter(
-							indexes.iter().copied().zip(values.into_iter()),
-						)
-					};
-					#[cfg(not(feature = "rayon"))]
-					let values = {
-						let mut values = HashMap::new();
-						data.select_many_nth_unstable(&indexes, &mut values);
-						values
-					};
-					for (result, &q) in results.iter_mut().zip(qs) {
-						let lower = if I::needs_lower(q, axis_len) {
-							Some(values[&lower_index(q, axis_len)].clone())
-						} else {
-							None
-						};
-						let higher = if I::needs_higher(q, axis_len) {
-							Some(values[&higher_index(q, axis_len)].clone())
-						} else {
-							None
-						};
-						*result = I::interpolate(lower, higher, q, axis_len);
-					}
-				});
-			Ok(results)
-		}
-
-		quantiles_axis_mut(self.view_mut(), axis, qs.view(), interpolate)
+		quantiles_axis_mut(
+			self.view_mut(),
+			axis,
+			qs.view(),
+			interpolate,
+			cfg!(feature = "rayon"),
+		)
 	}
 
 	fn quantile_axis_mut<F, I>(
@@ -572,9 +753,178 @@ where
 		Ok(quantile)
 	}
 
+	fn quantile<I>(&self, q: N64, interpolate: &I) -> Result<A, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		I: Interpolate<A>,
+	{
+		let mut data = self.iter().cloned().collect::<Array1<A>>();
+		data.quantile_mut(q.into_inner(), interpolate)
+	}
+
+	fn flat_quantiles_mut<S2, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		interpolate: &I,
+	) -> Result<Array1<A>, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		S2: Data<Elem = N64>,
+		I: Interpolate<A>,
+	{
+		let qs = qs.mapv(N64::into_inner);
+		match self.as_slice_memory_order_mut() {
+			Some(data) => ArrayViewMut1::from(data).quantiles_mut(&qs, interpolate),
+			None => {
+				let mut data = self.iter().cloned().collect::<Array1<A>>();
+				data.quantiles_mut(&qs, interpolate)
+			}
+		}
+	}
+
 	private_impl! {}
 }
 
+/// Returns the smallest value whose cumulative weight reaches `q` times the `total` weight, i.e. the
+/// inverse of the weighted empirical distribution function, given pairs of values and weights
+/// sorted by value.
+pub(crate) fn weighted_quantile<T: Clone>(pairs: &[(T, f64)], total: f64, q: f64) -> T {
+	let target = q * total;
+	let mut cumulative = 0.;
+	for (value, weight) in pairs {
+		cumulative += weight;
+		if cumulative >= target {
+			return value.clone();
+		}
+	}
+	// Accumulated rounding errors may fall short of `total`.
+	pairs.last().unwrap().0.clone()
+}
+
+/// Maximum length of data whose quantile is looked up after sorting instead of selecting it, as
+/// the overhead of selection dominates for tiny data.
+const SORT_QUANTILE_MAX_LEN: usize = 16;
+
+/// Sorts the data in place, in parallel if the `rayon` feature is enabled.
+fn sort_unstable<A: Ord + Send>(mut data: ArrayViewMut1<'_, A>) {
+	#[cfg(feature = "rayon")]
+	data.par_sort_unstable();
+	#[cfg(not(feature = "rayon"))]
+	data.sort_unstable();
+}
+
+/// Returns the number of leading elements of the sorted data satisfying `pred`.
+fn partition_point<A>(data: ArrayView1<'_, A>, mut pred: impl FnMut(&A) -> bool) -> usize {
+	let mut left = 0;
+	let mut right = data.len();
+	while left < right {
+		let mid = left + (right - left) / 2;
+		if pred(&data[mid]) {
+			left = mid + 1;
+		} else {
+			right = mid;
+		}
+	}
+	left
+}
+
+/// Returns the median of the data as `f64`, the midpoint of the two middle elements if needed.
+fn median_f64<A>(mut data: ArrayViewMut1<'_, A>) -> Result<f64, EmptyInput>
+where
+	A: Ord + ToPrimitive,
+{
+	let len = data.len();
+	if len == 0 {
+		return Err(EmptyInput);
+	}
+	let (lower_index, higher_index) = ((len - 1) / 2, len / 2);
+	let (_, lower, higher) = data.select_nth_unstable(lower_index);
+	let lower = lower.to_f64().unwrap();
+	if lower_index == higher_index {
+		Ok(lower)
+	} else {
+		let higher = higher.iter().min().unwrap().to_f64().unwrap();
+		Ok(lower + (higher - lower) / 2.)
+	}
+}
+
+// Minimize number of type parameters to avoid monomorphization bloat.
+fn quantiles_axis_mut<A, D, F, I>(
+	mut data: ArrayViewMut<'_, A, D>,
+	axis: Axis,
+	qs: ArrayView1<'_, F>,
+	_interpolate: &I,
+	#[cfg_attr(not(feature = "rayon"), allow(unused_variables))] parallel: bool,
+) -> Result<Array<A, D>, QuantileError<F>>
+where
+	D: RemoveAxis,
+	A: Ord + Send + Clone,
+	F: Float + Debug,
+	I: Interpolate<A>,
+{
+	for &q in qs {
+		if !(F::from(0.).unwrap()..=F::from(1.).unwrap()).contains(&q) {
+			return Err(QuantileError::InvalidQuantile(q));
+		}
+	}
+
+	let axis_len = data.len_of(axis);
+	if axis_len == 0 {
+		return Err(QuantileError::EmptyInput);
+	}
+
+	let mut results_shape = data.raw_dim();
+	results_shape[axis.index()] = qs.len();
+	if results_shape.size() == 0 {
+		return Ok(Array::from_shape_vec(results_shape, Vec::new()).unwrap());
+	}
+
+	let mut searched_indexes = Vec::with_capacity(2 * qs.len());
+	for &q in &qs {
+		if I::needs_lower(q, axis_len) {
+			searched_indexes.push(lower_index(q, axis_len));
+		}
+		if I::needs_higher(q, axis_len) {
+			searched_indexes.push(higher_index(q, axis_len));
+		}
+	}
+	let mut indexes = Array1::from_vec(searched_indexes);
+	indexes.sort_unstable();
+	let (indexes, _duplicates) = indexes.partition_dedup();
+
+	let mut results = Array::from_elem(results_shape, data.first().unwrap().clone());
+	Zip::from(results.lanes_mut(axis))
+		.and(data.lanes_mut(axis))
+		.for_each(|mut results, mut data| {
+			let mut values = HashMap::new();
+			#[cfg(feature = "rayon")]
+			if parallel {
+				let mut par_values = Vec::new();
+				data.par_select_many_nth_unstable(&indexes, &mut par_values);
+				values.extend(indexes.iter().copied().zip(par_values));
+			} else {
+				data.select_many_nth_unstable(&indexes, &mut values);
+			}
+			#[cfg(not(feature = "rayon"))]
+			data.select_many_nth_unstable(&indexes, &mut values);
+			for (result, &q) in results.iter_mut().zip(qs) {
+				let lower = if I::needs_lower(q, axis_len) {
+					Some(values[&lower_index(q, axis_len)].clone())
+				} else {
+					None
+				};
+				let higher = if I::needs_higher(q, axis_len) {
+					Some(values[&higher_index(q, axis_len)].clone())
+				} else {
+					None
+				};
+				*result = I::interpolate(lower, higher, q, axis_len);
+			}
+		});
+	Ok(results)
+}
+
 /// Quantile methods for 1-D arrays.
 pub trait Quantile1dExt<A, S>
 where
@@ -644,42 +994,1081 @@ where
 		S2: Data<Elem = F>,
 		I: Interpolate<A>;
 
-	private_decl! {}
-}
-
-impl<A, S> Quantile1dExt<A, S> for ArrayBase<S, Ix1>
-where
-	A: Send,
-	S: Data<Elem = A>,
-{
-	fn quantile_mut<F, I>(&mut self, q: F, interpolate: &I) -> Result<A, QuantileError<F>>
+	/// A parallel version of [`quantiles_mut`], using parallel bulk-selection.
+	///
+	/// Returns the same quantiles as [`quantiles_mut`], which selects in parallel as well when the
+	/// `rayon` feature is enabled. This method states the intent explicitly, see
+	/// [`par_quantiles_mut_with_threshold`] to select serially for small arrays.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if any `q` in
+	/// `qs` is not between `0.` and `1.` (inclusive).
+	///
+	/// [`quantiles_mut`]: #tymethod.quantiles_mut
+	/// [`par_quantiles_mut_with_threshold`]: #tymethod.par_quantiles_mut_with_threshold
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use ndarray::{array, aview1};
+	/// use ndarray_histogram::{interpolate::Linear, Quantile1dExt};
+	///
+	/// let mut data = array![3, 4, 5, 6, 7, 8];
+	/// let qs = &[0.2, 0.5, 0.8];
+	/// let quantiles = data.par_quantiles_mut(&aview1(qs), &Linear).unwrap();
+	/// assert_eq!(quantiles, data.quantiles_mut(&aview1(qs), &Linear).unwrap());
+	/// ```
+	#[cfg(feature = "rayon")]
+	fn par_quantiles_mut<S2, F, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		interpolate: &I,
+	) -> Result<Array1<A>, QuantileError<F>>
 	where
 		A: Ord + Send + Clone,
 		S: DataMut,
 		F: Float + Debug,
-		I: Interpolate<A>,
-	{
-		Ok(self
-			.quantile_axis_mut(Axis(0), q, interpolate)?
-			.into_scalar())
-	}
+		S2: Data<Elem = F>,
+		I: Interpolate<A>;
 
-	fn quantiles_mut<S2, F, I>(
+	/// Parallel version of [`quantiles_mut`] only if the array has at least `par_threshold`
+	/// elements, otherwise it falls back to serial bulk-selection.
+	///
+	/// Spawning parallel tasks does not pay off for small arrays. The crossover depends on the
+	/// element type and on the hardware, see the `par_quantiles_mut_crossover` benchmark. Both
+	/// versions return identical results.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if any `q` in
+	/// `qs` is not between `0.` and `1.` (inclusive).
+	///
+	/// [`quantiles_mut`]: #tymethod.quantiles_mut
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use ndarray::{array, aview1};
+	/// use ndarray_histogram::{interpolate::Linear, Quantile1dExt};
+	///
+	/// let mut data = array![3, 4, 5, 6, 7, 8];
+	/// let qs = &[0.2, 0.5, 0.8];
+	/// // Too small to be processed in parallel.
+	/// let quantiles = data
+	/// 	.par_quantiles_mut_with_threshold(&aview1(qs), &Linear, 10_000)
+	/// 	.unwrap();
+	/// assert_eq!(quantiles, data.quantiles_mut(&aview1(qs), &Linear).unwrap());
+	/// ```
+	#[cfg(feature = "rayon")]
+	fn par_quantiles_mut_with_threshold<S2, F, I>(
 		&mut self,
 		qs: &ArrayBase<S2, Ix1>,
 		interpolate: &I,
+		par_threshold: usize,
 	) -> Result<Array1<A>, QuantileError<F>>
 	where
 		A: Ord + Send + Clone,
 		S: DataMut,
 		F: Float + Debug,
 		S2: Data<Elem = F>,
-		I: Interpolate<A>,
-	{
-		self.quantiles_axis_mut(Axis(0), qs, interpolate)
+		I: Interpolate<A>;
+
+	/// Return the rolling quantile, i.e. the `q`th quantile of each window of `window` consecutive
+	/// elements, e.g. the rolling median for `q=0.5`.
+	///
+	/// Only complete windows are considered, i.e. the edges are not padded, hence the returned
+	/// array has `len - window + 1` elements.
+	///
+	/// Each window is copied into a buffer which is reused, the array itself is left unchanged.
+	///
+	/// Complexity: a [quickselect](https://en.wikipedia.org/wiki/Quickselect) pass per window,
+	/// where `m` is the number of elements in the array and `w` is `window`, hence
+	/// - average case: O(`m` `w`);
+	/// - worst case: O(`m` `w`^2).
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidWindow(window))` if `window` is not between `1` and the length of the
+	/// array (inclusive).
+	///
+	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Lower, n64, Quantile1dExt};
+	///
+	/// let mut a = array![1, 9, 2, 8, 3, 7];
+	/// let medians = a.rolling_quantile_mut(3, n64(0.5), &Lower);
+	/// assert_eq!(medians, Ok(array![2, 8, 3, 7]));
+	/// ```
+	fn rolling_quantile_mut<I>(
+		&mut self,
+		window: usize,
+		q: N64,
+		interpolate: &I,
+	) -> Result<Array1<A>, RollingQuantileError>
+	where
+		A: Ord + Send + Clone,
+		I: Interpolate<A>;
+
+	/// Return the mode, i.e. the most frequent value of the data.
+	///
+	/// The array is sorted **in place** in order to count runs of equal values without
+	/// allocating a copy. If multiple values are equally frequent, the smallest one is returned.
+	///
+	/// Complexity: O(`m` log `m`) where `m` is the number of elements in the array.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::Quantile1dExt;
+	///
+	/// let mut a = array![4, 1, 2, 4, 2, 4];
+	/// assert_eq!(a.mode_mut(), Ok(4));
+	/// // Ties are resolved in favor of the smallest value.
+	/// let mut a = array![3, 1, 3, 1];
+	/// assert_eq!(a.mode_mut(), Ok(1));
+	/// ```
+	fn mode_mut(&mut self) -> Result<A, EmptyInput>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut;
+
+	/// Return the percentile rank of `value`, i.e. the fraction of elements less than or equal to
+	/// `value`.
+	///
+	/// This is the inverse of [`quantile_mut`] and corresponds to the empirical cumulative
+	/// distribution function evaluated at `value`. It ranges from `0.` if `value` is below all
+	/// elements to `1.` if `value` is above or equal to all elements. Elements equal to `value`
+	/// are counted, see [`percentile_rank_strict_mut`] for excluding them.
+	///
+	/// The array is sorted **in place** in order to locate `value` without allocating a copy.
+	///
+	/// Complexity: O(`m` log `m`) where `m` is the number of elements in the array.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [`quantile_mut`]: #tymethod.quantile_mut
+	/// [`percentile_rank_strict_mut`]: #tymethod.percentile_rank_strict_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::Quantile1dExt;
+	///
+	/// let mut a = array![5, 1, 4, 2, 3, 3];
+	/// assert_eq!(a.percentile_rank_mut(&3), Ok(4. / 6.));
+	/// assert_eq!(a.percentile_rank_strict_mut(&3), Ok(2. / 6.));
+	/// ```
+	fn percentile_rank_mut(&mut self, value: &A) -> Result<f64, EmptyInput>
+	where
+		A: Ord + Send,
+		S: DataMut;
+
+	/// Return the strict percentile rank of `value`, i.e. the fraction of elements less than
+	/// `value`.
+	///
+	/// See [`percentile_rank_mut`] for details.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [`percentile_rank_mut`]: #tymethod.percentile_rank_mut
+	fn percentile_rank_strict_mut(&mut self, value: &A) -> Result<f64, EmptyInput>
+	where
+		A: Ord + Send,
+		S: DataMut;
+
+	/// Return the lower and higher values bracketing the `q`th quantile of the data.
+	///
+	/// These are the values the [interpolation strategies] consume, i.e. the elements that would be
+	/// indexed as `floor((N-1)q)` and `ceil((N-1)q)` if the array were sorted in increasing order.
+	/// They coincide if `(N-1)q` is an integer. See [`quantile_mut`] for details.
+	///
+	/// The array is shuffled **in place** in order to select the values without allocating a
+	/// copy.
+	///
+	/// Complexity: a [quickselect](https://en.wikipedia.org/wiki/Quickselect) pass where `m` is
+	/// the number of elements in the array, hence
+	/// - average case: O(`m`);
+	/// - worst case: O(`m`^2).
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+	///
+	/// [interpolation strategies]: interpolate/index.html
+	/// [`quantile_mut`]: #tymethod.quantile_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{n64, Quantile1dExt};
+	///
+	/// let mut a = array![8, 1, 4, 2];
+	/// assert_eq!(a.quantile_bracket_mut(n64(0.5)), Ok((2, 4)));
+	/// assert_eq!(a.quantile_bracket_mut(n64(1. / 3.)), Ok((2, 2)));
+	/// ```
+	fn quantile_bracket_mut(&mut self, q: N64) -> Result<(A, A), QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut;
+
+	/// Return the `q`th quantile of the data like [`quantile_mut`] together with the index of the
+	/// element it has been selected from.
+	///
+	/// The index refers to the array as it has been reordered **in place** by the selection, i.e.
+	/// the returned value is found at the returned index afterwards. This is meaningful for the
+	/// selection-based [interpolation strategies], i.e. [`Lower`], [`Higher`], and [`Nearest`].
+	/// For strategies blending two values, i.e. [`Linear`] and [`Midpoint`], the index is the one
+	/// of the lower value, followed by the higher value, whereas the interpolated value is
+	/// returned.
+	///
+	/// Complexity: a [quickselect](https://en.wikipedia.org/wiki/Quickselect) pass where `m` is
+	/// the number of elements in the array, hence
+	/// - average case: O(`m`);
+	/// - worst case: O(`m`^2).
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+	///
+	/// [interpolation strategies]: interpolate/index.html
+	/// [`quantile_mut`]: #tymethod.quantile_mut
+	/// [`Lower`]: interpolate/struct.Lower.html
+	/// [`Higher`]: interpolate/struct.Higher.html
+	/// [`Nearest`]: interpolate/struct.Nearest.html
+	/// [`Linear`]: interpolate/struct.Linear.html
+	/// [`Midpoint`]: interpolate/struct.Midpoint.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Nearest, n64, Quantile1dExt};
+	///
+	/// let mut a = array![8, 1, 4, 2, 6];
+	/// let (index, value) = a.quantile_arg_mut(n64(0.7), &Nearest)?;
+	/// assert_eq!((index, value), (3, 6));
+	/// assert_eq!(a[index], value);
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	fn quantile_arg_mut<I>(
+		&mut self,
+		q: N64,
+		interpolate: &I,
+	) -> Result<(usize, A), QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		I: Interpolate<A>;
+
+	/// Return the `q`th quantile of the data together with the lower and upper bounds of its
+	/// `confidence` interval, e.g. `0.95` for a 95% confidence interval.
+	///
+	/// The bounds are estimated by the [bootstrap] percentile method, i.e. the data is resampled
+	/// with replacement `n_boot` times and the bounds are the `(1 - confidence) / 2`th and
+	/// `(1 + confidence) / 2`th quantiles of the `q`th quantiles of the resamples. Each quantile
+	/// is computed with [`quantile_mut`] and the given interpolation strategy.
+	///
+	/// The array is shuffled **in place** when computing the point estimate, whereas each resample
+	/// is a copy. Hence, it requires O(`n_boot` × `m`) operations and O(`n_boot` + `m`) memory where
+	/// `m` is the number of elements in the array.
+	///
+	/// Requires the `rand` feature.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+	///
+	/// **Panics** if `confidence` is not between `0.` and `1.` (exclusive) or if `n_boot == 0`.
+	///
+	/// [bootstrap]: https://en.wikipedia.org/wiki/Bootstrapping_(statistics)
+	/// [`quantile_mut`]: #tymethod.quantile_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::Array1;
+	/// use ndarray_histogram::{interpolate::Nearest, n64, Quantile1dExt};
+	/// use rand::{rngs::StdRng, SeedableRng};
+	///
+	/// let mut a = Array1::from_iter(0..100);
+	/// let mut rng = StdRng::seed_from_u64(42);
+	/// let (median, lower, upper) = a.quantile_ci_mut(n64(0.5), 0.9, 200, &mut rng, &Nearest)?;
+	/// assert_eq!(median, 50);
+	/// assert!(lower < median && median < upper);
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	#[cfg(feature = "rand")]
+	fn quantile_ci_mut<R, I>(
+		&mut self,
+		q: N64,
+		confidence: f64,
+		n_boot: usize,
+		rng: &mut R,
+		interpolate: &I,
+	) -> Result<(A, A, A), QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		R: Rng,
+		I: Interpolate<A>;
+
+	/// Return the minimum and the maximum of the data, i.e. the `0.`th and `1.`th quantiles.
+	///
+	/// Both extremes are tracked simultaneously in a single pass comparing pairs of elements with
+	/// each other first, hence requiring about `3m/2` instead of `2m` comparisons where `m` is the
+	/// number of elements in the array. The array is not reordered.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::Quantile1dExt;
+	///
+	/// let mut a = array![4, 1, 8, 2, 6];
+	/// assert_eq!(a.min_max_mut(), Ok((1, 8)));
+	/// assert_eq!(a, array![4, 1, 8, 2, 6]);
+	/// ```
+	fn min_max_mut(&mut self) -> Result<(A, A), EmptyInput>
+	where
+		A: Ord + Send + Clone;
+
+	/// Return Tukey's five-number summary of the data, i.e. the minimum, the lower quartile, the
+	/// median, the upper quartile, and the maximum, e.g. to draw a box plot.
+	///
+	/// These are the `0.`th, `0.25`th, `0.5`th, `0.75`th, and `1.`th quantiles. They are selected
+	/// in a single pass of [`quantiles_mut`] sharing the partitions between the quantiles instead
+	/// of selecting each quantile from scratch. The array is shuffled **in place**.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [`quantiles_mut`]: #tymethod.quantiles_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Linear, Quantile1dExt};
+	///
+	/// let mut a = array![9, 1, 7, 3, 5];
+	/// assert_eq!(a.five_number_summary_mut(&Linear), Ok([1, 3, 5, 7, 9]));
+	/// ```
+	fn five_number_summary_mut<I>(&mut self, interpolate: &I) -> Result<[A; 5], QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		I: Interpolate<A>;
+
+	/// Return the `q`th quantile of data which is already sorted in increasing order.
+	///
+	/// The lower and higher values are indexed directly without selecting them, see
+	/// [`quantile_mut`] for the definition of the quantile. The array is not mutated.
+	///
+	/// Complexity: O(1). Debug builds verify the order in O(`m`) where `m` is the number of
+	/// elements in the array.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+	///
+	/// **Panics** in debug builds if the array is not sorted in increasing order. Otherwise, the
+	/// result is unspecified.
+	///
+	/// [`quantile_mut`]: #tymethod.quantile_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Midpoint, n64, Quantile1dExt};
+	///
+	/// let a = array![1, 2, 4, 8];
+	/// assert_eq!(a.quantile_sorted(n64(0.5), &Midpoint), Ok(3));
+	/// ```
+	fn quantile_sorted<I>(&self, q: N64, interpolate: &I) -> Result<A, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		I: Interpolate<A>;
+
+	/// A version of [`quantiles_mut`] which fully sorts the array **in place** and reads the
+	/// quantiles directly from the sorted positions, see [`quantile_sorted`].
+	///
+	/// This trades the extra work of sorting for an array left sorted in increasing order, e.g. to
+	/// be reused for plotting the empirical cumulative distribution function or for further
+	/// quantiles via [`quantile_sorted`].
+	///
+	/// Complexity: O(`m` log `m`) where `m` is the number of elements in the array.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if any `q` in `qs` is not between `0.` and `1.`
+	/// (inclusive). The array is left unchanged on error.
+	///
+	/// [`quantiles_mut`]: #tymethod.quantiles_mut
+	/// [`quantile_sorted`]: #tymethod.quantile_sorted
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Lower, n64, Quantile1dExt};
+	///
+	/// let mut a = array![8, 1, 4, 2];
+	/// let quantiles = a.sort_and_quantiles_mut(&array![n64(0.), n64(0.5)], &Lower)?;
+	/// assert_eq!(quantiles, array![1, 2]);
+	/// assert_eq!(a, array![1, 2, 4, 8]);
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	fn sort_and_quantiles_mut<S2, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		interpolate: &I,
+	) -> Result<Array1<A>, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		S2: Data<Elem = N64>,
+		I: Interpolate<A>;
+
+	/// Return the weighted `q`th quantile of the data, i.e. the smallest element whose cumulative
+	/// weight reaches `q` times the total weight.
+	///
+	/// This is the inverse of the weighted empirical cumulative distribution function. Hence, no
+	/// interpolation takes place and with equal weights it corresponds to [`Lower`] for `q=0.`
+	/// and to the `ceil(m q)`th smallest element otherwise. Elements of zero weight are ignored.
+	///
+	/// The elements are copied along with their weights and sorted, hence the array is left
+	/// unchanged.
+	///
+	/// Complexity: O(`m` log `m`) where `m` is the number of elements in the array.
+	///
+	/// Returns `Err(ShapeMismatch)` if the lengths of the array and of `weights` differ.
+	///
+	/// Returns `Err(EmptyInput)` if the total weight is zero, e.g. if the array is empty.
+	///
+	/// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+	///
+	/// **Panics** if any weight is negative or NaN.
+	///
+	/// [`Lower`]: interpolate/struct.Lower.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{errors::WeightedQuantileError, n64, Quantile1dExt};
+	///
+	/// let a = array![1, 2, 3, 4];
+	/// let weights = array![1., 1., 1., 5.];
+	/// assert_eq!(a.weighted_quantile(&weights, n64(0.5)), Ok(4));
+	/// assert_eq!(a.weighted_quantile(&weights, n64(0.25)), Ok(2));
+	/// assert!(matches!(
+	/// 	a.weighted_quantile(&array![1., 1.], n64(0.5)),
+	/// 	Err(WeightedQuantileError::ShapeMismatch(_))
+	/// ));
+	/// ```
+	fn weighted_quantile<S2>(
+		&self,
+		weights: &ArrayBase<S2, Ix1>,
+		q: N64,
+	) -> Result<A, WeightedQuantileError>
+	where
+		A: Ord + Send + Clone,
+		S2: Data<Elem = f64>;
+
+	/// Return the ranks of the elements, starting at `1.`, where ties are resolved according to
+	/// `method`.
+	///
+	/// The `i`-th rank is the one of the `i`-th element of the array, which is left untouched. This
+	/// is equivalent to SciPy's `rankdata`. The array is borrowed mutably for consistency with
+	/// [`percentile_rank_mut`] and the other rank-based methods, but any data is accepted.
+	///
+	/// Complexity: O(`m` log `m`) where `m` is the number of elements in the array.
+	///
+	/// [`percentile_rank_mut`]: #tymethod.percentile_rank_mut
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{Quantile1dExt, RankMethod};
+	///
+	/// let mut a = array![3, 1, 4, 1, 5];
+	/// assert_eq!(a.rank_mut(RankMethod::Average), array![3., 1.5, 4., 1.5, 5.]);
+	/// ```
+	fn rank_mut(&mut self, method: RankMethod) -> Array1<f64>
+	where
+		A: Ord + Send;
+
+	/// Return the [median absolute deviation] (MAD) of the data, i.e. the median of the absolute
+	/// deviations from the median.
+	///
+	/// The MAD is a robust measure of scale pairing with the median as measure of location. The
+	/// median of an even number of elements is the midpoint of the two middle elements.
+	///
+	/// The array is shuffled **in place** in order to find its median without allocating a copy.
+	/// The absolute deviations are collected into a newly allocated array of `f64` values.
+	///
+	/// Complexity: two [quickselect](https://en.wikipedia.org/wiki/Quickselect) passes, one for
+	/// the median and one for the median of the absolute deviations, where `m` is the number of
+	/// elements in the array, hence
+	/// - average case: O(`m`);
+	/// - worst case: O(`m`^2).
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::Quantile1dExt;
+	///
+	/// let mut a = array![1, 1, 2, 2, 4, 6, 9];
+	/// assert_eq!(a.mad_mut(), Ok(1.));
+	/// ```
+	fn mad_mut(&mut self) -> Result<f64, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone + ToPrimitive,
+		S: DataMut;
+
+	/// Return the [median absolute deviation] (MAD) of the data scaled by `1.4826`.
+	///
+	/// The scaled MAD is a consistent estimator of the standard deviation of normally distributed
+	/// data. See [`mad_mut`] for details.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+	/// [`mad_mut`]: #tymethod.mad_mut
+	fn scaled_mad_mut(&mut self) -> Result<f64, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone + ToPrimitive,
+		S: DataMut;
+
+	/// Return a mask flagging the outliers of the data according to [Tukey's fences], i.e. the
+	/// elements below `Q1 - k × IQR` or above `Q3 + k × IQR` where `Q1` and `Q3` are the lower and
+	/// upper quartiles and `IQR = Q3 - Q1` is the interquartile range. Tukey proposed `k = 1.5`
+	/// for outliers and `k = 3` for far out ones.
+	///
+	/// The `i`-th flag is the one of the `i`-th element. Unlike the `*_mut` methods, this takes
+	/// `&self` as selecting the quartiles in place would shuffle the data the mask refers to.
+	/// Instead, they are selected from a copy, see [`QuantileExt::quantile`], whereas the fences are
+	/// tested against the array itself which keeps its order. The fences are computed in `f64`.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// **Panics** if `k` is negative or not finite.
+	///
+	/// [Tukey's fences]: https://en.wikipedia.org/wiki/Outlier#Tukey's_fences
+	/// [`QuantileExt::quantile`]: trait.QuantileExt.html#tymethod.quantile
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::{interpolate::Linear, Quantile1dExt};
+	///
+	/// let a = array![4, -30, 5, 6, 5, 4, 50, 6];
+	/// let outliers = a.tukey_outliers(1.5, &Linear)?;
+	/// assert_eq!(
+	/// 	outliers,
+	/// 	array![false, true, false, false, false, false, true, false]
+	/// );
+	/// # Ok::<(), Box<dyn std::error::Error>>(())
+	/// ```
+	fn tukey_outliers<I>(
+		&self,
+		k: f64,
+		interpolate: &I,
+	) -> Result<Array1<bool>, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone + ToPrimitive,
+		I: Interpolate<A>;
+
+	private_decl! {}
+}
+
+impl<A, S> Quantile1dExt<A, S> for ArrayBase<S, Ix1>
+where
+	A: Send,
+	S: Data<Elem = A>,
+{
+	fn quantile_mut<F, I>(&mut self, q: F, interpolate: &I) -> Result<A, QuantileError<F>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		F: Float + Debug,
+		I: Interpolate<A>,
+	{
+		let len = self.len();
+		if len > SORT_QUANTILE_MAX_LEN {
+			return Ok(self
+				.quantile_axis_mut(Axis(0), q, interpolate)?
+				.into_scalar());
+		}
+		if !(F::from(0.).unwrap()..=F::from(1.).unwrap()).contains(&q) {
+			return Err(QuantileError::InvalidQuantile(q));
+		}
+		if len == 0 {
+			return Err(QuantileError::EmptyInput);
+		}
+		self.view_mut().sort_unstable();
+		let lower = I::needs_lower(q, len).then(|| self[lower_index(q, len)].clone());
+		let higher = I::needs_higher(q, len).then(|| self[higher_index(q, len)].clone());
+		Ok(I::interpolate(lower, higher, q, len))
+	}
+
+	fn quantiles_mut<S2, F, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		interpolate: &I,
+	) -> Result<Array1<A>, QuantileError<F>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		F: Float + Debug,
+		S2: Data<Elem = F>,
+		I: Interpolate<A>,
+	{
+		self.quantiles_axis_mut(Axis(0), qs, interpolate)
+	}
+
+	#[cfg(feature = "rayon")]
+	fn par_quantiles_mut<S2, F, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		interpolate: &I,
+	) -> Result<Array1<A>, QuantileError<F>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		F: Float + Debug,
+		S2: Data<Elem = F>,
+		I: Interpolate<A>,
+	{
+		quantiles_axis_mut(self.view_mut(), Axis(0), qs.view(), interpolate, true)
+	}
+
+	#[cfg(feature = "rayon")]
+	fn par_quantiles_mut_with_threshold<S2, F, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		interpolate: &I,
+		par_threshold: usize,
+	) -> Result<Array1<A>, QuantileError<F>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		F: Float + Debug,
+		S2: Data<Elem = F>,
+		I: Interpolate<A>,
+	{
+		let parallel = self.len() >= par_threshold;
+		quantiles_axis_mut(self.view_mut(), Axis(0), qs.view(), interpolate, parallel)
+	}
+
+	fn rolling_quantile_mut<I>(
+		&mut self,
+		window: usize,
+		q: N64,
+		interpolate: &I,
+	) -> Result<Array1<A>, RollingQuantileError>
+	where
+		A: Ord + Send + Clone,
+		I: Interpolate<A>,
+	{
+		if self.is_empty() {
+			return Err(RollingQuantileError::EmptyInput);
+		}
+		if window == 0 || window > self.len() {
+			return Err(RollingQuantileError::InvalidWindow(window));
+		}
+		let q = q.into_inner();
+		if !(0. ..=1.).contains(&q) {
+			return Err(RollingQuantileError::InvalidQuantile(q));
+		}
+		let mut buffer = self.slice(s![..window]).to_owned();
+		let quantiles = self
+			.windows(window)
+			.into_iter()
+			.map(|values| {
+				buffer.assign(&values);
+				buffer
+					.quantile_mut(q, interpolate)
+					.expect("Window is non-empty and quantile is valid")
+			})
+			.collect::<Vec<_>>();
+		Ok(Array1::from(quantiles))
+	}
+
+	fn mode_mut(&mut self) -> Result<A, EmptyInput>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+	{
+		sort_unstable(self.view_mut());
+		let mut values = self.iter();
+		let mut mode = values.next().ok_or(EmptyInput)?;
+		let mut mode_len = 1;
+		let mut run = mode;
+		let mut run_len = 1;
+		for value in values {
+			if value == run {
+				run_len += 1;
+			} else {
+				run = value;
+				run_len = 1;
+			}
+			// Strictly greater keeps the smallest value on ties.
+			if run_len > mode_len {
+				mode = run;
+				mode_len = run_len;
+			}
+		}
+		Ok(mode.clone())
+	}
+
+	fn percentile_rank_mut(&mut self, value: &A) -> Result<f64, EmptyInput>
+	where
+		A: Ord + Send,
+		S: DataMut,
+	{
+		if self.is_empty() {
+			return Err(EmptyInput);
+		}
+		sort_unstable(self.view_mut());
+		let rank = partition_point(self.view(), |elem| elem <= value);
+		Ok(rank as f64 / self.len() as f64)
+	}
+
+	fn percentile_rank_strict_mut(&mut self, value: &A) -> Result<f64, EmptyInput>
+	where
+		A: Ord + Send,
+		S: DataMut,
+	{
+		if self.is_empty() {
+			return Err(EmptyInput);
+		}
+		sort_unstable(self.view_mut());
+		let rank = partition_point(self.view(), |elem| elem < value);
+		Ok(rank as f64 / self.len() as f64)
+	}
+
+	fn min_max_mut(&mut self) -> Result<(A, A), EmptyInput>
+	where
+		A: Ord + Send + Clone,
+	{
+		let mut iter = self.iter();
+		let first = iter.next().ok_or(EmptyInput)?;
+		let (mut min, mut max) = (first, first);
+		while let Some(a) = iter.next() {
+			let (lower, higher) = match iter.next() {
+				Some(b) if b < a => (b, a),
+				Some(b) => (a, b),
+				None => (a, a),
+			};
+			min = min.min(lower);
+			max = max.max(higher);
+		}
+		Ok((min.clone(), max.clone()))
+	}
+
+	fn five_number_summary_mut<I>(&mut self, interpolate: &I) -> Result<[A; 5], QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		I: Interpolate<A>,
+	{
+		let summary = self.quantiles_mut(&aview1(&[0., 0.25, 0.5, 0.75, 1.]), interpolate)?;
+		Ok(core::array::from_fn(|i| summary[i].clone()))
+	}
+
+	fn quantile_bracket_mut(&mut self, q: N64) -> Result<(A, A), QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+	{
+		let q = q.into_inner();
+		if !(0.0..=1.0).contains(&q) {
+			return Err(QuantileError::InvalidQuantile(q));
+		}
+		if self.is_empty() {
+			return Err(QuantileError::EmptyInput);
+		}
+		let len = self.len();
+		let (lower_index, higher_index) = (lower_index(q, len), higher_index(q, len));
+		let mut data = self.view_mut();
+		let (_, lower, higher) = data.select_nth_unstable(lower_index);
+		let lower = lower.clone();
+		if lower_index == higher_index {
+			Ok((lower.clone(), lower))
+		} else {
+			// The higher value is the smallest one right of the lower value.
+			Ok((lower, higher.iter().min().unwrap().clone()))
+		}
+	}
+
+	fn quantile_arg_mut<I>(
+		&mut self,
+		q: N64,
+		_interpolate: &I,
+	) -> Result<(usize, A), QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		I: Interpolate<A>,
+	{
+		let q = q.into_inner();
+		if !(0.0..=1.0).contains(&q) {
+			return Err(QuantileError::InvalidQuantile(q));
+		}
+		if self.is_empty() {
+			return Err(QuantileError::EmptyInput);
+		}
+		let len = self.len();
+		let (lower_index, higher_index) = (lower_index(q, len), higher_index(q, len));
+		let needs_lower = I::needs_lower(q, len);
+		let index = if needs_lower {
+			lower_index
+		} else {
+			higher_index
+		};
+		let mut data = self.view_mut();
+		let (_, value, mut right) = data.select_nth_unstable(index);
+		let value = value.clone();
+		let higher = I::needs_higher(q, len).then(|| {
+			if higher_index == index {
+				value.clone()
+			} else {
+				// Move the higher value, i.e. the smallest one right of the lower value, next to
+				// the lower value so the array is partitioned around both of them.
+				let (min_index, _) = right
+					.iter()
+					.enumerate()
+					.min_by(|a, b| a.1.cmp(b.1))
+					.unwrap();
+				right.swap(0, min_index);
+				right[0].clone()
+			}
+		});
+		let lower = needs_lower.then(|| value.clone());
+		Ok((index, I::interpolate(lower, higher, q, len)))
+	}
+
+	#[cfg(feature = "rand")]
+	fn quantile_ci_mut<R, I>(
+		&mut self,
+		q: N64,
+		confidence: f64,
+		n_boot: usize,
+		rng: &mut R,
+		interpolate: &I,
+	) -> Result<(A, A, A), QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		R: Rng,
+		I: Interpolate<A>,
+	{
+		assert!(
+			confidence > 0. && confidence < 1.,
+			"Confidence must be between 0 and 1 (exclusive)"
+		);
+		assert!(n_boot > 0, "Number of resamples must be positive");
+		let q = q.into_inner();
+		let estimate = self.quantile_mut(q, interpolate)?;
+		let len = self.len();
+		let mut resample = Array1::from_elem(len, estimate.clone());
+		let mut estimates = Vec::with_capacity(n_boot);
+		for _ in 0..n_boot {
+			for value in &mut resample {
+				value.clone_from(&self[rng.gen_range(0..len)]);
+			}
+			estimates.push(resample.quantile_mut(q, interpolate)?);
+		}
+		let mut estimates = Array1::from(estimates);
+		let lower = estimates.quantile_mut((1. - confidence) / 2., interpolate)?;
+		let upper = estimates.quantile_mut((1. + confidence) / 2., interpolate)?;
+		Ok((estimate, lower, upper))
+	}
+
+	fn sort_and_quantiles_mut<S2, I>(
+		&mut self,
+		qs: &ArrayBase<S2, Ix1>,
+		_interpolate: &I,
+	) -> Result<Array1<A>, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		S: DataMut,
+		S2: Data<Elem = N64>,
+		I: Interpolate<A>,
+	{
+		if let Some(q) = qs.iter().find(|q| !(0.0..=1.0).contains(&q.into_inner())) {
+			return Err(QuantileError::InvalidQuantile(q.into_inner()));
+		}
+		if self.is_empty() {
+			return Err(QuantileError::EmptyInput);
+		}
+		self.view_mut().sort_unstable();
+		let len = self.len();
+		Ok(qs.mapv(|q| {
+			let q = q.into_inner();
+			let lower = I::needs_lower(q, len).then(|| self[lower_index(q, len)].clone());
+			let higher = I::needs_higher(q, len).then(|| self[higher_index(q, len)].clone());
+			I::interpolate(lower, higher, q, len)
+		}))
+	}
+
+	fn quantile_sorted<I>(&self, q: N64, _interpolate: &I) -> Result<A, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone,
+		I: Interpolate<A>,
+	{
+		debug_assert!(
+			self.windows(2).into_iter().all(|pair| pair[0] <= pair[1]),
+			"Array is not sorted in increasing order"
+		);
+		let q = q.into_inner();
+		if !(0.0..=1.0).contains(&q) {
+			return Err(QuantileError::InvalidQuantile(q));
+		}
+		if self.is_empty() {
+			return Err(QuantileError::EmptyInput);
+		}
+		let len = self.len();
+		let lower = I::needs_lower(q, len).then(|| self[lower_index(q, len)].clone());
+		let higher = I::needs_higher(q, len).then(|| self[higher_index(q, len)].clone());
+		Ok(I::interpolate(lower, higher, q, len))
+	}
+
+	fn weighted_quantile<S2>(
+		&self,
+		weights: &ArrayBase<S2, Ix1>,
+		q: N64,
+	) -> Result<A, WeightedQuantileError>
+	where
+		A: Ord + Send + Clone,
+		S2: Data<Elem = f64>,
+	{
+		if self.len() != weights.len() {
+			return Err(ShapeMismatch {
+				first_shape: self.shape().to_vec(),
+				second_shape: weights.shape().to_vec(),
+			}
+			.into());
+		}
+		if !(0.0..=1.0).contains(&q.into_inner()) {
+			return Err(WeightedQuantileError::InvalidQuantile(q.into_inner()));
+		}
+		assert!(
+			weights.iter().all(|&weight| weight >= 0.),
+			"Weights must be non-negative"
+		);
+		let mut pairs = self
+			.iter()
+			.cloned()
+			.zip(weights.iter().copied())
+			.filter(|&(_, weight)| weight > 0.)
+			.collect::<Vec<_>>();
+		if pairs.is_empty() {
+			return Err(WeightedQuantileError::EmptyInput);
+		}
+		pairs.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+		let total = pairs.iter().map(|&(_, weight)| weight).sum::<f64>();
+		Ok(weighted_quantile(&pairs, total, q.into_inner()))
+	}
+
+	fn rank_mut(&mut self, method: RankMethod) -> Array1<f64>
+	where
+		A: Ord + Send,
+	{
+		let mut order = (0..self.len()).collect::<Vec<_>>();
+		// Stable in order to keep ties in order of appearance for `RankMethod::Ordinal`.
+		order.sort_by(|&i, &j| self[i].cmp(&self[j]));
+		let mut ranks = Array1::zeros(self.len());
+		let mut dense = 0;
+		let mut start = 0;
+		while start < order.len() {
+			let end = start
+				+ order[start..]
+					.iter()
+					.take_while(|&&i| self[i] == self[order[start]])
+					.count();
+			dense += 1;
+			for (position, &i) in order.iter().enumerate().take(end).skip(start) {
+				ranks[i] = match method {
+					RankMethod::Average => (start + end + 1) as f64 / 2.,
+					RankMethod::Min => (start + 1) as f64,
+					RankMethod::Max => end as f64,
+					RankMethod::Dense => f64::from(dense),
+					RankMethod::Ordinal => (position + 1) as f64,
+				};
+			}
+			start = end;
+		}
+		ranks
+	}
+
+	fn mad_mut(&mut self) -> Result<f64, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone + ToPrimitive,
+		S: DataMut,
+	{
+		let median = median_f64(self.view_mut())?;
+		let mut deviations = self.mapv(|x| O64::from((x.to_f64().unwrap() - median).abs()));
+		Ok(median_f64(deviations.view_mut())?)
+	}
+
+	fn scaled_mad_mut(&mut self) -> Result<f64, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone + ToPrimitive,
+		S: DataMut,
+	{
+		// Reciprocal of the standard normal distribution's third quartile.
+		Ok(1.4826 * self.mad_mut()?)
+	}
+
+	fn tukey_outliers<I>(&self, k: f64, interpolate: &I) -> Result<Array1<bool>, QuantileError<f64>>
+	where
+		A: Ord + Send + Clone + ToPrimitive,
+		I: Interpolate<A>,
+	{
+		assert!(
+			k >= 0. && k.is_finite(),
+			"Fence factor must be non-negative and finite"
+		);
+		let quartiles = self
+			.to_owned()
+			.quantiles_mut(&aview1(&[0.25, 0.75]), interpolate)?;
+		let (q1, q3) = (
+			quartiles[0].to_f64().unwrap(),
+			quartiles[1].to_f64().unwrap(),
+		);
+		let iqr = q3 - q1;
+		let (lower, upper) = (q1 - k * iqr, q3 + k * iqr);
+		Ok(self.mapv(|value| {
+			let value = value.to_f64().unwrap();
+			value < lower || value > upper
+		}))
 	}
 
 	private_impl! {}
 }
 
+mod geometric_median;
 pub mod interpolate;
+mod kde;
+mod running;
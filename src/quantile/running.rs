@@ -0,0 +1,96 @@
+/// Running statistics of a stream of observations in constant memory.
+///
+/// The mean and variance are updated in `f64` precision with [Welford's algorithm] which avoids the
+/// catastrophic cancellation of the naive `mean(x^2) - mean(x)^2`. The results match the batch
+/// statistics of [`QuantileExt`], i.e. [`mean`], [`var`], and [`std`], as well as [`min`] and
+/// [`max`].
+///
+/// NaN observations propagate to the mean and variance whereas they are ignored by the minimum and
+/// maximum.
+///
+/// [Welford's algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+/// [`QuantileExt`]: trait.QuantileExt.html
+/// [`mean`]: trait.QuantileExt.html#tymethod.mean
+/// [`var`]: trait.QuantileExt.html#tymethod.var
+/// [`std`]: trait.QuantileExt.html#tymethod.std
+/// [`min`]: trait.QuantileExt.html#tymethod.min
+/// [`max`]: trait.QuantileExt.html#tymethod.max
+///
+/// # Example
+///
+/// ```
+/// use ndarray_histogram::RunningStats;
+///
+/// let mut stats = RunningStats::new();
+/// for x in [2., 4., 4., 4., 5., 5., 7., 9.] {
+/// 	stats.update(x);
+/// }
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(5.));
+/// assert_eq!(stats.var(0), Some(4.));
+/// assert_eq!(stats.std(0), Some(2.));
+/// assert_eq!((stats.min(), stats.max()), (Some(2.), Some(9.)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunningStats {
+	count: usize,
+	mean: f64,
+	sum_sq: f64,
+	min: Option<f64>,
+	max: Option<f64>,
+}
+
+impl RunningStats {
+	/// Returns running statistics without any observations.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds the observation `x`.
+	pub fn update(&mut self, x: f64) {
+		self.count += 1;
+		let delta = x - self.mean;
+		self.mean += delta / self.count as f64;
+		self.sum_sq += delta * (x - self.mean);
+		self.min = Some(self.min.map_or(x, |min| min.min(x)));
+		self.max = Some(self.max.map_or(x, |max| max.max(x)));
+	}
+
+	/// Returns the number of observations.
+	pub fn count(&self) -> usize {
+		self.count
+	}
+
+	/// Returns the arithmetic mean of the observations, or `None` if there are none.
+	pub fn mean(&self) -> Option<f64> {
+		(self.count > 0).then_some(self.mean)
+	}
+
+	/// Returns the variance of the observations with `ddof` delta degrees of freedom, i.e.
+	/// `sum((x - mean)^2) / (n - ddof)` where `n` is the number of observations.
+	///
+	/// Returns `None` if `ddof >= n`.
+	pub fn var(&self, ddof: usize) -> Option<f64> {
+		(ddof < self.count).then(|| self.sum_sq / (self.count - ddof) as f64)
+	}
+
+	/// Returns the standard deviation of the observations with `ddof` delta degrees of freedom,
+	/// i.e. the square root of [`var`].
+	///
+	/// Returns `None` if `ddof >= n` where `n` is the number of observations.
+	///
+	/// [`var`]: #method.var
+	pub fn std(&self, ddof: usize) -> Option<f64> {
+		self.var(ddof).map(f64::sqrt)
+	}
+
+	/// Returns the minimum of the observations, or `None` if there are none.
+	pub fn min(&self) -> Option<f64> {
+		self.min
+	}
+
+	/// Returns the maximum of the observations, or `None` if there are none.
+	pub fn max(&self) -> Option<f64> {
+		self.max
+	}
+}
@@ -0,0 +1,323 @@
+use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::ToPrimitive;
+
+/// Summary statistics methods for `ArrayBase`.
+///
+/// The statistics are computed in `f64` precision over all elements of the array in logical order
+/// and do not require the array to be mutable.
+pub trait SummaryStatisticsExt<A, S, D>
+where
+	S: Data<Elem = A>,
+	D: Dimension,
+{
+	/// Returns the [geometric mean] `exp(mean(ln(x)))` of all elements in the array.
+	///
+	/// Returns `Ok(NaN)` if any element is non-positive.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [geometric mean]: https://en.wikipedia.org/wiki/Geometric_mean
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::SummaryStatisticsExt;
+	///
+	/// assert_eq!(array![1, 4].geometric_mean(), Ok(2.));
+	/// assert!(array![1, 0].geometric_mean().unwrap().is_nan());
+	/// ```
+	fn geometric_mean(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive;
+
+	/// Returns the [harmonic mean] `1 / mean(1 / x)` of all elements in the array.
+	///
+	/// Returns `Ok(NaN)` if any element is non-positive.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [harmonic mean]: https://en.wikipedia.org/wiki/Harmonic_mean
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::SummaryStatisticsExt;
+	///
+	/// assert_eq!(array![1, 4, 4].harmonic_mean(), Ok(2.));
+	/// ```
+	fn harmonic_mean(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive;
+
+	/// Returns the [mean absolute deviation] `mean(|x - mean(x)|)` of all elements in the array
+	/// about their mean.
+	///
+	/// Unlike the standard deviation, the deviations are not squared, hence outliers weigh less.
+	/// The mean is computed in a first pass and the deviations in a second one.
+	///
+	/// Returns `Err(EmptyInput)` if the array is empty.
+	///
+	/// [mean absolute deviation]: https://en.wikipedia.org/wiki/Average_absolute_deviation
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::SummaryStatisticsExt;
+	///
+	/// assert_eq!(array![2, 4, 4, 4, 5, 5, 7, 9].mean_abs_deviation(), Ok(1.5));
+	/// ```
+	fn mean_abs_deviation(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive;
+
+	/// Returns the bias-corrected sample [skewness] `G1` of all elements in the array.
+	///
+	/// Let `n` be the number of elements and `g1 = m3 / m2^(3/2)` the biased skewness where `mk` is
+	/// the `k`th central moment. Then
+	///
+	/// `G1` = `g1` × √(`n`(`n` − 1)) / (`n` − 2)
+	///
+	/// which is the adjusted Fisher–Pearson coefficient also used by spreadsheet software.
+	///
+	/// Returns `Ok(NaN)` if all elements are equal.
+	///
+	/// Returns `Err(EmptyInput)` if the array has less than 3 elements.
+	///
+	/// [skewness]: https://en.wikipedia.org/wiki/Skewness#Sample_skewness
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::SummaryStatisticsExt;
+	///
+	/// assert_eq!(array![1, 2, 3].skewness(), Ok(0.));
+	/// assert!(array![1, 2, 9].skewness().unwrap() > 0.);
+	/// assert!(array![1, 2].skewness().is_err());
+	/// ```
+	fn skewness(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive;
+
+	/// Returns the bias-corrected sample excess [kurtosis] `G2` of all elements in the array.
+	///
+	/// Let `n` be the number of elements and `g2 = m4 / m2^2 - 3` the biased excess kurtosis where
+	/// `mk` is the `k`th central moment. Then
+	///
+	/// `G2` = (`n` − 1) / ((`n` − 2)(`n` − 3)) × ((`n` + 1)`g2` + 6)
+	///
+	/// which is the estimator also used by spreadsheet software.
+	///
+	/// Returns `Ok(NaN)` if all elements are equal.
+	///
+	/// Returns `Err(EmptyInput)` if the array has less than 4 elements.
+	///
+	/// [kurtosis]: https://en.wikipedia.org/wiki/Kurtosis#Standard_unbiased_estimator
+	fn kurtosis(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive;
+
+	/// Returns the weighted variance of all elements in the array with `ddof` delta degrees of
+	/// freedom, treating `weights` of the same shape as reliability weights.
+	///
+	/// Let `V1` be the total weight, `m` the weighted mean, and `n_eff = V1^2 / V2` the effective
+	/// sample size where `V2` is the sum of the squared weights. Then the variance is
+	///
+	/// Σ `w_i`(`x_i` − `m`)² / `V1` × `n_eff` / (`n_eff` − `ddof`)
+	///
+	/// which reduces to [`QuantileExt::var`] for equal weights. For `ddof = 1`, it is the unbiased
+	/// estimator Σ `w_i`(`x_i` − `m`)² / (`V1` − `V2` / `V1`).
+	///
+	/// Returns `Err(ShapeMismatch)` if the shapes of the array and of `weights` differ.
+	///
+	/// Returns `Err(InvalidWeights)` if any weight is negative or NaN.
+	///
+	/// Returns `Err(EmptyInput)` if the total weight is zero, e.g. if the array is empty, or if
+	/// `ddof >= n_eff`.
+	///
+	/// [`QuantileExt::var`]: trait.QuantileExt.html#tymethod.var
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ndarray::array;
+	/// use ndarray_histogram::SummaryStatisticsExt;
+	///
+	/// let a = array![1, 3, 5];
+	/// assert_eq!(a.weighted_var(&array![1., 2., 1.], 0), Ok(2.));
+	/// assert_eq!(a.weighted_std(&array![2., 2., 2.], 1), Ok(2.));
+	/// ```
+	fn weighted_var<S2>(
+		&self,
+		weights: &ArrayBase<S2, D>,
+		ddof: usize,
+	) -> Result<f64, MultiInputError>
+	where
+		A: ToPrimitive,
+		S2: Data<Elem = f64>;
+
+	/// Returns the weighted standard deviation of all elements in the array with `ddof` delta
+	/// degrees of freedom, i.e. the square root of [`weighted_var`].
+	///
+	/// Returns `Err(ShapeMismatch)` if the shapes of the array and of `weights` differ.
+	///
+	/// Returns `Err(InvalidWeights)` if any weight is negative or NaN.
+	///
+	/// Returns `Err(EmptyInput)` if the total weight is zero or if `ddof` is not less than the
+	/// effective sample size.
+	///
+	/// [`weighted_var`]: #tymethod.weighted_var
+	fn weighted_std<S2>(
+		&self,
+		weights: &ArrayBase<S2, D>,
+		ddof: usize,
+	) -> Result<f64, MultiInputError>
+	where
+		A: ToPrimitive,
+		S2: Data<Elem = f64>;
+
+	private_decl! {}
+}
+
+impl<A, S, D> SummaryStatisticsExt<A, S, D> for ArrayBase<S, D>
+where
+	S: Data<Elem = A>,
+	D: Dimension,
+{
+	fn geometric_mean(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive,
+	{
+		mean_of(self, |x| if x > 0. { x.ln() } else { f64::NAN }).map(f64::exp)
+	}
+
+	fn harmonic_mean(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive,
+	{
+		mean_of(self, |x| if x > 0. { x.recip() } else { f64::NAN }).map(f64::recip)
+	}
+
+	fn mean_abs_deviation(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive,
+	{
+		let mean = mean_of(self, |x| x)?;
+		mean_of(self, |x| (x - mean).abs())
+	}
+
+	fn skewness(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive,
+	{
+		if self.len() < 3 {
+			return Err(EmptyInput);
+		}
+		let n = self.len() as f64;
+		let [m2, m3, _m4] = central_moments(self);
+		let g1 = m3 / m2.powf(1.5);
+		Ok(g1 * (n * (n - 1.)).sqrt() / (n - 2.))
+	}
+
+	fn kurtosis(&self) -> Result<f64, EmptyInput>
+	where
+		A: ToPrimitive,
+	{
+		if self.len() < 4 {
+			return Err(EmptyInput);
+		}
+		let n = self.len() as f64;
+		let [m2, _m3, m4] = central_moments(self);
+		let g2 = m4 / (m2 * m2) - 3.;
+		Ok((n - 1.) / ((n - 2.) * (n - 3.)) * ((n + 1.) * g2 + 6.))
+	}
+
+	fn weighted_var<S2>(
+		&self,
+		weights: &ArrayBase<S2, D>,
+		ddof: usize,
+	) -> Result<f64, MultiInputError>
+	where
+		A: ToPrimitive,
+		S2: Data<Elem = f64>,
+	{
+		if self.shape() != weights.shape() {
+			return Err(ShapeMismatch {
+				first_shape: self.shape().to_vec(),
+				second_shape: weights.shape().to_vec(),
+			}
+			.into());
+		}
+		if !weights.iter().all(|&weight| weight >= 0.) {
+			return Err(MultiInputError::InvalidWeights);
+		}
+		let v1 = weights.sum();
+		if v1 == 0. {
+			return Err(MultiInputError::EmptyInput);
+		}
+		let v2 = weights.iter().map(|weight| weight * weight).sum::<f64>();
+		let n_eff = v1 * v1 / v2;
+		if n_eff <= ddof as f64 {
+			return Err(MultiInputError::EmptyInput);
+		}
+		let pairs = || self.iter().map(|x| x.to_f64().unwrap()).zip(weights);
+		let mean = pairs().map(|(x, weight)| weight * x).sum::<f64>() / v1;
+		let sum_sq = pairs()
+			.map(|(x, weight)| weight * (x - mean).powi(2))
+			.sum::<f64>();
+		Ok(sum_sq / v1 * n_eff / (n_eff - ddof as f64))
+	}
+
+	fn weighted_std<S2>(
+		&self,
+		weights: &ArrayBase<S2, D>,
+		ddof: usize,
+	) -> Result<f64, MultiInputError>
+	where
+		A: ToPrimitive,
+		S2: Data<Elem = f64>,
+	{
+		self.weighted_var(weights, ddof).map(f64::sqrt)
+	}
+
+	private_impl! {}
+}
+
+/// Returns the mean of `f` applied to all elements converted to `f64`.
+fn mean_of<A, S, D>(array: &ArrayBase<S, D>, f: impl Fn(f64) -> f64) -> Result<f64, EmptyInput>
+where
+	A: ToPrimitive,
+	S: Data<Elem = A>,
+	D: Dimension,
+{
+	if array.is_empty() {
+		return Err(EmptyInput);
+	}
+	let sum = array.iter().map(|x| f(x.to_f64().unwrap())).sum::<f64>();
+	Ok(sum / array.len() as f64)
+}
+
+/// Returns the 2nd, 3rd, and 4th central moments of the non-empty array in two passes.
+fn central_moments<A, S, D>(array: &ArrayBase<S, D>) -> [f64; 3]
+where
+	A: ToPrimitive,
+	S: Data<Elem = A>,
+	D: Dimension,
+{
+	let n = array.len() as f64;
+	let mean = array.iter().map(|x| x.to_f64().unwrap()).sum::<f64>() / n;
+	let mut moments = [0.; 3];
+	for x in array {
+		let d = x.to_f64().unwrap() - mean;
+		let d2 = d * d;
+		moments[0] += d2;
+		moments[1] += d2 * d;
+		moments[2] += d2 * d2;
+	}
+	moments.map(|moment| moment / n)
+}
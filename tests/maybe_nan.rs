@@ -1,5 +1,62 @@
+#![cfg(feature = "std")]
+
 use ndarray::prelude::*;
-use ndarray_histogram::{n64, MaybeNan, N64};
+use ndarray_histogram::{
+	errors::{NanFound, NotFinite, QuantileError},
+	f32_array, f64_array,
+	interpolate::{Higher, Linear},
+	n32_array, n64, n64_array, o32, o32_array, o64, o64_array, try_o32, try_o64, MaybeNan,
+	MaybeNanExt, N64,
+};
+
+#[test]
+fn n64_array_round_trip() {
+	let a = array![[1., -2.5, 3.], [0., 5., 1e9]];
+	let n = n64_array(&a).unwrap();
+	assert_eq!(n.shape(), a.shape());
+	assert_eq!(n[[0, 1]], n64(-2.5));
+	assert_eq!(f64_array(&n), a);
+	let a = array![1f32, 2., 3.];
+	assert_eq!(f32_array(&n32_array(&a).unwrap()), a);
+}
+
+#[test]
+fn n64_array_with_nan() {
+	let a = array![[1., 2.], [f64::NAN, 4.]];
+	assert_eq!(n64_array(&a), Err(NanFound));
+	let a = array![f32::NAN];
+	assert_eq!(n32_array(&a), Err(NanFound));
+}
+
+#[test]
+fn try_o64_accepts_finite_numbers() {
+	assert_eq!(try_o64(-2.5), Ok(o64(-2.5)));
+	assert_eq!(try_o64(f64::MAX).map(|num| num.into_inner()), Ok(f64::MAX));
+	assert_eq!(try_o32(0.), Ok(o32(0.)));
+}
+
+#[test]
+fn try_o64_rejects_nan_and_infinity() {
+	assert_eq!(try_o64(f64::NAN), Err(NotFinite));
+	assert_eq!(try_o64(f64::INFINITY), Err(NotFinite));
+	assert_eq!(try_o64(f64::NEG_INFINITY), Err(NotFinite));
+	assert_eq!(try_o32(f32::NAN), Err(NotFinite));
+	assert_eq!(try_o32(f32::INFINITY), Err(NotFinite));
+}
+
+#[test]
+fn o64_array_round_trip() {
+	let a = array![[1., f64::NAN], [3., -4.]];
+	let o = o64_array(&a);
+	assert_eq!(o[[1, 1]], o64(-4.));
+	assert!(o[[0, 1]].is_nan());
+	let b = f64_array(&o);
+	assert_eq!(b[[1, 0]], 3.);
+	assert!(b[[0, 1]].is_nan());
+	let a = array![1f32, -2.];
+	assert_eq!(o32_array(&a), array![o32(1.), o32(-2.)]);
+	assert_eq!(f32_array(&o32_array(&a)), a);
+}
 
 #[test]
 fn remove_nan_mut_nonstandard_layout() {
@@ -28,3 +85,26 @@ fn remove_nan_mut_nonstandard_layout() {
 		assert!(eq_unordered(v.to_vec(), vec![n64(5.), n64(2.)]));
 	}
 }
+
+#[test]
+fn nan_quantile_axis_mut_skips_nan_per_row() {
+	let mut a = array![
+		[4., 1., 3., 2.],
+		[f64::NAN, 5., 1., f64::NAN],
+		[f64::NAN, f64::NAN, 7., f64::NAN],
+		[f64::NAN, f64::NAN, f64::NAN, f64::NAN],
+	];
+	let medians = a.nan_quantile_axis_mut(Axis(1), n64(0.5), &Linear).unwrap();
+	assert_eq!(medians.shape(), &[4]);
+	assert_eq!(medians[[0]], 2.5);
+	assert_eq!(medians[[1]], 3.);
+	assert_eq!(medians[[2]], 7.);
+	assert!(medians[[3]].is_nan());
+	let maxima = a.nan_quantile_axis_mut(Axis(1), n64(1.), &Higher).unwrap();
+	assert_eq!(maxima.slice(s![..3]), array![4., 5., 7.]);
+	let mut a = Array2::<f64>::zeros((2, 0));
+	assert_eq!(
+		a.nan_quantile_axis_mut(Axis(1), n64(0.5), &Linear),
+		Err(QuantileError::EmptyInput)
+	);
+}
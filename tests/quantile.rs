@@ -1,10 +1,17 @@
+#![cfg(feature = "std")]
+
 use itertools::izip;
 use ndarray::array;
 use ndarray::prelude::*;
 use ndarray_histogram::{
-	errors::{EmptyInput, MinMaxError, QuantileError},
-	interpolate::{Higher, Interpolate, Linear, Lower, Midpoint, Nearest},
-	o64, Quantile1dExt, QuantileExt, O64,
+	errors::{
+		EmptyInput, KdeError, MinMaxError, QuantileError, RollingQuantileError, ShapeMismatch,
+		WeightedQuantileError,
+	},
+	gaussian_kde,
+	interpolate::{resolve, Higher, Interpolate, Linear, Lower, Midpoint, Nearest},
+	n64, o64, Bandwidth, GeometricMedianExt, Quantile1dExt, QuantileExt, RankMethod, RunningStats,
+	N64, O64,
 };
 use quickcheck::TestResult;
 use quickcheck_macros::quickcheck;
@@ -172,6 +179,62 @@ fn test_max_skipnan_all_nan() {
 	assert!(a.max_skipnan().is_nan());
 }
 
+#[test]
+fn test_peak_to_peak() {
+	let a = array![4, -2, 9, 3];
+	assert_eq!(a.peak_to_peak(), Ok(11));
+
+	let a = array![[1., 5., 7.], [2., -3., 6.]];
+	assert_eq!(a.peak_to_peak(), Ok(10.));
+
+	let a = array![[1., 5., 7.], [2., ::std::f64::NAN, 6.]];
+	assert_eq!(a.peak_to_peak(), Err(MinMaxError::UndefinedOrder));
+
+	let a: Array2<i32> = array![[], []];
+	assert_eq!(a.peak_to_peak(), Err(MinMaxError::EmptyInput));
+}
+
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn peak_to_peak_matches_max_minus_min(data: Vec<i32>) -> bool {
+	let a = Array1::from(data).mapv(i64::from);
+	match (a.max(), a.min()) {
+		(Ok(max), Ok(min)) => a.peak_to_peak() == Ok(max - min),
+		_ => a.peak_to_peak() == Err(MinMaxError::EmptyInput),
+	}
+}
+
+#[test]
+fn test_mean_var_std() {
+	let a = array![[1, 2], [3, 6]];
+	assert_eq!(QuantileExt::mean(&a), Some(3));
+	assert_eq!(QuantileExt::var(&a, 0), Some(3.5));
+	assert_eq!(QuantileExt::var(&a, 1), Some(14. / 3.));
+	assert_eq!(QuantileExt::std(&a, 0), Some(3.5_f64.sqrt()));
+	let a = array![o64(1.), o64(2.)];
+	assert_eq!(QuantileExt::mean(&a), Some(o64(1.5)));
+}
+
+#[test]
+fn test_mean_var_std_with_too_few_elements() {
+	let a = Array1::<i32>::zeros(0);
+	assert_eq!(QuantileExt::mean(&a), None);
+	assert_eq!(QuantileExt::var(&a, 0), None);
+	assert_eq!(QuantileExt::std(&a, 0), None);
+	let a = array![1, 2];
+	assert_eq!(QuantileExt::var(&a, 2), None);
+	assert_eq!(QuantileExt::std(&a, 3), None);
+}
+
+#[test]
+fn test_var_of_large_magnitude_array() {
+	// The naive `mean(x^2) - mean(x)^2` loses all significant digits.
+	let a = array![4., 7., 13., 16.].mapv(|x: f64| x + 1e9);
+	assert_eq!(QuantileExt::var(&a, 1), Some(30.));
+	let naive = a.mapv(|x| x * x).sum() / 4. - (a.sum() / 4.).powi(2);
+	assert_ne!(naive * 4. / 3., 30.);
+}
+
 #[cfg_attr(miri, ignore)]
 #[test]
 fn test_quantile_mut_with_large_array_of_equal_floats() {
@@ -247,6 +310,21 @@ fn test_quantile_axis_mut_with_even_axis_length() {
 	assert!(q == b.index_axis(Axis(0), 1));
 }
 
+#[test]
+fn test_quantile_axis_mut_row_and_column_medians() {
+	let a = arr2(&[[1., 3., 2., 10.], [4., 8., 6., 0.], [7., 5., 9., 11.]]).mapv(n64);
+	let row_medians: Array1<N64> = a
+		.clone()
+		.quantile_axis_mut(Axis(1), o64(0.5), &Linear)
+		.unwrap();
+	assert_eq!(row_medians, arr1(&[2.5, 5., 8.]).mapv(n64));
+	let column_medians: Array1<N64> = a
+		.clone()
+		.quantile_axis_mut(Axis(0), o64(0.5), &Linear)
+		.unwrap();
+	assert_eq!(column_medians, arr1(&[4., 5., 6., 10.]).mapv(n64));
+}
+
 #[test]
 fn test_quantile_axis_mut_to_get_minimum() {
 	let mut b = arr2(&[[1, 3, 22, 10]]);
@@ -331,6 +409,502 @@ fn test_midpoint_overflow() {
 	assert_eq!(median, expected_median);
 }
 
+#[test]
+fn test_mode_mut() {
+	let mut a = array![7, 3, 5, 3, 9, 3, 5, 1];
+	assert_eq!(a.mode_mut(), Ok(3));
+}
+
+#[test]
+fn test_mode_mut_with_uniform_array() {
+	let mut a = array![4, 2, 8, 6];
+	assert_eq!(a.mode_mut(), Ok(2));
+}
+
+#[test]
+fn test_mode_mut_with_empty_array() {
+	let mut a = Array1::<i32>::zeros(0);
+	assert_eq!(a.mode_mut(), Err(EmptyInput));
+}
+
+#[test]
+fn test_rank_mut() {
+	let a = array![40, 10, 20, 20, 30, 10, 20];
+	for (method, expected) in [
+		(RankMethod::Average, array![7., 1.5, 4., 4., 6., 1.5, 4.]),
+		(RankMethod::Min, array![7., 1., 3., 3., 6., 1., 3.]),
+		(RankMethod::Max, array![7., 2., 5., 5., 6., 2., 5.]),
+		(RankMethod::Dense, array![4., 1., 2., 2., 3., 1., 2.]),
+		(RankMethod::Ordinal, array![7., 1., 3., 4., 6., 2., 5.]),
+	] {
+		let mut b = a.clone();
+		assert_eq!(b.rank_mut(method), expected, "{:?}", method);
+		assert_eq!(b, a);
+	}
+	let mut a = Array1::<i32>::zeros(0);
+	assert_eq!(a.rank_mut(RankMethod::Average), Array1::<f64>::zeros(0));
+	// Views of immutable data are accepted.
+	let a = array![3, 1, 4, 1, 5];
+	assert_eq!(
+		a.view().rank_mut(RankMethod::Average),
+		array![3., 1.5, 4., 1.5, 5.]
+	);
+}
+
+#[test]
+fn test_percentile_rank_mut() {
+	let mut a = array![9, 1, 7, 3, 5];
+	assert_eq!(a.percentile_rank_mut(&5), Ok(0.6));
+	assert_eq!(a.percentile_rank_strict_mut(&5), Ok(0.4));
+	let mut a = array![8, 2, 6, 4];
+	assert_eq!(a.percentile_rank_mut(&5), Ok(0.5));
+	assert_eq!(a.percentile_rank_strict_mut(&5), Ok(0.5));
+}
+
+#[test]
+fn test_percentile_rank_mut_out_of_range() {
+	let mut a = array![3, 1, 2];
+	assert_eq!(a.percentile_rank_mut(&0), Ok(0.));
+	assert_eq!(a.percentile_rank_strict_mut(&0), Ok(0.));
+	assert_eq!(a.percentile_rank_mut(&4), Ok(1.));
+	assert_eq!(a.percentile_rank_strict_mut(&4), Ok(1.));
+	assert_eq!(a.percentile_rank_mut(&3), Ok(1.));
+	assert_eq!(a.percentile_rank_strict_mut(&1), Ok(0.));
+}
+
+#[test]
+fn test_percentile_rank_mut_with_empty_array() {
+	let mut a = Array1::<i32>::zeros(0);
+	assert_eq!(a.percentile_rank_mut(&0), Err(EmptyInput));
+	assert_eq!(a.percentile_rank_strict_mut(&0), Err(EmptyInput));
+}
+
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn percentile_rank_mut_matches_count(xs: Vec<i32>, value: i32) -> bool {
+	let expected = xs.iter().filter(|&&x| x <= value).count() as f64 / xs.len() as f64;
+	let mut a = Array1::from(xs);
+	match a.percentile_rank_mut(&value) {
+		Ok(rank) => rank == expected,
+		Err(EmptyInput) => a.is_empty(),
+	}
+}
+
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn quantile_matches_quantile_mut(xs: Vec<i64>, q: u8) -> bool {
+	let q = f64::from(q) / f64::from(u8::MAX);
+	let a = Array::from(xs);
+	let quantile = a.quantile(n64(q), &Nearest);
+	let mut b = a.clone();
+	quantile == b.quantile_mut(q, &Nearest)
+}
+
+#[test]
+fn test_quantile_of_2d_array() {
+	let a = array![[4, 8, 1], [2, 6, 7]];
+	assert_eq!(a.quantile(n64(0.), &Lower), Ok(1));
+	assert_eq!(a.quantile(n64(0.5), &Higher), Ok(6));
+	assert_eq!(a.quantile(n64(1.), &Lower), Ok(8));
+	assert_eq!(a, array![[4, 8, 1], [2, 6, 7]]);
+	assert_eq!(
+		a.quantile(n64(1.1), &Lower),
+		Err(QuantileError::InvalidQuantile(1.1))
+	);
+	assert_eq!(
+		Array2::<i32>::zeros((0, 2)).quantile(n64(0.5), &Lower),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[test]
+fn test_resolve() {
+	// `(len - 1)q` is 2.5
+	let (lower, higher, q, len) = (Some(4), Some(8), n64(0.25), 11);
+	assert_eq!(resolve::<_, Lower>(lower, higher, q, len), 4);
+	assert_eq!(resolve::<_, Higher>(lower, higher, q, len), 8);
+	assert_eq!(resolve::<_, Nearest>(lower, higher, q, len), 8);
+	assert_eq!(resolve::<_, Midpoint>(lower, higher, q, len), 6);
+	assert_eq!(resolve::<_, Linear>(lower, higher, q, len), 6);
+	assert_eq!(resolve::<_, Lower>(lower, None, q, len), 4);
+}
+
+#[test]
+fn test_midpoint_interpolation_at_integer_extremes() {
+	let midpoint = |lower, higher| resolve::<i32, Midpoint>(Some(lower), Some(higher), n64(0.5), 2);
+	assert_eq!(midpoint(i32::MIN, i32::MAX), -1);
+	assert_eq!(midpoint(i32::MIN, i32::MIN + 1), i32::MIN);
+	assert_eq!(midpoint(i32::MIN, 0), i32::MIN / 2);
+	assert_eq!(midpoint(i32::MIN + 1, i32::MAX), 0);
+	assert_eq!(midpoint(i32::MAX - 1, i32::MAX), i32::MAX - 1);
+	assert_eq!(midpoint(i32::MAX, i32::MAX), i32::MAX);
+	// Rounded down for odd sums, also across zero.
+	assert_eq!(midpoint(-3, 0), -2);
+	assert_eq!(midpoint(-5, 4), -1);
+	assert_eq!(midpoint(-4, 5), 0);
+	assert_eq!(midpoint(-7, -4), -6);
+	let midpoint = |lower, higher| resolve::<u32, Midpoint>(Some(lower), Some(higher), n64(0.5), 2);
+	assert_eq!(midpoint(0, u32::MAX), u32::MAX / 2);
+	assert_eq!(midpoint(u32::MAX - 1, u32::MAX), u32::MAX - 1);
+	assert_eq!(midpoint(u32::MAX, u32::MAX), u32::MAX);
+	let mut a = array![i32::MAX, i32::MIN];
+	assert_eq!(a.quantile_mut(o64(0.5), &Midpoint).unwrap(), -1);
+	assert_eq!(
+		resolve::<f64, Midpoint>(Some(-3.), Some(0.), n64(0.5), 2),
+		-1.5
+	);
+}
+
+#[test]
+fn test_linear_interpolation_of_large_integers() {
+	// The values are not exactly representable as `f64` and round to the same `f64`.
+	let mut a = array![i64::MAX, i64::MAX - 10];
+	assert_eq!(a.quantile_mut(o64(0.5), &Linear).unwrap(), i64::MAX - 5);
+	assert_eq!(a.quantile_mut(o64(0.95), &Linear).unwrap(), i64::MAX - 1);
+	assert_eq!(a.quantile_mut(o64(1.), &Linear).unwrap(), i64::MAX);
+	let mut a = array![i64::MIN, i64::MIN + 7];
+	assert_eq!(a.quantile_mut(o64(0.5), &Linear).unwrap(), i64::MIN + 3);
+	let mut a = array![u64::MAX - 1, u64::MAX - 9];
+	assert_eq!(a.quantile_mut(o64(0.25), &Linear).unwrap(), u64::MAX - 7);
+	// The difference overflows `i64` and is truncated towards the lower value.
+	let mut a = array![i64::MIN, i64::MAX];
+	assert_eq!(a.quantile_mut(o64(0.5), &Linear).unwrap(), -1);
+	let mut a = array![u64::MAX, 0];
+	assert_eq!(a.quantile_mut(o64(0.5), &Linear).unwrap(), u64::MAX / 2);
+	// Floats are unaffected.
+	assert_eq!(
+		resolve::<_, Linear>(Some(1e300), Some(3e300), n64(0.5), 2),
+		2e300
+	);
+}
+
+#[test]
+fn test_rolling_quantile_mut() {
+	let a = array![5, 1, 4, 4, 9, 2, 6, 3, 8, 7];
+	for window in 1..=a.len() {
+		let mut b = a.clone();
+		let medians = b.rolling_quantile_mut(window, n64(0.5), &Midpoint).unwrap();
+		assert_eq!(medians.len(), a.len() - window + 1);
+		assert_eq!(b, a);
+		for (start, median) in medians.iter().enumerate() {
+			let mut values = a.slice(s![start..start + window]).to_vec();
+			values.sort_unstable();
+			let expected = if window % 2 == 0 {
+				(values[window / 2 - 1] + values[window / 2]) / 2
+			} else {
+				values[window / 2]
+			};
+			assert_eq!(*median, expected);
+		}
+	}
+}
+
+#[test]
+fn test_rolling_quantile_mut_with_invalid_arguments() {
+	let mut a = array![3, 1, 2];
+	assert_eq!(
+		a.rolling_quantile_mut(0, n64(0.5), &Lower),
+		Err(RollingQuantileError::InvalidWindow(0))
+	);
+	assert_eq!(
+		a.rolling_quantile_mut(4, n64(0.5), &Lower),
+		Err(RollingQuantileError::InvalidWindow(4))
+	);
+	assert_eq!(
+		a.rolling_quantile_mut(2, n64(1.5), &Lower),
+		Err(RollingQuantileError::InvalidQuantile(1.5))
+	);
+	let mut a = Array1::<i32>::zeros(0);
+	assert_eq!(
+		a.rolling_quantile_mut(1, n64(0.5), &Lower),
+		Err(RollingQuantileError::EmptyInput)
+	);
+}
+
+#[test]
+fn test_quantile_bracket_mut() {
+	let a = array![10, 50, 30, 20, 40];
+	// `(len - 1)q` is 1.2
+	let mut b = a.clone();
+	assert_eq!(b.quantile_bracket_mut(n64(0.3)), Ok((20, 30)));
+	// `(len - 1)q` is 2
+	let mut b = a.clone();
+	assert_eq!(b.quantile_bracket_mut(n64(0.5)), Ok((30, 30)));
+	for q in [0., 0.1, 0.3, 0.45, 0.8, 1.] {
+		let mut b = a.clone();
+		let (lower, higher) = b.quantile_bracket_mut(n64(q)).unwrap();
+		assert_eq!(Ok(lower), a.clone().quantile_mut(q, &Lower));
+		assert_eq!(Ok(higher), a.clone().quantile_mut(q, &Higher));
+	}
+	let mut b = a.clone();
+	assert_eq!(
+		b.quantile_bracket_mut(n64(1.5)),
+		Err(QuantileError::InvalidQuantile(1.5))
+	);
+	assert_eq!(
+		Array1::<i32>::zeros(0).quantile_bracket_mut(n64(0.5)),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[test]
+fn test_quantile_arg_mut() {
+	let a = array![10, 50, 30, 20, 40, 60];
+	for q in [0., 0.1, 0.3, 0.45, 0.5, 0.8, 1.] {
+		let mut b = a.clone();
+		let (index, value) = b.quantile_arg_mut(n64(q), &Nearest).unwrap();
+		assert_eq!(b[index], value);
+		assert_eq!(Ok(value), a.clone().quantile_mut(q, &Nearest));
+		let mut b = a.clone();
+		let (index, value) = b.quantile_arg_mut(n64(q), &Lower).unwrap();
+		assert_eq!(b[index], value);
+		assert_eq!(Ok(value), a.clone().quantile_mut(q, &Lower));
+		let mut b = a.clone();
+		let (index, value) = b.quantile_arg_mut(n64(q), &Higher).unwrap();
+		assert_eq!(b[index], value);
+		assert_eq!(Ok(value), a.clone().quantile_mut(q, &Higher));
+	}
+	// `(len - 1)q` is 1.5, hence the index is the one of the lower value followed by the higher
+	// value.
+	let mut b = a.clone();
+	let (index, value) = b.quantile_arg_mut(n64(0.3), &Midpoint).unwrap();
+	assert_eq!((index, value), (1, 25));
+	assert_eq!((b[index], b[index + 1]), (20, 30));
+	assert_eq!(
+		b.quantile_arg_mut(n64(1.5), &Nearest),
+		Err(QuantileError::InvalidQuantile(1.5))
+	);
+	assert_eq!(
+		Array1::<i32>::zeros(0).quantile_arg_mut(n64(0.5), &Nearest),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_quantile_ci_mut() {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	let data = |len: u32| {
+		Array1::from_iter((0..len).map(|i| n64(f64::from(i * 7919 % len) / f64::from(len))))
+	};
+	let mut rng = StdRng::seed_from_u64(7);
+	let (small, small_lower, small_upper) = data(50)
+		.quantile_ci_mut(n64(0.5), 0.9, 500, &mut rng, &Linear)
+		.unwrap();
+	assert!(small_lower <= small && small <= small_upper);
+	let (large, large_lower, large_upper) = data(2000)
+		.quantile_ci_mut(n64(0.5), 0.9, 500, &mut rng, &Linear)
+		.unwrap();
+	assert!(large_lower <= large && large <= large_upper);
+	// Narrows with larger samples.
+	assert!(large_upper - large_lower < (small_upper - small_lower) / 2.);
+	// Widens with higher confidence.
+	let mut rng = StdRng::seed_from_u64(7);
+	let (_, lower, upper) = data(50)
+		.quantile_ci_mut(n64(0.5), 0.99, 500, &mut rng, &Linear)
+		.unwrap();
+	assert!(upper - lower > small_upper - small_lower);
+	// Deterministic for a given seed.
+	let mut rng = StdRng::seed_from_u64(7);
+	assert_eq!(
+		data(50).quantile_ci_mut(n64(0.5), 0.9, 500, &mut rng, &Linear),
+		Ok((small, small_lower, small_upper))
+	);
+	assert_eq!(
+		Array1::<N64>::zeros(0).quantile_ci_mut(n64(0.5), 0.9, 10, &mut rng, &Linear),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic(expected = "Confidence must be between 0 and 1 (exclusive)")]
+fn test_quantile_ci_mut_rejects_invalid_confidence() {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	let mut rng = StdRng::seed_from_u64(7);
+	let _ = array![1, 2, 3].quantile_ci_mut(n64(0.5), 1., 10, &mut rng, &Linear);
+}
+
+#[test]
+fn test_quantile_sorted() {
+	let a = array![-3, 0, 1, 1, 4, 9, 10, 27];
+	for q in [0., 0.1, 0.25, 0.5, 0.6, 0.75, 0.99, 1.] {
+		let mut b = a.clone();
+		assert_eq!(a.quantile_sorted(n64(q), &Lower), b.quantile_mut(q, &Lower));
+		assert_eq!(
+			a.quantile_sorted(n64(q), &Higher),
+			b.quantile_mut(q, &Higher)
+		);
+		assert_eq!(
+			a.quantile_sorted(n64(q), &Nearest),
+			b.quantile_mut(q, &Nearest)
+		);
+		assert_eq!(
+			a.quantile_sorted(n64(q), &Midpoint),
+			b.quantile_mut(q, &Midpoint)
+		);
+		assert_eq!(
+			a.quantile_sorted(n64(q), &Linear),
+			b.quantile_mut(q, &Linear)
+		);
+	}
+	assert_eq!(
+		a.quantile_sorted(n64(1.5), &Lower),
+		Err(QuantileError::InvalidQuantile(1.5))
+	);
+	assert_eq!(
+		Array1::<i32>::zeros(0).quantile_sorted(n64(0.5), &Lower),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Array is not sorted in increasing order")]
+fn test_quantile_sorted_with_unsorted_array_panics() {
+	let _ = array![1, 3, 2].quantile_sorted(n64(0.5), &Lower);
+}
+
+#[test]
+fn test_sort_and_quantiles_mut() {
+	let a = array![9, -3, 27, 1, 0, 10, 4, 1];
+	let qs = array![n64(0.), n64(0.1), n64(0.25), n64(0.5), n64(0.75), n64(1.)];
+	let mut sorted = a.clone();
+	let quantiles = sorted.sort_and_quantiles_mut(&qs, &Linear).unwrap();
+	assert_eq!(sorted, array![-3, 0, 1, 1, 4, 9, 10, 27]);
+	let expected = a
+		.clone()
+		.quantiles_mut(&qs.mapv(N64::into_inner), &Linear)
+		.unwrap();
+	assert_eq!(quantiles, expected);
+
+	let mut b = a.clone();
+	assert_eq!(
+		b.sort_and_quantiles_mut(&array![n64(0.5), n64(1.5)], &Lower),
+		Err(QuantileError::InvalidQuantile(1.5))
+	);
+	assert_eq!(b, a);
+	assert_eq!(
+		Array1::<i32>::zeros(0).sort_and_quantiles_mut(&qs, &Lower),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn sort_and_quantiles_mut_sorts_and_matches_quantiles_mut(data: Vec<i64>) -> bool {
+	let qs = array![n64(0.), n64(0.3), n64(0.5), n64(0.9), n64(1.)];
+	let mut sorted = Array::from(data.clone());
+	let mut expected = Array::from(data);
+	let quantiles = sorted.sort_and_quantiles_mut(&qs, &Midpoint);
+	let expected = expected.quantiles_mut(&qs.mapv(N64::into_inner), &Midpoint);
+	let is_sorted = sorted.windows(2).into_iter().all(|pair| pair[0] <= pair[1]);
+	is_sorted && quantiles == expected
+}
+
+#[test]
+fn test_weighted_quantile() {
+	let a = array![30, 10, 40, 20];
+	let weights = array![1., 1., 1., 1.];
+	assert_eq!(a.weighted_quantile(&weights, n64(0.)), Ok(10));
+	assert_eq!(a.weighted_quantile(&weights, n64(0.5)), Ok(20));
+	assert_eq!(a.weighted_quantile(&weights, n64(0.51)), Ok(30));
+	assert_eq!(a.weighted_quantile(&weights, n64(1.)), Ok(40));
+	// Zero weights are ignored.
+	let weights = array![0., 2., 0., 1.];
+	assert_eq!(a.weighted_quantile(&weights, n64(0.)), Ok(10));
+	assert_eq!(a.weighted_quantile(&weights, n64(0.7)), Ok(20));
+	assert_eq!(a.weighted_quantile(&weights, n64(1.)), Ok(20));
+}
+
+#[test]
+fn test_weighted_quantile_with_invalid_arguments() {
+	let a = array![3, 1, 2];
+	assert_eq!(
+		a.weighted_quantile(&array![1., 1.], n64(0.5)),
+		Err(WeightedQuantileError::ShapeMismatch(ShapeMismatch {
+			first_shape: vec![3],
+			second_shape: vec![2],
+		}))
+	);
+	assert_eq!(
+		a.weighted_quantile(&array![0., 0., 0.], n64(0.5)),
+		Err(WeightedQuantileError::EmptyInput)
+	);
+	assert_eq!(
+		Array1::<i32>::zeros(0).weighted_quantile(&Array1::zeros(0), n64(0.5)),
+		Err(WeightedQuantileError::EmptyInput)
+	);
+	assert_eq!(
+		a.weighted_quantile(&array![1., 1., 1.], n64(1.5)),
+		Err(WeightedQuantileError::InvalidQuantile(1.5))
+	);
+	let err: WeightedQuantileError = ShapeMismatch {
+		first_shape: vec![1],
+		second_shape: vec![2],
+	}
+	.into();
+	assert_eq!(
+		err.to_string(),
+		"Shape mismatch: Array shapes do not match: [1] and [2]."
+	);
+}
+
+#[test]
+#[should_panic(expected = "Weights must be non-negative")]
+fn test_weighted_quantile_with_negative_weight_panics() {
+	let _ = array![1, 2].weighted_quantile(&array![1., -1.], n64(0.5));
+}
+
+#[test]
+fn test_mad_mut_with_outliers() {
+	let mut a = array![1, 2, 3, 4, 100];
+	assert_eq!(a.mad_mut(), Ok(1.));
+	let mut a = array![1, 2, 1000, 3, 4, -500];
+	assert_eq!(a.mad_mut(), Ok(1.5));
+	assert_eq!(a.scaled_mad_mut(), Ok(1.4826 * 1.5));
+	let mut a = array![o64(0.5), o64(-2.), o64(1e9), o64(1.)];
+	assert_eq!(a.mad_mut(), Ok(1.5));
+}
+
+#[test]
+fn test_tukey_outliers_flag_planted_outliers() {
+	let mut a = Array1::from_iter((0..40).map(|i| o64(f64::from(i % 10) + 10.)));
+	let planted = [3, 17, 29];
+	for (&i, value) in planted.iter().zip([-20., 100., 28.]) {
+		a[i] = o64(value);
+	}
+	let copy = a.clone();
+	let outliers = a.tukey_outliers(1.5, &Linear).unwrap();
+	assert_eq!(a, copy);
+	for (i, &outlier) in outliers.iter().enumerate() {
+		assert_eq!(outlier, planted.contains(&i), "{i}");
+	}
+	// Only far out values remain with wider fences.
+	let far_out = a.tukey_outliers(3., &Linear).unwrap();
+	assert_eq!(far_out.iter().filter(|&&outlier| outlier).count(), 2);
+	assert!(far_out[3] && far_out[17] && !far_out[29]);
+	assert_eq!(
+		Array1::<i32>::zeros(0).tukey_outliers(1.5, &Linear),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[test]
+#[should_panic(expected = "Fence factor must be non-negative and finite")]
+fn test_tukey_outliers_with_negative_factor() {
+	let _ = array![1, 2, 3].tukey_outliers(-1., &Linear);
+}
+
+#[test]
+fn test_mad_mut_with_empty_array() {
+	let mut a = Array1::<i32>::zeros(0);
+	assert_eq!(a.mad_mut(), Err(QuantileError::EmptyInput));
+	assert_eq!(a.scaled_mad_mut(), Err(QuantileError::EmptyInput));
+}
+
 #[cfg_attr(miri, ignore)]
 #[quickcheck]
 fn test_quantiles_mut(xs: Vec<i64>) -> TestResult {
@@ -394,6 +968,89 @@ fn check_one_interpolation_method_for_quantiles_mut(
 	}
 }
 
+#[cfg(feature = "rayon")]
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn test_par_quantiles_mut(xs: Vec<i64>) -> TestResult {
+	if xs.iter().any(|&x| x < 0) {
+		return TestResult::discard();
+	}
+	let v = Array::from(xs);
+	let quantile_indexes = Array::from(vec![
+		o64(0.75),
+		o64(0.90),
+		o64(0.),
+		o64(0.25),
+		o64(0.5),
+		o64(0.5),
+		o64(1.),
+	]);
+	let serial = v
+		.clone()
+		.par_quantiles_mut_with_threshold(&quantile_indexes, &Linear, usize::MAX);
+	let parallel = v.clone().par_quantiles_mut(&quantile_indexes, &Linear);
+	// Selects in parallel as well under `rayon`.
+	let default = v.clone().quantiles_mut(&quantile_indexes, &Linear);
+	TestResult::from_bool(serial == parallel && serial == default)
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_quantiles_axis_mut_in_parallel_matches_sorted_lanes() {
+	let data = Array::from_shape_fn((3, 10_000), |(row, column)| (column * 7919 + row) % 10_007);
+	let qs = array![o64(0.), o64(0.25), o64(0.5), o64(0.9), o64(1.)];
+	let quantiles = data
+		.clone()
+		.quantiles_axis_mut(Axis(1), &qs, &Lower)
+		.unwrap();
+	for (lane, quantiles) in data.outer_iter().zip(quantiles.outer_iter()) {
+		let mut sorted = lane.to_vec();
+		sorted.sort_unstable();
+		for (&index, &quantile) in [0, 2499, 4999, 8999, 9999].iter().zip(quantiles) {
+			assert_eq!(quantile, sorted[index]);
+		}
+	}
+}
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn test_par_quantiles_mut_with_threshold(xs: Vec<u32>, par_threshold: u8) -> bool {
+	let v = Array::from(xs);
+	let quantile_indexes = array![o64(0.), o64(0.1), o64(0.5), o64(0.5), o64(0.9), o64(1.)];
+	let serial = v.clone().quantiles_mut(&quantile_indexes, &Linear);
+	[0, usize::from(par_threshold), usize::MAX]
+		.into_iter()
+		.all(|par_threshold| {
+			let mut v = v.clone();
+			v.par_quantiles_mut_with_threshold(&quantile_indexes, &Linear, par_threshold) == serial
+		})
+}
+
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn test_quantile_mut_of_tiny_arrays_matches_selection(mut xs: Vec<i32>, q: u8) -> bool {
+	// Tiny arrays are sorted whereas `quantile_axis_mut` always selects.
+	xs.truncate(16);
+	let q = o64(f64::from(q) / 255.);
+	// Widen to avoid overflows of linear interpolation.
+	let a = Array1::from(xs).mapv(i64::from);
+	macro_rules! matches_selection {
+		($interpolate:expr) => {
+			a.clone().quantile_mut(q, &$interpolate)
+				== a.clone()
+					.quantile_axis_mut(Axis(0), q, &$interpolate)
+					.map(|quantile| quantile.into_scalar())
+		};
+	}
+	matches_selection!(Linear)
+		&& matches_selection!(Lower)
+		&& matches_selection!(Higher)
+		&& matches_selection!(Midpoint)
+		&& matches_selection!(Nearest)
+}
+
 #[cfg_attr(miri, ignore)]
 #[quickcheck]
 fn test_quantiles_axis_mut(mut xs: Vec<u64>) -> bool {
@@ -473,3 +1130,185 @@ fn check_one_interpolation_method_for_quantiles_axis_mut(
 		)
 	}
 }
+
+#[test]
+fn test_flat_quantiles_mut() {
+	let mut a = Array::from_shape_fn((6, 7), |(i, j)| ((i * 13 + j * 5) % 17) as i32);
+	let qs = array![n64(0.9), n64(0.), n64(0.5), n64(1.), n64(0.25)];
+	let mut reference = a
+		.slice(s![.., ..;2])
+		.iter()
+		.copied()
+		.collect::<Array1<i32>>();
+	let expected = reference
+		.quantiles_mut(&qs.mapv(|q| q.into_inner()), &Linear)
+		.unwrap();
+	let mut view = a.slice_mut(s![.., ..;2]);
+	assert!(view.as_slice_memory_order().is_none());
+	assert_eq!(view.flat_quantiles_mut(&qs, &Linear), Ok(expected.clone()));
+	let mut owned = a.slice(s![.., ..;2]).to_owned();
+	assert_eq!(owned.flat_quantiles_mut(&qs, &Linear), Ok(expected));
+	assert_eq!(
+		Array2::<i32>::zeros((0, 3)).flat_quantiles_mut(&qs, &Linear),
+		Err(QuantileError::EmptyInput)
+	);
+	assert_eq!(
+		a.flat_quantiles_mut(&array![n64(1.5)], &Linear),
+		Err(QuantileError::InvalidQuantile(1.5))
+	);
+}
+
+#[test]
+fn test_min_max_mut_of_empty_array() {
+	let mut a: Array1<i32> = array![];
+	assert_eq!(a.min_max_mut(), Err(EmptyInput));
+}
+
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn min_max_mut_matches_min_and_max(data: Vec<i32>) -> bool {
+	let mut a = Array1::from(data);
+	match a.min_max_mut() {
+		Ok((min, max)) => Ok(&min) == a.min() && Ok(&max) == a.max(),
+		Err(EmptyInput) => a.is_empty(),
+	}
+}
+
+#[test]
+fn test_five_number_summary_mut() {
+	let a = array![12, -3, 7, 7, 40, 0, 5, 18, 2, 9, 11];
+	let summary = a.clone().five_number_summary_mut(&Linear).unwrap();
+	assert_eq!(summary[0], *a.min().unwrap());
+	assert_eq!(summary[4], *a.max().unwrap());
+	for (&value, q) in summary.iter().zip([0., 0.25, 0.5, 0.75, 1.]) {
+		assert_eq!(Ok(value), a.clone().quantile_mut(q, &Linear));
+	}
+	assert_eq!(summary, [-3, 3, 7, 11, 40]);
+	assert_eq!(
+		Array1::<i32>::zeros(0).five_number_summary_mut(&Linear),
+		Err(QuantileError::EmptyInput)
+	);
+}
+
+#[cfg_attr(miri, ignore)]
+#[quickcheck]
+fn five_number_summary_mut_is_ordered(data: Vec<i64>) -> bool {
+	let mut a = Array1::from(data);
+	match a.five_number_summary_mut(&Midpoint) {
+		Ok(summary) => summary.windows(2).all(|pair| pair[0] <= pair[1]),
+		Err(QuantileError::EmptyInput) => a.is_empty(),
+		Err(_) => false,
+	}
+}
+
+#[test]
+fn test_min_max_mut_of_various_lengths() {
+	for len in 1..10 {
+		let mut a = Array1::from_shape_fn(len, |i| (i as i32 * 5 + 3) % 7);
+		let expected = (*a.min().unwrap(), *a.max().unwrap());
+		assert_eq!(a.min_max_mut(), Ok(expected));
+	}
+}
+
+#[test]
+fn test_running_stats_match_batch_statistics() {
+	let data = array![3.5, -1.25, 8., 0.5, 2., 2., -7.75, 4.25, 1e3, 0.125];
+	let mut stats = RunningStats::new();
+	assert_eq!(stats.mean(), None);
+	assert_eq!(stats.var(0), None);
+	assert_eq!((stats.min(), stats.max()), (None, None));
+	for (i, &x) in data.iter().enumerate() {
+		stats.update(x);
+		let seen = data.slice(s![..=i]);
+		assert_eq!(stats.count(), i + 1);
+		let mean = QuantileExt::mean(&seen).unwrap();
+		assert!((stats.mean().unwrap() - mean).abs() <= 1e-12 * mean.abs());
+		for ddof in 0..3 {
+			assert_eq!(stats.var(ddof), QuantileExt::var(&seen, ddof));
+			assert_eq!(stats.std(ddof), QuantileExt::std(&seen, ddof));
+		}
+		assert_eq!(stats.min(), seen.min().ok().copied());
+		assert_eq!(stats.max(), seen.max().ok().copied());
+	}
+}
+
+#[test]
+fn test_gaussian_kde_integrates_to_one_and_peaks_at_mode() {
+	let sample = array![1., 1.5, 2., 2., 2., 2.5, 3., 4., 6., 9.];
+	for bandwidth in [
+		Bandwidth::Scott,
+		Bandwidth::Silverman,
+		Bandwidth::Fixed(0.5),
+	] {
+		let kde = gaussian_kde(&sample, bandwidth).unwrap();
+		let step = 0.01;
+		let xs = Array1::range(-20., 30., step);
+		let densities = kde.evaluate(&xs);
+		assert!((densities.sum() * step - 1.).abs() < 1e-6);
+		let mode = xs[densities.argmax().unwrap()];
+		assert!((mode - 2.).abs() < 0.5, "{:?}: mode at {}", bandwidth, mode);
+	}
+}
+
+#[test]
+fn test_gaussian_kde_bandwidth() {
+	let sample = array![2, 4, 4, 4, 5, 5, 7, 9];
+	let sd = QuantileExt::std(&sample, 1).unwrap();
+	let kde = gaussian_kde(&sample, Bandwidth::Scott).unwrap();
+	assert!((kde.bandwidth() - sd * 8f64.powf(-0.2)).abs() < 1e-12);
+	let kde = gaussian_kde(&sample, Bandwidth::Silverman).unwrap();
+	assert!((kde.bandwidth() - sd * 6f64.powf(-0.2)).abs() < 1e-12);
+
+	let empty: Array1<f64> = array![];
+	assert_eq!(
+		gaussian_kde(&empty, Bandwidth::Scott),
+		Err(KdeError::EmptyInput)
+	);
+	assert_eq!(
+		gaussian_kde(&array![1., 1.], Bandwidth::Scott),
+		Err(KdeError::InvalidBandwidth(0.))
+	);
+	assert_eq!(
+		gaussian_kde(&array![1.], Bandwidth::Fixed(-1.)),
+		Err(KdeError::InvalidBandwidth(-1.))
+	);
+}
+
+#[test]
+fn test_geometric_median_of_symmetric_points() {
+	// The center of a square, translated and without any point at the center.
+	let square = array![[1., 3.], [3., 3.], [1., 5.], [3., 5.]];
+	let median = square.geometric_median(1e-12, 100).unwrap();
+	assert!((&median - &array![2., 4.]).iter().all(|x| x.abs() < 1e-9));
+	// The Fermat point of an equilateral triangle is its centroid.
+	let height = 3f64.sqrt() / 2.;
+	let triangle = array![[0., 0.], [1., 0.], [0.5, height]];
+	let median = triangle.geometric_median(1e-12, 1_000).unwrap();
+	assert!((&median - &array![0.5, height / 3.])
+		.iter()
+		.all(|x| x.abs() < 1e-9));
+	// A point at the center is the median as well.
+	let centered = array![[-1, -1, 0], [1, 1, 0], [0, 0, 0], [-1, 1, 0], [1, -1, 0]];
+	assert_eq!(
+		centered.geometric_median(1e-12, 1),
+		Some(array![0., 0., 0.])
+	);
+}
+
+#[test]
+fn test_geometric_median_of_collinear_points() {
+	// The median of collinear points is their median along the line, one of the points here.
+	let points = array![[0., 0.], [1., 2.], [2., 4.], [3., 6.], [10., 20.]];
+	let median = points.geometric_median(1e-12, 10_000).unwrap();
+	assert!((&median - &array![2., 4.]).iter().all(|x| x.abs() < 1e-6));
+}
+
+#[test]
+fn test_geometric_median_without_convergence_or_points() {
+	let points = array![[0., 0.], [1., 0.], [0., 7.]];
+	assert_eq!(points.geometric_median(1e-12, 1), None);
+	assert_eq!(
+		Array2::<f64>::zeros((0, 2)).geometric_median(1e-12, 100),
+		None
+	);
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+use ndarray_histogram::{
+	errors::{EmptyInput, MultiInputError, ShapeMismatch},
+	o64, QuantileExt, SummaryStatisticsExt,
+};
+
+#[test]
+fn test_geometric_mean() {
+	assert_eq!(array![1, 4].geometric_mean(), Ok(2.));
+	let mean = array![[1., 3.], [9., 27.]].geometric_mean().unwrap();
+	assert!((mean - 27_f64.sqrt()).abs() < 1e-12);
+	let mean = array![o64(2.), o64(8.)].geometric_mean().unwrap();
+	assert!((mean - 4.).abs() < 1e-12);
+}
+
+#[test]
+fn test_harmonic_mean() {
+	assert_eq!(array![1, 4, 4].harmonic_mean(), Ok(2.));
+	let mean = array![[40., 60.]].harmonic_mean().unwrap();
+	assert!((mean - 48.).abs() < 1e-12);
+}
+
+#[test]
+fn test_means_of_non_positive_values() {
+	assert!(array![1, 0, 4].geometric_mean().unwrap().is_nan());
+	assert!(array![1, -1, 4].geometric_mean().unwrap().is_nan());
+	assert!(array![1, 0, 4].harmonic_mean().unwrap().is_nan());
+	assert!(array![1, -1, 4].harmonic_mean().unwrap().is_nan());
+}
+
+#[test]
+fn test_means_of_empty_array() {
+	let a = Array1::<f64>::zeros(0);
+	assert_eq!(a.geometric_mean(), Err(EmptyInput));
+	assert_eq!(a.harmonic_mean(), Err(EmptyInput));
+}
+
+#[test]
+fn test_mean_abs_deviation() {
+	// Deviations of 3, 2, 1, 0, and 6 about the mean of 4.
+	let a = array![1, 2, 3, 4, 10];
+	let mad = a.mean_abs_deviation().unwrap();
+	assert!((mad - 2.4).abs() < 1e-12);
+	// The outlier weighs more in the standard deviation of √10.
+	let sd = QuantileExt::std(&a, 0).unwrap();
+	assert!((sd - 10_f64.sqrt()).abs() < 1e-12);
+	assert!(mad <= sd);
+	// The mean absolute deviation of `1..=n` is `n / 4` for even `n`.
+	let a = Array1::from_iter(1..=100);
+	assert!((a.mean_abs_deviation().unwrap() - 25.).abs() < 1e-12);
+	assert!(a.mean_abs_deviation().unwrap() <= QuantileExt::std(&a, 0).unwrap());
+	assert_eq!(array![[1., 3.], [5., 7.]].mean_abs_deviation(), Ok(2.));
+	assert_eq!(array![o64(3.), o64(3.)].mean_abs_deviation(), Ok(0.));
+	assert_eq!(
+		Array1::<f64>::zeros(0).mean_abs_deviation(),
+		Err(EmptyInput)
+	);
+}
+
+#[test]
+fn test_skewness_and_kurtosis() {
+	let a = array![2, 8, 0, 4, 1, 9, 9, 0];
+	assert!((a.skewness().unwrap() - 0.330_582_180_407_974_66).abs() < 1e-12);
+	assert!((a.kurtosis().unwrap() - -2.098_602_258_096_087).abs() < 1e-12);
+	let a = array![[1., 2.], [3., 4.]];
+	assert!(a.skewness().unwrap().abs() < 1e-12);
+	assert!((a.kurtosis().unwrap() - -1.2).abs() < 1e-12);
+}
+
+#[test]
+fn test_skewness_and_kurtosis_of_small_samples() {
+	assert_eq!(array![1, 2].skewness(), Err(EmptyInput));
+	assert_eq!(array![1, 2, 3].kurtosis(), Err(EmptyInput));
+	assert!(array![1, 1, 1, 1].skewness().unwrap().is_nan());
+	assert!(array![1, 1, 1, 1].kurtosis().unwrap().is_nan());
+}
+
+#[test]
+fn test_weighted_var_with_equal_weights() {
+	let a = array![2, 4, 4, 4, 5, 5, 7, 9];
+	for weight in [1., 0.5, 3.] {
+		let weights = Array1::from_elem(a.len(), weight);
+		for ddof in 0..3 {
+			let var = a.weighted_var(&weights, ddof).unwrap();
+			assert!((var - QuantileExt::var(&a, ddof).unwrap()).abs() < 1e-12);
+			let std = a.weighted_std(&weights, ddof).unwrap();
+			assert!((std - QuantileExt::std(&a, ddof).unwrap()).abs() < 1e-12);
+		}
+	}
+}
+
+#[test]
+fn test_weighted_var_with_unequal_weights() {
+	// Weighted mean of 5, squared deviations of 16, 0, and 4, and `V2` of 14.
+	let a = array![1, 5, 7];
+	let weights = array![1., 3., 2.];
+	assert!((a.weighted_var(&weights, 0).unwrap() - 4.).abs() < 1e-12);
+	// Unbiased for reliability weights: 24 / (6 - 14 / 6).
+	assert!((a.weighted_var(&weights, 1).unwrap() - 72. / 11.).abs() < 1e-12);
+	assert!((a.weighted_std(&weights, 1).unwrap() - (72_f64 / 11.).sqrt()).abs() < 1e-12);
+	// Zero weights ignore elements.
+	let var = array![1, 5, 7, 100].weighted_var(&array![1., 3., 2., 0.], 1);
+	assert!((var.unwrap() - 72. / 11.).abs() < 1e-12);
+}
+
+#[test]
+fn test_weighted_var_errors() {
+	let a = array![1., 2., 3.];
+	assert_eq!(
+		a.weighted_var(&array![1., 1.], 0),
+		Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+			first_shape: vec![3],
+			second_shape: vec![2],
+		}))
+	);
+	assert_eq!(
+		a.weighted_var(&array![0., 0., 0.], 0),
+		Err(MultiInputError::EmptyInput)
+	);
+	assert_eq!(
+		Array1::<f64>::zeros(0).weighted_std(&Array1::zeros(0), 0),
+		Err(MultiInputError::EmptyInput)
+	);
+	// A single effective observation leaves no degree of freedom.
+	assert_eq!(
+		a.weighted_var(&array![0., 5., 0.], 1),
+		Err(MultiInputError::EmptyInput)
+	);
+	assert_eq!(
+		a.weighted_var(&array![1., -1., 1.], 0),
+		Err(MultiInputError::InvalidWeights)
+	);
+	assert_eq!(
+		a.weighted_std(&array![1., f64::NAN, 1.], 0),
+		Err(MultiInputError::InvalidWeights)
+	);
+}
+
+#[test]
+fn test_weighted_var_of_2d_array() {
+	let a = array![[1, 5], [7, 100]];
+	let var = a.weighted_var(&array![[1., 3.], [2., 0.]], 1);
+	assert!((var.unwrap() - 72. / 11.).abs() < 1e-12);
+	assert!(a
+		.weighted_var(
+			&array![1., 3., 2., 0.]
+				.into_shape_with_order((4, 1))
+				.unwrap(),
+			1
+		)
+		.unwrap_err()
+		.is_shape_mismatch());
+}